@@ -0,0 +1,202 @@
+extern crate tokio;
+
+use self::tokio::io::{AsyncRead, AsyncReadExt};
+use self::tokio::sync::mpsc::{self, Receiver, Sender};
+use self::tokio::task::{self, JoinError};
+
+use crate::book::Book;
+use crate::error::Error;
+use crate::events::{Event, Herald};
+use crate::limits::Limits;
+use crate::model::schema::Schema;
+use crate::reader::Reader;
+use crate::sage::SageError;
+use crate::savant::Savant;
+use crate::txt::Chunks;
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::io;
+use std::iter;
+use std::panic;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+
+/// How many bytes the source is read by at most
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// How many chunks the source may be read ahead of the reader
+const CHUNKS_AHEAD: usize = 4;
+
+/// Reads an asynchronous source into a book.
+///
+/// The reader works in a blocking thread of the runtime (see `tokio::task::spawn_blocking`),
+/// while the source is read by the calling task no more than a few chunks ahead of it.
+/// So the executor is never blocked, and the source is not kept in memory in full
+/// before the reader gets to it.
+///
+/// The errors of the source and the reader are returned, while the ones stamped into
+/// the book (such as the scalars that could not be decoded) are left to `Book::check`.
+pub async fn read<R, S>(source: R, schema: S) -> Result<Book, Error>
+where
+    R: AsyncRead + Unpin,
+    S: Schema + 'static,
+{
+    read_with_limits(source, schema, Limits::default()).await
+}
+
+/// The same as `read`, with the reader and the book bound by the limits.
+/// The source is not read any further once a limit has been exceeded.
+pub async fn read_with_limits<R, S>(mut source: R, schema: S, limits: Limits) -> Result<Book, Error>
+where
+    R: AsyncRead + Unpin,
+    S: Schema + 'static,
+{
+    let (sender, receiver) = mpsc::channel(CHUNKS_AHEAD);
+
+    let reading = task::spawn_blocking(move || {
+        let failure = Rc::new(RefCell::new(None));
+
+        let mut book = Book::new();
+        let mut savant = Savant::new(schema);
+
+        book.set_limits(limits);
+
+        let result =
+            Reader::with_limits(limits).read(chunks(receiver, failure.clone()), &mut |block| {
+                match savant.think(block) {
+                    Ok(Some(idea)) => {
+                        book.stamp(idea);
+                        Ok(())
+                    }
+                    Ok(None) => Ok(()),
+                    Err(SageError::Error(message)) => Err(message),
+                    Err(error) => Err(Cow::from(error.to_string())),
+                }
+            });
+
+        // the source failing is what the reader has failed for, if it has
+        if let Some(error) = failure.borrow_mut().take() {
+            return Err(Error::from(error));
+        }
+
+        result?;
+
+        Ok(book)
+    });
+
+    feed(&mut source, sender).await;
+
+    reading.await.unwrap_or_else(joined)
+}
+
+/// Stream of events read from an asynchronous source, the same as `events::Events`.
+///
+/// The source is read by a task of the runtime no more than a few chunks ahead of the reader,
+/// and the reader works in a blocking thread of the runtime, no more than `capacity` events
+/// ahead of the consumer. The errors of the source come as `Event::Error` before `Event::StreamEnd`.
+pub struct Events {
+    pipe: Receiver<Event>,
+}
+
+impl Events {
+    /// Starts reading the source, which must be done within the context of a runtime
+    pub fn new<R, S>(source: R, schema: S) -> Events
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+        S: Schema + 'static,
+    {
+        Events::with_capacity(source, schema, 256)
+    }
+
+    pub fn with_capacity<R, S>(mut source: R, schema: S, capacity: usize) -> Events
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+        S: Schema + 'static,
+    {
+        let (sender, receiver) = mpsc::channel(CHUNKS_AHEAD);
+        let (out, pipe) = mpsc::channel(capacity);
+
+        task::spawn(async move { feed(&mut source, sender).await });
+
+        task::spawn_blocking(move || {
+            let failure = Rc::new(RefCell::new(None));
+            let reader = chunks(receiver, failure.clone());
+
+            Herald::new(move |event| {
+                if let Event::StreamEnd = event {
+                    if let Some(error) = failure.borrow_mut().take() {
+                        let error = Event::Error(Cow::from(error.to_string()), None);
+
+                        if out.blocking_send(error).is_err() {
+                            return false;
+                        }
+                    }
+                }
+
+                out.blocking_send(event).is_ok()
+            })
+            .run(reader, schema)
+        });
+
+        Events { pipe }
+    }
+
+    /// The next event, or `None` after the stream end
+    pub async fn next(&mut self) -> Option<Event> {
+        self.pipe.recv().await
+    }
+
+    /// The same as `next`, for the implementations of `Stream` and other futures
+    pub fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Event>> {
+        self.pipe.poll_recv(cx)
+    }
+}
+
+/// Reads the source into the channel until the source is over or the reader stops listening
+async fn feed<R>(source: &mut R, sender: Sender<io::Result<Vec<u8>>>)
+where
+    R: AsyncRead + Unpin,
+{
+    loop {
+        let mut chunk = Vec::with_capacity(CHUNK_SIZE);
+
+        let chunk = match source.read_buf(&mut chunk).await {
+            Ok(0) => return,
+            Ok(_) => Ok(chunk),
+            Err(error) => Err(error),
+        };
+
+        let failed = chunk.is_err();
+
+        if sender.send(chunk).await.is_err() || failed {
+            return;
+        }
+    }
+}
+
+/// The chunks of the channel, ending with the first error of the source, which is kept in `failure`
+fn chunks(
+    mut receiver: Receiver<io::Result<Vec<u8>>>,
+    failure: Rc<RefCell<Option<io::Error>>>,
+) -> Chunks<impl Iterator<Item = Vec<u8>>> {
+    Chunks::new(iter::from_fn(move || match receiver.blocking_recv()? {
+        Ok(chunk) => Some(chunk),
+        Err(error) => {
+            *failure.borrow_mut() = Some(error);
+            None
+        }
+    }))
+}
+
+/// Passes on the panic of the reader, or tells that the runtime has shut down before the reader finished
+fn joined(error: JoinError) -> Result<Book, Error> {
+    if error.is_panic() {
+        panic::resume_unwind(error.into_panic());
+    }
+
+    Err(Error::from(io::Error::new(
+        io::ErrorKind::Interrupted,
+        error,
+    )))
+}
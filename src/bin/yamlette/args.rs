@@ -0,0 +1,161 @@
+use yamlette::model::style::CommonStyles;
+
+pub const USAGE: &str = "\
+Usage: yamlette <command> [options] [file]
+
+Reads the file, or the standard input if the file is omitted or is `-`.
+
+Commands:
+  check [file...]         report the first error of every file as file:line:column: message
+  fmt [file]              write the documents out again with the chosen styles
+  to-json [file]          write every document as a line of JSON
+  from-json [file]        write the JSON documents as YAML with the chosen styles
+  get <path> [file]       write the node at the JSON Pointer or dotted path (strings as they are)
+  docs [file]             count the documents of the stream
+  help                    print this message
+
+Styles (fmt, from-json, get, docs --split):
+  --indent <n>            indentation of the block collections (2 by default)
+  --threshold <n>         width the flow collections should fit in (120 by default)
+  --flow                  write the collections in the flow style
+  --compact               keep the nested block collections on the lines of their parents
+  --multiline             write the long strings over several lines
+  --respect-threshold     break the flow collections wider than the threshold
+  --issue-tag             put the tags on every node
+
+Options:
+  --pretty                to-json: indent the JSON, one line per entry
+  --doc <n>               get: look up in the document with the index (0 by default)
+  --json                  get: write the node as JSON
+  --split <prefix>        docs: write every non-empty document into <prefix><index>.yaml
+";
+
+pub enum Command {
+    Check,
+    Fmt,
+    ToJson,
+    FromJson,
+    Get(String),
+    Docs,
+    Help,
+}
+
+pub struct Args {
+    pub command: Command,
+    pub files: Vec<String>,
+    pub styles: CommonStyles,
+    pub pretty: bool,
+    pub doc: usize,
+    pub json: bool,
+    pub split: Option<String>,
+}
+
+impl Args {
+    pub fn parse<I>(args: I) -> Result<Args, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut args = args.into_iter();
+
+        let command = match args.next().as_deref() {
+            Some("check") => Command::Check,
+            Some("fmt") => Command::Fmt,
+            Some("to-json") => Command::ToJson,
+            Some("from-json") => Command::FromJson,
+            Some("get") => match args.next() {
+                Some(path) => Command::Get(path),
+                None => return Err(String::from("get: the path is missing")),
+            },
+            Some("docs") => Command::Docs,
+            Some("help") | Some("--help") | Some("-h") => Command::Help,
+            Some(command) => return Err(format!("unknown command `{}`", command)),
+            None => return Err(String::from("the command is missing")),
+        };
+
+        let mut result = Args {
+            command,
+            files: Vec::new(),
+            styles: CommonStyles::default(),
+            pretty: false,
+            doc: 0,
+            json: false,
+            split: None,
+        };
+
+        while let Some(arg) = args.next() {
+            if arg == "-" || !arg.starts_with("--") {
+                result.files.push(arg);
+                continue;
+            }
+
+            if !result.accepts(&arg) {
+                return Err(format!("{}: unexpected option `{}`", result.name(), arg));
+            }
+
+            match arg.as_str() {
+                "--indent" => result.styles.set_indent(number(&arg, args.next())?),
+                "--threshold" => result.styles.set_threshold(number(&arg, args.next())?),
+                "--flow" => result.styles.set_flow(true),
+                "--compact" => result.styles.set_compact(true),
+                "--multiline" => result.styles.set_multiline(true),
+                "--respect-threshold" => result.styles.set_respect_threshold(true),
+                "--issue-tag" => result.styles.set_issue_tag(true),
+                "--pretty" => result.pretty = true,
+                "--doc" => result.doc = number(&arg, args.next())?,
+                "--json" => result.json = true,
+                "--split" => match args.next() {
+                    Some(prefix) => result.split = Some(prefix),
+                    None => return Err(String::from("--split: the prefix is missing")),
+                },
+                _ => unreachable!(),
+            }
+        }
+
+        if result.files.len() > 1 && !matches!(result.command, Command::Check) {
+            return Err(format!("{}: only one file is read", result.name()));
+        }
+
+        Ok(result)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self.command {
+            Command::Check => "check",
+            Command::Fmt => "fmt",
+            Command::ToJson => "to-json",
+            Command::FromJson => "from-json",
+            Command::Get(_) => "get",
+            Command::Docs => "docs",
+            Command::Help => "help",
+        }
+    }
+
+    /// Whether the option makes sense for the command
+    fn accepts(&self, option: &str) -> bool {
+        match option {
+            "--indent"
+            | "--threshold"
+            | "--flow"
+            | "--compact"
+            | "--multiline"
+            | "--respect-threshold"
+            | "--issue-tag" => matches!(
+                self.command,
+                Command::Fmt | Command::FromJson | Command::Get(_) | Command::Docs
+            ),
+            "--pretty" => matches!(self.command, Command::ToJson),
+            "--doc" | "--json" => matches!(self.command, Command::Get(_)),
+            "--split" => matches!(self.command, Command::Docs),
+            _ => false,
+        }
+    }
+}
+
+fn number<T: std::str::FromStr>(option: &str, value: Option<String>) -> Result<T, String> {
+    match value {
+        Some(value) => value
+            .parse()
+            .map_err(|_| format!("{}: `{}` is not a valid number", option, value)),
+        None => Err(format!("{}: the number is missing", option)),
+    }
+}
@@ -0,0 +1,127 @@
+use yamlette::book::value::Value;
+
+use std::fmt::Write;
+
+/// Writes the value as JSON, on a single line or indented by two spaces if `pretty`.
+///
+/// Binaries become Base64 strings, timestamps their canonical text, the keys of maps
+/// the text of their scalars, and the tags out of the schema are dropped. The values
+/// without a JSON counterpart (infinite floats, collections as keys) are errors.
+pub fn to_json(value: &Value, pretty: bool) -> Result<String, String> {
+    let mut out = String::new();
+    write_value(&mut out, value, pretty, 0)?;
+    Ok(out)
+}
+
+fn write_value(out: &mut String, value: &Value, pretty: bool, level: usize) -> Result<(), String> {
+    match *value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(value) => out.push_str(if value { "true" } else { "false" }),
+        Value::Int(ref value) => write!(out, "{}", value).unwrap(),
+        Value::Float(value) if value.is_finite() => write!(out, "{:?}", value).unwrap(),
+        Value::Float(value) => return Err(format!("{} cannot be written in JSON", value)),
+        Value::Str(ref value) => write_str(out, value),
+        Value::Binary(ref value) => write_str(out, &base64(value)),
+        Value::Timestamp(ref value) => write_str(out, &value.to_string()),
+        Value::Seq(ref seq) => {
+            out.push('[');
+
+            for (idx, item) in seq.iter().enumerate() {
+                separate(out, idx, pretty, level + 1);
+                write_value(out, item, pretty, level + 1)?;
+            }
+
+            close(out, seq.is_empty(), pretty, level, ']');
+        }
+        Value::Map(ref map) => {
+            out.push('{');
+
+            for (idx, (key, value)) in map.iter().enumerate() {
+                separate(out, idx, pretty, level + 1);
+                write_str(out, &key_text(key)?);
+                out.push_str(if pretty { ": " } else { ":" });
+                write_value(out, value, pretty, level + 1)?;
+            }
+
+            close(out, map.is_empty(), pretty, level, '}');
+        }
+        Value::Tagged(_, ref value) => write_value(out, value, pretty, level)?,
+    }
+
+    Ok(())
+}
+
+fn separate(out: &mut String, idx: usize, pretty: bool, level: usize) {
+    if idx > 0 {
+        out.push(',');
+    }
+
+    if pretty {
+        out.push('\n');
+        out.extend(std::iter::repeat("  ").take(level));
+    }
+}
+
+fn close(out: &mut String, empty: bool, pretty: bool, level: usize, bracket: char) {
+    if pretty && !empty {
+        out.push('\n');
+        out.extend(std::iter::repeat("  ").take(level));
+    }
+
+    out.push(bracket);
+}
+
+fn key_text(key: &Value) -> Result<String, String> {
+    match *key {
+        Value::Str(ref key) => Ok(key.clone()),
+        Value::Binary(ref key) => Ok(base64(key)),
+        Value::Timestamp(ref key) => Ok(key.to_string()),
+        Value::Seq(_) | Value::Map(_) => Err(String::from(
+            "a collection as a key cannot be written in JSON",
+        )),
+        Value::Tagged(_, ref key) => key_text(key),
+        ref key => to_json(key, false),
+    }
+}
+
+fn write_str(out: &mut String, value: &str) {
+    out.push('"');
+
+    for chr in value.chars() {
+        match chr {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            chr if (chr as u32) < 0x20 => write!(out, "\\u{:04x}", chr as u32).unwrap(),
+            chr => out.push(chr),
+        }
+    }
+
+    out.push('"');
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut out = String::with_capacity((bytes.len() + 2) / 3 * 4);
+
+    for chunk in bytes.chunks(3) {
+        let bits = chunk.iter().enumerate().fold(0u32, |bits, (idx, &byte)| {
+            bits | (byte as u32) << (16 - idx * 8)
+        });
+
+        for idx in 0..4 {
+            if idx <= chunk.len() {
+                out.push(TABLE[(bits >> (18 - idx * 6) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+
+    out
+}
@@ -0,0 +1,374 @@
+//! The command line of yamlette: checks, formats and converts YAML with the same
+//! reader and orchestra as the library. See `yamlette help` for the commands.
+
+mod args;
+mod json;
+
+use yamlette::book::extractor::pointer::Pointer;
+use yamlette::book::extractor::traits::FromPointer;
+use yamlette::book::value::Value;
+use yamlette::book::word::Word;
+use yamlette::book::Book;
+use yamlette::error::Error;
+use yamlette::face::skimmer::reader::IntoReader;
+use yamlette::model::schema::core::Core;
+use yamlette::model::schema::json::Json;
+use yamlette::model::schema::Schema;
+use yamlette::model::style::CommonStyles;
+use yamlette::model::yaml::str::QuoteAmbiguous;
+use yamlette::model::yaml::{map, seq};
+use yamlette::orchestra::chord::Chord;
+use yamlette::orchestra::Orchestra;
+use yamlette::reader::Reader;
+use yamlette::sage::SageError;
+use yamlette::savant::Savant;
+
+use args::{Args, Command, USAGE};
+
+use std::borrow::Cow;
+use std::env;
+use std::error::Error as StdError;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::process;
+
+/// The documents are fine (or the file has been written)
+const OK: i32 = 0;
+
+/// The documents could not be read, converted or looked up in
+const FAILURE: i32 = 1;
+
+/// The command line is wrong
+const USAGE_ERROR: i32 = 2;
+
+fn main() {
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!(
+                "yamlette: {}\nSee `yamlette help` for the commands.",
+                message
+            );
+            process::exit(USAGE_ERROR);
+        }
+    };
+
+    let code = match run(&args) {
+        Ok(code) => code,
+        Err(message) => {
+            eprintln!("yamlette {}: {}", args.name(), message);
+            FAILURE
+        }
+    };
+
+    process::exit(code);
+}
+
+fn run(args: &Args) -> Result<i32, String> {
+    match args.command {
+        Command::Help => {
+            print!("{}", USAGE);
+            Ok(OK)
+        }
+        Command::Check => check(args),
+        Command::Fmt => {
+            let file = input(args);
+            let book = book(file, Core::new())?;
+
+            rewritable(file, &book)?;
+            write(&yaml(documents(file, &book)?, args.styles)?)
+        }
+        Command::ToJson => {
+            let file = input(args);
+            let book = book(file, Core::new())?;
+            let mut out = String::new();
+
+            for value in documents(file, &book)? {
+                out.push_str(&json::to_json(&value, args.pretty)?);
+                out.push('\n');
+            }
+
+            write(out.as_bytes())
+        }
+        Command::FromJson => {
+            let file = input(args);
+            let book = book(file, Json::new())?;
+
+            write(&yaml(documents(file, &book)?, args.styles)?)
+        }
+        Command::Get(ref path) => get(args, path),
+        Command::Docs => docs(args),
+    }
+}
+
+/// Reports the first error of every file, going on to the next one
+fn check(args: &Args) -> Result<i32, String> {
+    let mut code = OK;
+    let stdin = [String::from("-")];
+
+    let files = if args.files.is_empty() {
+        &stdin[..]
+    } else {
+        &args.files[..]
+    };
+
+    for file in files {
+        let result = load(file)
+            .map_err(Error::from)
+            .and_then(|source| read(source, Core::new()))
+            .and_then(|book| book.check());
+
+        if let Err(error) = result {
+            eprintln!("{}", describe(file, &error));
+            code = FAILURE;
+        }
+    }
+
+    Ok(code)
+}
+
+fn get(args: &Args, path: &str) -> Result<i32, String> {
+    let file = input(args);
+    let book = book(file, Core::new())?;
+
+    let value = book
+        .volumes
+        .get(args.doc)
+        .and_then(Pointer::new)
+        .and_then(|pointer| pointer.query(path))
+        .and_then(Value::from_pointer);
+
+    match value {
+        None => {
+            eprintln!("{}: `{}` is not found", name(file), path);
+            Ok(FAILURE)
+        }
+        Some(value) => {
+            if args.json {
+                write(format!("{}\n", json::to_json(&value, false)?).as_bytes())
+            } else if let Value::Str(ref text) = value {
+                write(format!("{}\n", text).as_bytes())
+            } else {
+                write(&yaml(vec![value], args.styles)?)
+            }
+        }
+    }
+}
+
+/// Counts the documents of the input, the empty ones as well,
+/// or splits them into files of their own, leaving the empty ones out
+fn docs(args: &Args) -> Result<i32, String> {
+    let file = input(args);
+    let source = load(file).map_err(|error| describe(file, &Error::from(error)))?;
+    let explicit = explicit(&source);
+    let book = parse(file, source, Core::new())?;
+
+    match args.split {
+        None => {
+            // the reader gives a stream with no document an empty one all the same
+            let count = if explicit || book.volumes.iter().any(|v| !v.gist.is_empty()) {
+                book.volumes.len()
+            } else {
+                0
+            };
+
+            write(format!("{}\n", count).as_bytes())
+        }
+        Some(ref prefix) => {
+            rewritable(file, &book)?;
+
+            for (idx, value) in documents(file, &book)?.into_iter().enumerate() {
+                let path = format!("{}{}.yaml", prefix, idx);
+
+                fs::write(&path, yaml(vec![value], args.styles)?)
+                    .map_err(|error| format!("{}: {}", path, error))?;
+
+                println!("{}", path);
+            }
+
+            Ok(OK)
+        }
+    }
+}
+
+/// The documents of the book, the empty ones left out
+fn documents(file: &str, book: &Book) -> Result<Vec<Value>, String> {
+    let mut values = Vec::with_capacity(book.volumes.len());
+
+    for (idx, volume) in book.volumes.iter().enumerate() {
+        if let Some(pointer) = Pointer::new(volume) {
+            match Value::try_from_pointer(pointer) {
+                Ok(value) => values.push(value),
+                Err(error) => return Err(format!("{}: document {}: {}", name(file), idx, error)),
+            }
+        }
+    }
+
+    Ok(values)
+}
+
+/// Fails on the documents the values of which cannot be written back as they are,
+/// since the values keep neither the anchors nor the aliases (copying the anchored nodes instead),
+/// and the orchestra has no way of issuing the tags of collections yet
+fn rewritable(file: &str, book: &Book) -> Result<(), String> {
+    for (idx, volume) in book.volumes.iter().enumerate() {
+        for &(ref anchor, _, ref word) in volume.gist.iter() {
+            let lost = match (anchor, word) {
+                (&Some(ref anchor), _) => format!("The anchor &{} cannot be written yet", anchor),
+                (&None, &Word::Alias(_)) | (&None, &Word::UnboundAlias(_)) => {
+                    String::from("The aliases cannot be written yet")
+                }
+                (&None, &Word::Seq(ref tag)) if tag != seq::TAG => {
+                    format!("The tag <{}> of a sequence cannot be written yet", tag)
+                }
+                (&None, &Word::Map(ref tag)) if tag != map::TAG => {
+                    format!("The tag <{}> of a mapping cannot be written yet", tag)
+                }
+                _ => continue,
+            };
+
+            return Err(format!("{}: document {}: {}", name(file), idx, lost));
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads the file into a book with no errors in it
+fn book<S>(file: &str, schema: S) -> Result<Book, String>
+where
+    S: Schema + 'static,
+{
+    load(file)
+        .map_err(|error| describe(file, &Error::from(error)))
+        .and_then(|source| parse(file, source, schema))
+}
+
+fn parse<S>(file: &str, source: Vec<u8>, schema: S) -> Result<Book, String>
+where
+    S: Schema + 'static,
+{
+    read(source, schema)
+        .and_then(|book| book.check().map(|_| book))
+        .map_err(|error| describe(file, &error))
+}
+
+/// Whether the source starts a document with a marker of its own
+fn explicit(source: &[u8]) -> bool {
+    source.split(|&byte| byte == b'\n').any(|line| {
+        line.starts_with(b"---") && line.get(3).map_or(true, |byte| b" \t\r".contains(byte))
+    })
+}
+
+fn read<S>(source: Vec<u8>, schema: S) -> Result<Book, Error>
+where
+    S: Schema + 'static,
+{
+    let mut book = Book::new();
+    let mut savant = Savant::new(schema);
+
+    Reader::new().read(
+        source.into_reader(),
+        &mut |block| match savant.think(block) {
+            Ok(Some(idea)) => {
+                book.stamp(idea);
+                Ok(())
+            }
+            Ok(None) => Ok(()),
+            Err(SageError::Error(message)) => Err(message),
+            Err(error) => Err(Cow::from(error.to_string())),
+        },
+    )?;
+
+    Ok(book)
+}
+
+/// Plays the values as the documents of a stream, with the styles of the command line.
+/// The strings the core schema would read back as something else are quoted.
+/// Every document is played by an orchestra of its own and ended with a line break,
+/// since a root scalar or flow collection leaves its line open for the next border.
+fn yaml(values: Vec<Value>, styles: CommonStyles) -> Result<Vec<u8>, String> {
+    let stream = values.len() > 1;
+    let schema = Core::new();
+    let mut out = Vec::new();
+
+    for value in values {
+        let orch = Orchestra::solo(Core::new());
+        let size = value.chord_size();
+
+        let music = orch
+            .volumes(1)
+            .and_then(|_| orch.vol_next())
+            .and_then(|_| orch.vol_reserve(size))
+            .and_then(|_| value.play(&orch, 0, None, styles, &mut [&mut QuoteAmbiguous(&schema)]))
+            .and_then(|_| orch.vol_end())
+            .and_then(|_| orch.the_end())
+            .and_then(|_| orch.listen())
+            .map_err(|error| error.to_string())?;
+
+        if stream {
+            out.extend_from_slice(b"---\n");
+        }
+
+        out.extend_from_slice(&music);
+
+        if !out.ends_with(b"\n") {
+            out.push(b'\n');
+        }
+    }
+
+    Ok(out)
+}
+
+fn input(args: &Args) -> &str {
+    args.files.first().map_or("-", String::as_str)
+}
+
+fn load(file: &str) -> io::Result<Vec<u8>> {
+    if file == "-" {
+        let mut source = Vec::new();
+        io::stdin().read_to_end(&mut source)?;
+        Ok(source)
+    } else {
+        fs::read(file)
+    }
+}
+
+fn name(file: &str) -> &str {
+    if file == "-" {
+        "<stdin>"
+    } else {
+        file
+    }
+}
+
+/// The error in the form of `file:line:column: message`, which most editors understand.
+/// The message goes on with the error it has been caused by.
+fn describe(file: &str, error: &Error) -> String {
+    let message = match error.source() {
+        Some(source) => format!("{}: {}", error.message(), source),
+        None => error.message().to_string(),
+    };
+
+    match error.location() {
+        Some(location) => format!(
+            "{}:{}:{}: {}",
+            name(file),
+            location.line,
+            location.column,
+            message
+        ),
+        None => format!("{}: {}", name(file), message),
+    }
+}
+
+fn write(out: &[u8]) -> Result<i32, String> {
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+
+    stdout
+        .write_all(out)
+        .and_then(|_| stdout.flush())
+        .map(|_| OK)
+        .map_err(|error| error.to_string())
+}
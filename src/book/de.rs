@@ -0,0 +1,504 @@
+extern crate num;
+extern crate serde;
+extern crate skimmer;
+
+use self::num::ToPrimitive;
+
+use self::serde::de::{
+    self, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+use self::serde::forward_to_deserialize_any;
+
+use self::skimmer::reader::IntoReader;
+
+use crate::book::extractor::pointer::{Expansion, Pointer};
+use crate::book::volume::Volume;
+use crate::book::word::Word;
+use crate::book::Book;
+
+use crate::limits::{LimitError, Limits};
+
+use crate::model::schema::core::Core;
+use crate::model::yamlette::incognitum::IncognitumValue;
+use crate::model::{Tagged, TaggedValue};
+
+use crate::reader::Reader;
+use crate::savant::Savant;
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::error;
+use std::fmt;
+use std::rc::Rc;
+
+#[derive(Debug)]
+pub struct Error {
+    message: Cow<'static, str>,
+    limit: Option<LimitError>,
+}
+
+impl Error {
+    pub fn new<T>(message: T) -> Error
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        Error {
+            message: message.into(),
+            limit: None,
+        }
+    }
+
+    /// The limit the document has exceeded, if that is what the error is about
+    pub fn limit(&self) -> Option<&LimitError> {
+        self.limit.as_ref()
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, fmtter: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmtter, "{}", self.message)
+    }
+}
+
+impl error::Error for Error {}
+
+impl From<LimitError> for Error {
+    fn from(err: LimitError) -> Error {
+        Error {
+            message: Cow::from(err.to_string()),
+            limit: Some(err),
+        }
+    }
+}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::new(msg.to_string())
+    }
+}
+
+/// Deserializes the node the pointer refers to.
+pub fn from_pointer<'a, T>(pointer: Pointer<'a>) -> Result<T, Error>
+where
+    T: de::Deserialize<'a>,
+{
+    T::deserialize(Deserializer::new(pointer))
+}
+
+/// Deserializes the root node of the volume.
+pub fn from_volume<'a, T>(volume: &'a Volume) -> Result<T, Error>
+where
+    T: de::Deserialize<'a>,
+{
+    if let Some(error) = volume.exceeded() {
+        return Err(Error::from(error.clone()));
+    }
+
+    match Pointer::new(volume) {
+        Some(pointer) => from_pointer(pointer),
+        None => Err(Error::new("The volume is empty")),
+    }
+}
+
+/// Deserializes the first volume of the book.
+pub fn from_book<'a, T>(book: &'a Book) -> Result<T, Error>
+where
+    T: de::Deserialize<'a>,
+{
+    match book.volumes.first() {
+        Some(volume) => from_volume(volume),
+        None => Err(Error::new("The book has no volumes")),
+    }
+}
+
+/// Reads the first document of the source with the Core schema and deserializes it.
+pub fn from_str<T>(source: &str) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    from_str_with_limits(source, Limits::default())
+}
+
+/// Reads the first document of the source with the Core schema and deserializes it,
+/// failing with the limit the document exceeds (see `Error::limit`)
+pub fn from_str_with_limits<T>(source: &str, limits: Limits) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    let mut book = Book::new();
+    let mut reader = Reader::with_limits(limits);
+    let mut savant = Savant::new(Core::new());
+
+    book.set_limits(limits);
+
+    reader
+        .read(
+            source.as_bytes().into_reader(),
+            &mut |block| match savant.think(block) {
+                Ok(Some(idea)) => {
+                    book.stamp(idea);
+                    Ok(())
+                }
+                Ok(None) => Ok(()),
+                Err(error) => Err(Cow::from(error.to_string())),
+            },
+        )
+        .map_err(|err| Error {
+            message: Cow::from(format!("{} at {}", err, err.location)),
+            limit: err
+                .limit
+                .and_then(|limit| limit.of(&limits).map(|max| LimitError { limit, max })),
+        })?;
+
+    from_book(&book)
+}
+
+pub struct Deserializer<'a> {
+    pointer: Pointer<'a>,
+    expansion: Rc<RefCell<Expansion>>,
+}
+
+impl<'a> Deserializer<'a> {
+    /// The deserializer of the node, keeping within the limits of its volume
+    pub fn new(pointer: Pointer<'a>) -> Deserializer<'a> {
+        Deserializer::within(pointer, Rc::new(RefCell::new(Expansion::of(pointer))))
+    }
+
+    fn within(pointer: Pointer<'a>, expansion: Rc<RefCell<Expansion>>) -> Deserializer<'a> {
+        Deserializer {
+            pointer: pointer.unalias(),
+            expansion,
+        }
+    }
+
+    /// Steps into the collection, which must not be one of those it is within
+    fn enter(&self) -> Result<(), Error> {
+        if self.expansion.borrow_mut().enter(self.pointer)? {
+            Ok(())
+        } else {
+            Err(Error::new("The alias refers to a collection containing it"))
+        }
+    }
+
+    fn leave(&self) {
+        self.expansion.borrow_mut().leave();
+    }
+
+    fn word(&self) -> &'a Word {
+        self.pointer.to_word()
+    }
+
+    fn failure(word: &Word) -> Error {
+        match *word {
+            Word::Err(ref message, Some(ref location))
+            | Word::Wrn(ref message, Some(ref location)) => {
+                Error::new(format!("{} at {}", message, location))
+            }
+            Word::Err(ref message, None) | Word::Wrn(ref message, None) => {
+                Error::new(message.clone())
+            }
+            Word::UnboundAlias(ref alias) => Error::new(format!("Unbound alias *{}", alias)),
+            ref word => Error::new(format!("Unexpected node {:?}", word)),
+        }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match *self.word() {
+            Word::Null => visitor.visit_unit(),
+            Word::Bool(value) => visitor.visit_bool(value),
+            Word::Int(ref value) => {
+                if let Some(value) = value.to_i64() {
+                    visitor.visit_i64(value)
+                } else if let Some(value) = value.to_u64() {
+                    visitor.visit_u64(value)
+                } else {
+                    Err(Error::new("The integer does not fit into 64 bits"))
+                }
+            }
+            Word::Float(ref value) => match value.to_f64() {
+                Some(value) => visitor.visit_f64(value),
+                None => Err(Error::new("The float cannot be represented as f64")),
+            },
+            Word::Str(ref value) => visitor.visit_borrowed_str(value),
+            Word::Bin(ref value) => visitor.visit_borrowed_bytes(value),
+            Word::Timestamp(ref value) => visitor.visit_string(value.to_string()),
+            Word::Seq(_) => {
+                self.enter()?;
+                let result = visitor.visit_seq(Siblings::new(
+                    self.pointer.into_seq(),
+                    self.expansion.clone(),
+                ));
+                self.leave();
+                result
+            }
+            Word::Map(_) => {
+                self.enter()?;
+                let result = visitor.visit_map(Siblings::new(
+                    self.pointer.into_map(),
+                    self.expansion.clone(),
+                ));
+                self.leave();
+                result
+            }
+            Word::Scalar(TaggedValue::Incognitum(ref value)) => {
+                visitor.visit_borrowed_str(IncognitumValue::get_value(value))
+            }
+            Word::Scalar(ref value) => match value.as_any().downcast_ref::<String>() {
+                Some(value) => visitor.visit_borrowed_str(value),
+                None => Err(Error::new(format!("Unsupported scalar {:?}", value))),
+            },
+            ref word => Err(Deserializer::failure(word)),
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match *self.word() {
+            Word::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match *self.word() {
+            Word::Null => visitor.visit_unit(),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_unit_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match *self.word() {
+            Word::Map(_) => match self.pointer.into_map() {
+                Some(key) => {
+                    let value = key.next_sibling();
+
+                    if value.and_then(|ptr| ptr.next_sibling()).is_some() {
+                        return Err(Error::new("An enum map must have exactly one key"));
+                    }
+
+                    self.enter()?;
+                    let result = visitor.visit_enum(Variant {
+                        key,
+                        value,
+                        expansion: self.expansion.clone(),
+                    });
+                    self.leave();
+                    result
+                }
+                None => Err(Error::new("An enum map must have exactly one key")),
+            },
+            _ => visitor.visit_enum(Variant {
+                key: self.pointer,
+                value: None,
+                expansion: self.expansion,
+            }),
+        }
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf seq tuple tuple_struct map struct identifier
+    }
+}
+
+impl<'de> IntoDeserializer<'de, Error> for Deserializer<'de> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+struct Siblings<'a> {
+    pointer: Option<Pointer<'a>>,
+    expansion: Rc<RefCell<Expansion>>,
+}
+
+impl<'a> Siblings<'a> {
+    fn new(pointer: Option<Pointer<'a>>, expansion: Rc<RefCell<Expansion>>) -> Siblings<'a> {
+        Siblings { pointer, expansion }
+    }
+
+    /// The next node, counted in the expansion
+    fn take(&mut self) -> Result<Option<Pointer<'a>>, Error> {
+        let pointer = self.pointer.take();
+        self.pointer = pointer.and_then(|ptr| ptr.next_sibling());
+
+        if pointer.is_some() {
+            self.expansion.borrow_mut().count()?;
+        }
+
+        Ok(pointer)
+    }
+}
+
+impl<'de> SeqAccess<'de> for Siblings<'de> {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        match self.take()? {
+            Some(pointer) => seed
+                .deserialize(Deserializer::within(pointer, self.expansion.clone()))
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.pointer.map_or(0, |ptr| ptr.count_siblings()))
+    }
+}
+
+impl<'de> MapAccess<'de> for Siblings<'de> {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        match self.take()? {
+            Some(pointer) => seed
+                .deserialize(Deserializer::within(pointer, self.expansion.clone()))
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        match self.take()? {
+            Some(pointer) => {
+                seed.deserialize(Deserializer::within(pointer, self.expansion.clone()))
+            }
+            None => Err(Error::new("A map key has no value")),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.pointer.map_or(0, |ptr| ptr.count_siblings() / 2))
+    }
+}
+
+struct Variant<'a> {
+    key: Pointer<'a>,
+    value: Option<Pointer<'a>>,
+    expansion: Rc<RefCell<Expansion>>,
+}
+
+impl<'de> EnumAccess<'de> for Variant<'de> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self), Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let variant = seed.deserialize(Deserializer::within(self.key, self.expansion.clone()))?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de> VariantAccess<'de> for Variant<'de> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        match self.value {
+            None => Ok(()),
+            Some(pointer) => match *pointer.unalias().to_word() {
+                Word::Null => Ok(()),
+                _ => Err(Error::new("A unit variant cannot have a value")),
+            },
+        }
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        match self.value {
+            Some(pointer) => seed.deserialize(Deserializer::within(pointer, self.expansion)),
+            None => Err(Error::new("A newtype variant must have a value")),
+        }
+    }
+
+    fn tuple_variant<V>(self, _len: usize, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Some(pointer) => de::Deserializer::deserialize_seq(
+                Deserializer::within(pointer, self.expansion),
+                visitor,
+            ),
+            None => Err(Error::new("A tuple variant must have a value")),
+        }
+    }
+
+    fn struct_variant<V>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Some(pointer) => de::Deserializer::deserialize_map(
+                Deserializer::within(pointer, self.expansion),
+                visitor,
+            ),
+            None => Err(Error::new("A struct variant must have a value")),
+        }
+    }
+}
@@ -4,6 +4,9 @@ pub mod traits;
 pub use self::pointer::Pointer;
 pub use self::traits::FromPointer;
 
+#[cfg(feature = "derive")]
+pub use yamlette_derive::FromPointer;
+
 #[macro_export]
 macro_rules! yamlette_reckon {
     ( book ; $book:expr ; [ $( $rules:tt ),* ] ) => {
@@ -45,33 +48,6 @@ macro_rules! yamlette_reckon {
     };
 
 
-    ( ptr ; $pointer:expr ; { $( $k:expr => $v:tt ),* } ) => {
-        $(
-            let mut _pointer = if let Some (p) = $pointer { p.into_map () } else { None };
-            {
-                let mut found = false;
-
-                loop {
-                    if _pointer.is_none () { break; }
-                    let ptr = _pointer.unwrap ();
-
-                    if ptr == $k {
-                        found = true;
-                        _pointer = ptr.next_sibling ();
-                        break;
-                    }
-
-                    _pointer = ptr.next_sibling ();
-                    _pointer = if let Some (p) = _pointer { p.next_sibling () } else { None };
-                }
-
-                if !found { _pointer = None; }
-            }
-            $crate::yamlette_reckon! ( ptr ; _pointer ; $v );
-        )*
-    };
-
-
     ( ptr ; $pointer:expr ; { $( ( $v:ident:$t:ty ) ),* } ) => {
         $( let mut $v: Option<$t> = None; )*
 
@@ -104,6 +80,33 @@ macro_rules! yamlette_reckon {
     };
 
 
+    ( ptr ; $pointer:expr ; { $( $k:expr => $v:tt ),* } ) => {
+        $(
+            let mut _pointer = if let Some (p) = $pointer { p.into_map () } else { None };
+            {
+                let mut found = false;
+
+                loop {
+                    if _pointer.is_none () { break; }
+                    let ptr = _pointer.unwrap ();
+
+                    if ptr == $k {
+                        found = true;
+                        _pointer = ptr.next_sibling ();
+                        break;
+                    }
+
+                    _pointer = ptr.next_sibling ();
+                    _pointer = if let Some (p) = _pointer { p.next_sibling () } else { None };
+                }
+
+                if !found { _pointer = None; }
+            }
+            $crate::yamlette_reckon! ( ptr ; _pointer ; $v );
+        )*
+    };
+
+
     ( ptr ; $pointer:expr ; ( $($v:ident:$t:ty),* ) ) => {
         $(
             let $v: Option<$t> = if let Some (p) = $pointer {
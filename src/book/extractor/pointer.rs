@@ -1,6 +1,15 @@
+extern crate num;
+
+use self::num::BigInt;
+
+use std::borrow::Cow;
+
 use crate::book::extractor::traits::FromPointer;
 use crate::book::volume::Volume;
 use crate::book::word::Word;
+use crate::limits::{Limit, LimitError, Limits};
+use crate::model::Tagged;
+use crate::reader::Span;
 
 #[derive(Copy, Clone)]
 pub struct Pointer<'a> {
@@ -39,13 +48,13 @@ impl<'a> Pointer<'a> {
     }
 
     pub fn unalias(self) -> Pointer<'a> {
-        match self.vol.gist[self.pos] {
-            (_, _, Word::Alias(sz)) => Pointer::unalias(Pointer {
-                vol: self.vol,
-                pos: sz,
-            }),
-            _ => self,
+        let mut pos = self.pos;
+
+        while let (_, _, Word::Alias(anchor)) = self.vol.gist[pos] {
+            pos = anchor;
         }
+
+        Pointer { vol: self.vol, pos }
     }
 
     pub fn to_word(self) -> &'a Word {
@@ -53,6 +62,20 @@ impl<'a> Pointer<'a> {
         word
     }
 
+    /// The source span of the node (of the alias itself, if that's an alias)
+    pub fn span(self) -> Span {
+        self.vol.spans[self.pos]
+    }
+
+    /// The tag of the node, following aliases (`None` for the scalars resolved by the schema)
+    pub fn tag(self) -> Option<Cow<'a, str>> {
+        match *self.unalias().to_word() {
+            Word::Seq(ref tag) | Word::Map(ref tag) => Some(Cow::Borrowed(tag.as_ref())),
+            Word::Scalar(ref value) => Some(value.get_tag()),
+            _ => None,
+        }
+    }
+
     pub fn next_sibling(self) -> Option<Pointer<'a>> {
         let (_, level, _) = self.vol.gist[self.pos];
 
@@ -136,4 +159,255 @@ impl<'a> Pointer<'a> {
             _ => None,
         }
     }
+
+    /// Steps into the child by a map key or a sequence index, following aliases
+    pub fn get(self, segment: &str) -> Option<Pointer<'a>> {
+        let ptr = self.unalias();
+
+        match *ptr.to_word() {
+            Word::Map(_) => {
+                let mut key = ptr.into_map();
+
+                while let Some(k) = key {
+                    let value = k.next_sibling()?;
+
+                    if k.unalias().is_key(segment) {
+                        return Some(value.unalias());
+                    }
+
+                    key = value.next_sibling();
+                }
+
+                None
+            }
+
+            Word::Seq(_) => {
+                let index = parse_index(segment)?;
+                let mut item = ptr.into_seq();
+
+                for _ in 0..index {
+                    item = item?.next_sibling();
+                }
+
+                item.map(Pointer::unalias)
+            }
+
+            _ => None,
+        }
+    }
+
+    /// Looks up the node by an RFC 6901 JSON Pointer, e.g. `/servers/2/ports/0`
+    pub fn pointer(self, path: &str) -> Option<Pointer<'a>> {
+        if path.is_empty() {
+            return Some(self.unalias());
+        }
+
+        if !path.starts_with('/') {
+            return None;
+        }
+
+        let mut ptr = self;
+
+        for token in path[1..].split('/') {
+            let mut segment = String::with_capacity(token.len());
+            let mut chars = token.chars();
+
+            while let Some(c) = chars.next() {
+                match c {
+                    '~' => match chars.next() {
+                        Some('0') => segment.push('~'),
+                        Some('1') => segment.push('/'),
+                        _ => return None,
+                    },
+                    _ => segment.push(c),
+                }
+            }
+
+            ptr = ptr.get(&segment)?;
+        }
+
+        Some(ptr.unalias())
+    }
+
+    /// Looks up the node by a dotted path with optional brackets, e.g. `servers[2].ports[0]`
+    /// or `labels["app.kubernetes.io/name"]`
+    pub fn path(self, path: &str) -> Option<Pointer<'a>> {
+        let mut ptr = self.unalias();
+
+        for segment in parse_path(path)? {
+            ptr = ptr.get(&segment)?;
+        }
+
+        Some(ptr)
+    }
+
+    /// Looks up the node either by a JSON Pointer (if the path starts with `/`) or by a dotted path
+    pub fn query(self, path: &str) -> Option<Pointer<'a>> {
+        if path.starts_with('/') {
+            self.pointer(path)
+        } else {
+            self.path(path)
+        }
+    }
+
+    /// Looks up the node the same way as `query` and converts it into the value
+    pub fn fetch<T>(self, path: &str) -> Option<T>
+    where
+        T: FromPointer<'a>,
+    {
+        self.query(path).and_then(T::from_pointer)
+    }
+
+    fn is_key(self, segment: &str) -> bool {
+        match *self.to_word() {
+            Word::Str(ref string) => string == segment,
+            Word::Int(_) => match (self.into::<BigInt>(), segment.parse::<BigInt>()) {
+                (Some(key), Ok(seg)) => key == seg,
+                _ => false,
+            },
+            Word::Float(_) => match (self.into::<f64>(), segment.parse::<f64>()) {
+                (Some(key), Ok(seg)) => key == seg,
+                _ => false,
+            },
+            Word::Bool(value) => segment == if value { "true" } else { "false" },
+            Word::Null => segment == "~" || segment == "null" || segment.is_empty(),
+            _ => false,
+        }
+    }
+}
+
+/// The collections an extraction is within, so that an alias to one of them
+/// (e.g. `&a [ *a ]`) is caught instead of being expanded endlessly,
+/// and the nodes it has come to, so that it keeps within the limits of the volume
+#[derive(Clone, Debug, Default)]
+pub struct Expansion {
+    within: Vec<usize>,
+    limits: Limits,
+    nodes: usize,
+}
+
+impl Expansion {
+    /// The expansion of the node the pointer refers to, which is the first one counted
+    pub fn of(pointer: Pointer) -> Expansion {
+        Expansion {
+            within: Vec::new(),
+            limits: pointer.vol.limits(),
+            nodes: 1,
+        }
+    }
+
+    /// Steps into the collection the pointer refers to (following aliases),
+    /// unless that is one of the collections being expanded already.
+    /// Such an alias would expand into endless nodes, so it exceeds their limit if there is one.
+    pub fn enter(&mut self, pointer: Pointer) -> Result<bool, LimitError> {
+        let pos = pointer.unalias().pos;
+
+        if self.within.contains(&pos) {
+            Limit::Nodes.check(&self.limits, usize::MAX)?;
+            return Ok(false);
+        }
+
+        self.within.push(pos);
+        Limit::Depth.check(&self.limits, self.within.len())?;
+
+        Ok(true)
+    }
+
+    /// Steps out of the collection entered last
+    pub fn leave(&mut self) {
+        self.within.pop();
+    }
+
+    /// Counts one more node of the expanded tree
+    pub fn count(&mut self) -> Result<(), LimitError> {
+        self.nodes += 1;
+        Limit::Nodes.check(&self.limits, self.nodes)
+    }
+}
+
+/// An array index of RFC 6901: `0` or digits with no leading zero (no sign either)
+pub(crate) fn parse_index(segment: &str) -> Option<usize> {
+    if !segment.bytes().all(|b| b.is_ascii_digit())
+        || (segment.len() > 1 && segment.starts_with('0'))
+    {
+        return None;
+    }
+
+    segment.parse().ok()
+}
+
+/// Splits a dotted path into segments; brackets hold indexes or quoted keys
+fn parse_path(path: &str) -> Option<Vec<String>> {
+    let mut segments = Vec::new();
+    let mut chars = path.chars().peekable();
+    let mut expect_segment = !path.is_empty() && !path.starts_with('[');
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            '.' if !expect_segment => {
+                chars.next();
+                expect_segment = true;
+            }
+
+            '[' => {
+                chars.next();
+
+                let mut segment = String::new();
+
+                match chars.peek() {
+                    Some(&quote) if quote == '"' || quote == '\'' => {
+                        chars.next();
+
+                        loop {
+                            match chars.next()? {
+                                '\\' => segment.push(chars.next()?),
+                                c if c == quote => break,
+                                c => segment.push(c),
+                            }
+                        }
+
+                        if chars.next()? != ']' {
+                            return None;
+                        }
+                    }
+                    _ => loop {
+                        match chars.next()? {
+                            ']' => break,
+                            c => segment.push(c),
+                        }
+                    },
+                }
+
+                segments.push(segment);
+                expect_segment = false;
+            }
+
+            _ if expect_segment => {
+                let mut segment = String::new();
+
+                while let Some(&c) = chars.peek() {
+                    if c == '.' || c == '[' {
+                        break;
+                    }
+                    segment.push(c);
+                    chars.next();
+                }
+
+                if segment.is_empty() {
+                    return None;
+                }
+
+                segments.push(segment);
+                expect_segment = false;
+            }
+
+            _ => return None,
+        }
+    }
+
+    if expect_segment {
+        None
+    } else {
+        Some(segments)
+    }
 }
@@ -8,6 +8,7 @@ use std::collections::HashMap;
 use std::hash::Hash;
 
 use crate::book::extractor::pointer::Pointer;
+use crate::model::yaml::timestamp::TimestampValue;
 
 pub trait FromPointer<'a>: Sized {
     fn from_pointer(pointer: Pointer<'a>) -> Option<Self>;
@@ -62,6 +63,29 @@ from_pointer_impl! (link BigInt);
 from_pointer_impl!(Vec<u8>);
 from_pointer_impl! (link Vec<u8>);
 
+from_pointer_impl!(TimestampValue);
+from_pointer_impl! (link TimestampValue);
+
+#[cfg(feature = "chrono")]
+from_pointer_impl!(chrono::NaiveDate);
+#[cfg(feature = "chrono")]
+from_pointer_impl!(chrono::NaiveTime);
+#[cfg(feature = "chrono")]
+from_pointer_impl!(chrono::NaiveDateTime);
+#[cfg(feature = "chrono")]
+from_pointer_impl!(chrono::DateTime<chrono::FixedOffset>);
+#[cfg(feature = "chrono")]
+from_pointer_impl!(chrono::DateTime<chrono::Utc>);
+
+#[cfg(feature = "time")]
+from_pointer_impl!(time::Date);
+#[cfg(feature = "time")]
+from_pointer_impl!(time::Time);
+#[cfg(feature = "time")]
+from_pointer_impl!(time::PrimitiveDateTime);
+#[cfg(feature = "time")]
+from_pointer_impl!(time::OffsetDateTime);
+
 pub trait List<'a>: Sized {
     fn list_new() -> Self;
 
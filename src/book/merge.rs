@@ -0,0 +1,367 @@
+extern crate num;
+
+use self::num::{BigInt, ToPrimitive};
+
+use crate::book::word::Word;
+use crate::model::TaggedValue;
+use crate::reader::Span;
+
+type Entry = (Option<String>, usize, Word);
+
+/// A map entry (key, value) to be merged in
+#[derive(Clone, Copy)]
+enum Pair {
+    /// Both nodes belong to an inline map and have not been moved yet
+    Inline(usize, usize),
+
+    /// Both nodes have already been placed into the resolved gist
+    Placed(usize, usize),
+}
+
+/// Rebuilds a volume gist, resolving the merge keys (`<<`) accordingly to
+/// [the merge key type](https://yaml.org/type/merge.html) rules:
+///   - keys of the map itself override the merged ones,
+///   - a map earlier in the sequence of merged maps overrides the later ones.
+///
+/// Merged in entries become aliases of the original nodes.
+/// A merge key that does not refer to a map or a sequence of maps stays an ordinary key.
+pub struct Merger {
+    old: Vec<Option<Entry>>,
+    old_spans: Vec<Span>,
+    ends: Vec<usize>,
+
+    remap: Vec<Option<usize>>,
+    done: Vec<bool>,
+
+    gist: Vec<Entry>,
+    spans: Vec<Span>,
+}
+
+impl Merger {
+    pub fn new(gist: Vec<Entry>, spans: Vec<Span>) -> Merger {
+        let len = gist.len();
+
+        let mut ends: Vec<usize> = vec![len; len];
+        let mut stack: Vec<usize> = Vec::with_capacity(16);
+
+        for (ix, entry) in gist.iter().enumerate() {
+            while let Some(&top) = stack.last() {
+                if gist[top].1 < entry.1 {
+                    break;
+                }
+                ends[top] = ix;
+                stack.pop();
+            }
+            stack.push(ix);
+        }
+
+        Merger {
+            old: gist.into_iter().map(Some).collect(),
+            old_spans: spans,
+            ends,
+
+            remap: vec![None; len],
+            done: vec![false; len],
+
+            gist: Vec::with_capacity(len),
+            spans: Vec::with_capacity(len),
+        }
+    }
+
+    pub fn resolve(mut self) -> (Vec<Entry>, Vec<Span>) {
+        let mut ix = 0;
+
+        while ix < self.old.len() {
+            let level = self.old[ix].as_ref().map_or(0, |entry| entry.1);
+            self.emit(ix, level);
+            ix = self.ends[ix];
+        }
+
+        (self.gist, self.spans)
+    }
+
+    fn children(&self, ix: usize) -> Vec<usize> {
+        let mut result = Vec::new();
+
+        let mut child = ix + 1;
+        while child < self.ends[ix] {
+            result.push(child);
+            child = self.ends[child];
+        }
+
+        result
+    }
+
+    fn placed_children(&self, ix: usize) -> Vec<usize> {
+        let level = self.gist[ix].1;
+
+        self.gist[ix + 1..]
+            .iter()
+            .take_while(|entry| entry.1 > level)
+            .enumerate()
+            .filter(|&(_, entry)| entry.1 == level + 1)
+            .map(|(jx, _)| ix + 1 + jx)
+            .collect()
+    }
+
+    fn placed_target(&self, mut ix: usize) -> usize {
+        while let Word::Alias(target) = self.gist[ix].2 {
+            ix = target;
+        }
+        ix
+    }
+
+    fn word(&self, ix: usize) -> Option<&Word> {
+        match self.old[ix] {
+            Some((_, _, Word::Alias(target))) => self.word(target),
+            Some((_, _, ref word)) => Some(word),
+            None => self.remap[ix].map(|jx| &self.gist[self.placed_target(jx)].2),
+        }
+    }
+
+    fn pair_key(&self, pair: Pair) -> Option<&Word> {
+        match pair {
+            Pair::Inline(key, _) => self.word(key),
+            Pair::Placed(key, _) => Some(&self.gist[self.placed_target(key)].2),
+        }
+    }
+
+    /// Only a plain `<<` or the one tagged `!!merge` is a merge key, a quoted `'<<'` is an ordinary string.
+    /// The schemas other than YAML 1.1 leave the plain `<<` a string, which is told from
+    /// the quoted one by its span covering the two characters only
+    fn is_merge_key(&self, ix: usize) -> bool {
+        match self.old[ix] {
+            Some((_, _, Word::Scalar(TaggedValue::Merge(_)))) => true,
+            Some((_, _, Word::Str(ref key))) if key == "<<" => self
+                .old_spans
+                .get(ix)
+                .is_some_and(|span| span.end.offset - span.start.offset == 2),
+            _ => false,
+        }
+    }
+
+    /// Pairs of a placed map, or None if that's not a finished map
+    fn placed_pairs(&self, ix: usize) -> Option<Vec<Pair>> {
+        let ix = self.placed_target(ix);
+
+        match self.gist[ix].2 {
+            Word::Map(_) => Some(
+                self.placed_children(ix)
+                    .chunks(2)
+                    .filter(|pair| pair.len() == 2)
+                    .map(|pair| Pair::Placed(pair[0], pair[1]))
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Pairs of the map an alias refers to
+    fn aliased_pairs(&self, target: usize) -> Option<Vec<Pair>> {
+        if !self.done[target] {
+            return None;
+        }
+        self.remap[target].and_then(|ix| self.placed_pairs(ix))
+    }
+
+    fn inline_pairs(&self, ix: usize) -> Vec<Pair> {
+        self.children(ix)
+            .chunks(2)
+            .filter(|pair| pair.len() == 2)
+            .map(|pair| Pair::Inline(pair[0], pair[1]))
+            .collect()
+    }
+
+    /// All the pairs a merge value brings in, ordered by precedence
+    fn sources(&self, value: usize) -> Option<Vec<Pair>> {
+        match self.old[value] {
+            Some((_, _, Word::Alias(target))) => {
+                if !self.done[target] {
+                    return None;
+                }
+
+                let ix = self.placed_target(self.remap[target]?);
+
+                match self.gist[ix].2 {
+                    Word::Map(_) => self.placed_pairs(ix),
+                    Word::Seq(_) => {
+                        let mut result = Vec::new();
+                        for item in self.placed_children(ix) {
+                            result.extend(self.placed_pairs(item)?);
+                        }
+                        Some(result)
+                    }
+                    _ => None,
+                }
+            }
+
+            Some((_, _, Word::Map(_))) => Some(self.inline_pairs(value)),
+
+            Some((_, _, Word::Seq(_))) => {
+                let mut result = Vec::new();
+
+                for item in self.children(value) {
+                    match self.old[item] {
+                        Some((_, _, Word::Alias(target))) => {
+                            result.extend(self.aliased_pairs(target)?)
+                        }
+                        Some((_, _, Word::Map(_))) => result.extend(self.inline_pairs(item)),
+                        _ => return None,
+                    }
+                }
+
+                Some(result)
+            }
+
+            _ => None,
+        }
+    }
+
+    fn push(&mut self, entry: Entry, span: Span) -> usize {
+        self.gist.push(entry);
+        self.spans.push(span);
+        self.gist.len() - 1
+    }
+
+    fn emit(&mut self, ix: usize, level: usize) {
+        let (anchor, _, word) = match self.old[ix].take() {
+            Some(entry) => entry,
+            None => return,
+        };
+
+        let word = match word {
+            Word::Alias(target) => match self.remap[target] {
+                Some(jx) => Word::Alias(jx),
+                None => Word::UnboundAlias(self.anchor_of(target)),
+            },
+            word => word,
+        };
+
+        let is_map = matches!(word, Word::Map(_));
+
+        let jx = self.push((anchor, level, word), self.old_spans[ix]);
+        self.remap[ix] = Some(jx);
+
+        if is_map {
+            self.emit_map(ix, level);
+        } else {
+            for child in self.children(ix) {
+                self.emit(child, level + 1);
+            }
+        }
+
+        self.done[ix] = true;
+    }
+
+    fn anchor_of(&self, ix: usize) -> String {
+        match self.old[ix] {
+            Some((Some(ref anchor), _, _)) => anchor.clone(),
+            _ => match self.remap[ix] {
+                Some(jx) => self.gist[jx].0.clone().unwrap_or_default(),
+                None => String::new(),
+            },
+        }
+    }
+
+    fn emit_map(&mut self, ix: usize, level: usize) {
+        let children = self.children(ix);
+
+        if !children.len().is_multiple_of(2) {
+            for child in children {
+                self.emit(child, level + 1);
+            }
+            return;
+        }
+
+        let pairs: Vec<(usize, usize)> =
+            children.chunks(2).map(|pair| (pair[0], pair[1])).collect();
+
+        let merges: Vec<Option<Vec<Pair>>> = pairs
+            .iter()
+            .map(|&(key, value)| {
+                if self.is_merge_key(key) {
+                    self.sources(value)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let own: Vec<usize> = pairs
+            .iter()
+            .zip(merges.iter())
+            .filter(|&(_, merge)| merge.is_none())
+            .map(|(&(key, _), _)| key)
+            .collect();
+
+        let mut accepted: Vec<Pair> = Vec::new();
+        let mut selected: Vec<Option<Vec<Pair>>> = Vec::with_capacity(pairs.len());
+
+        for merge in merges {
+            selected.push(merge.map(|sources| {
+                let mut chosen = Vec::new();
+
+                for pair in sources {
+                    let key = self.pair_key(pair);
+
+                    let overridden = own.iter().any(|&own| same_key(self.word(own), key))
+                        || accepted
+                            .iter()
+                            .any(|&other| same_key(self.pair_key(other), key));
+
+                    if !overridden {
+                        accepted.push(pair);
+                        chosen.push(pair);
+                    }
+                }
+
+                chosen
+            }));
+        }
+
+        for (&(key, value), chosen) in pairs.iter().zip(selected) {
+            match chosen {
+                None => {
+                    self.emit(key, level + 1);
+                    self.emit(value, level + 1);
+                }
+                Some(chosen) => {
+                    for pair in chosen {
+                        match pair {
+                            Pair::Inline(key, value) => {
+                                self.emit(key, level + 1);
+                                self.emit(value, level + 1);
+                            }
+                            Pair::Placed(key, value) => {
+                                self.alias(key, level + 1);
+                                self.alias(value, level + 1);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn alias(&mut self, jx: usize, level: usize) {
+        let span = self.spans[jx];
+        self.push((None, level, Word::Alias(jx)), span);
+    }
+}
+
+/// Whether two scalar keys are equal
+fn same_key(left: Option<&Word>, right: Option<&Word>) -> bool {
+    match (left, right) {
+        (Some(Word::Str(l)), Some(Word::Str(r))) => l == r,
+        (Some(Word::Bool(l)), Some(Word::Bool(r))) => l == r,
+        (Some(Word::Null), Some(Word::Null)) => true,
+        (Some(Word::Bin(l)), Some(Word::Bin(r))) => l == r,
+        (Some(Word::Int(l)), Some(Word::Int(r))) => {
+            let (l, r): (BigInt, BigInt) = (l.clone().into(), r.clone().into());
+            l == r
+        }
+        (Some(Word::Float(l)), Some(Word::Float(r))) => l.to_f64() == r.to_f64(),
+        (Some(Word::Timestamp(l)), Some(Word::Timestamp(r))) => l.to_string() == r.to_string(),
+        _ => false,
+    }
+}
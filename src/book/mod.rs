@@ -2,19 +2,34 @@ extern crate skimmer;
 
 use self::skimmer::data::Datum;
 
+#[cfg(feature = "serde")]
+pub mod de;
 pub mod extractor;
+mod merge;
+#[cfg(feature = "validator")]
+pub mod validator;
+pub mod value;
 pub mod volume;
 pub mod word;
 
+use crate::error::{Error, ErrorKind};
+use crate::limits::Limits;
 use crate::model::schema::Schema;
+use crate::reader::Location;
 use crate::sage::{Idea, Sage};
 
+use self::extractor::pointer::Pointer;
+use self::extractor::traits::FromPointer;
 use self::volume::Volume;
+use self::word::Word;
 
 use std::sync::mpsc::Receiver;
 
 pub struct Book {
     pub volumes: Vec<Volume>,
+
+    merge_keys: bool,
+    limits: Limits,
 }
 
 impl Book {
@@ -38,13 +53,74 @@ impl Book {
     pub fn with_capacity(size: usize) -> Book {
         Book {
             volumes: Vec::with_capacity(size),
+            merge_keys: false,
+            limits: Limits::default(),
+        }
+    }
+
+    /// Whether to resolve the merge keys (`<<`) in the volumes started after the call
+    pub fn set_merge_keys(&mut self, merge_keys: bool) {
+        self.merge_keys = merge_keys;
+    }
+
+    /// The limits on the aliases and the expanded nodes of the volumes started after the call
+    pub fn set_limits(&mut self, limits: Limits) {
+        self.limits = limits;
+    }
+
+    /// Looks up the node in the first volume by a JSON Pointer or a dotted path (see `Pointer::query`)
+    pub fn query(&self, path: &str) -> Option<Pointer<'_>> {
+        self.volumes.first().and_then(Pointer::new)?.query(path)
+    }
+
+    /// Looks up the node in the first volume and converts it into the value
+    pub fn fetch<'a, T>(&'a self, path: &str) -> Option<T>
+    where
+        T: FromPointer<'a>,
+    {
+        self.query(path).and_then(T::from_pointer)
+    }
+
+    /// Returns the first error stamped into the volumes (a read error, a scalar
+    /// that could not be decoded or an exceeded limit), so that a book can be checked with `?`
+    pub fn check(&self) -> Result<(), Error> {
+        for volume in &self.volumes {
+            if let Some(error) = volume.exceeded() {
+                return Err(Error::from(error.clone()));
+            }
+
+            for (idx, &(_, _, ref word)) in volume.gist.iter().enumerate() {
+                if let Word::Err(ref message, ref location) = *word {
+                    return Err(match *location {
+                        Some(ref location) => {
+                            Error::new(ErrorKind::Read, message.clone()).at(location.clone())
+                        }
+                        None => {
+                            let error = Error::new(ErrorKind::Sage, message.clone());
+                            match volume.spans.get(idx) {
+                                Some(span) if span.start.line > 0 => {
+                                    error.at(Location::from(span.start))
+                                }
+                                _ => error,
+                            }
+                        }
+                    });
+                }
+            }
         }
+
+        Ok(())
     }
 
     pub fn stamp(&mut self, idea: Idea) -> bool {
         match idea {
             Idea::Done => return true,
-            Idea::Dawn => self.volumes.push(Volume::new()),
+            Idea::Dawn => {
+                let mut volume = Volume::new();
+                volume.set_merge_keys(self.merge_keys);
+                volume.set_limits(self.limits);
+                self.volumes.push(volume)
+            }
             Idea::Dusk => {
                 if let Some(vol) = self.volumes.last_mut() {
                     vol.complete()
@@ -0,0 +1,726 @@
+extern crate num;
+extern crate regex;
+
+use self::num::{BigInt, ToPrimitive};
+use self::regex::Regex;
+
+use crate::book::extractor::pointer::Pointer;
+use crate::book::extractor::traits::FromPointer;
+use crate::book::value::Value;
+use crate::book::volume::Volume;
+use crate::book::word::Word;
+
+use crate::model::yamlette::incognitum::IncognitumValue;
+use crate::model::{Tagged, TaggedValue};
+
+use crate::reader::Span;
+
+use std::borrow::Cow;
+use std::error;
+use std::fmt;
+
+/// A node type of the Core schema, as named by JSON Schema (plus `binary`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Type {
+    Null,
+    Boolean,
+    Integer,
+    Number,
+    String,
+    Binary,
+    Array,
+    Object,
+}
+
+impl Type {
+    fn parse(name: &str) -> Option<Type> {
+        match name {
+            "null" => Some(Type::Null),
+            "boolean" => Some(Type::Boolean),
+            "integer" => Some(Type::Integer),
+            "number" => Some(Type::Number),
+            "string" => Some(Type::String),
+            "binary" => Some(Type::Binary),
+            "array" => Some(Type::Array),
+            "object" => Some(Type::Object),
+            _ => None,
+        }
+    }
+
+    fn of(word: &Word) -> Option<Type> {
+        match *word {
+            Word::Null => Some(Type::Null),
+            Word::Bool(_) => Some(Type::Boolean),
+            Word::Int(_) => Some(Type::Integer),
+            Word::Float(_) => Some(Type::Number),
+            Word::Str(_) => Some(Type::String),
+            Word::Bin(_) => Some(Type::Binary),
+            Word::Timestamp(_) => Some(Type::String),
+            Word::Seq(_) => Some(Type::Array),
+            Word::Map(_) => Some(Type::Object),
+            Word::Scalar(TaggedValue::Incognitum(_)) => Some(Type::String),
+            Word::Scalar(ref value) if value.as_any().is::<String>() => Some(Type::String),
+            _ => None,
+        }
+    }
+
+    /// Integers are numbers as well
+    fn matches(self, actual: Type) -> bool {
+        self == actual || (self == Type::Number && actual == Type::Integer)
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, fmtter: &mut fmt::Formatter) -> fmt::Result {
+        fmtter.write_str(match *self {
+            Type::Null => "null",
+            Type::Boolean => "boolean",
+            Type::Integer => "integer",
+            Type::Number => "number",
+            Type::String => "string",
+            Type::Binary => "binary",
+            Type::Array => "array",
+            Type::Object => "object",
+        })
+    }
+}
+
+/// A schema that cannot be understood
+#[derive(Clone, Debug)]
+pub struct SchemaError {
+    /// JSON Pointer to the faulty keyword within the schema
+    pub path: String,
+    pub message: Cow<'static, str>,
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, fmtter: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmtter, "{} at #{}", self.message, self.path)
+    }
+}
+
+impl error::Error for SchemaError {}
+
+/// A node that does not conform to the schema
+#[derive(Clone, Debug)]
+pub struct Violation {
+    /// JSON Pointer to the node within the document
+    pub path: String,
+    pub span: Span,
+    pub message: Cow<'static, str>,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, fmtter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmtter,
+            "{} at #{} ({})",
+            self.message, self.path, self.span.start
+        )
+    }
+}
+
+enum Additional {
+    Any,
+    Forbidden,
+    Rule(Box<Rule>),
+}
+
+/// A compiled schema node
+struct Rule {
+    accept: bool,
+
+    types: Option<Vec<Type>>,
+    enumeration: Option<Vec<Value>>,
+
+    minimum: Option<f64>,
+    maximum: Option<f64>,
+    exclusive_minimum: Option<f64>,
+    exclusive_maximum: Option<f64>,
+
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    pattern: Option<Regex>,
+
+    items: Option<Box<Rule>>,
+    min_items: Option<usize>,
+    max_items: Option<usize>,
+    unique_items: bool,
+
+    properties: Vec<(String, Rule)>,
+    additional: Additional,
+    required: Vec<String>,
+    min_properties: Option<usize>,
+    max_properties: Option<usize>,
+
+    all_of: Vec<Rule>,
+    any_of: Vec<Rule>,
+    one_of: Vec<Rule>,
+    not: Option<Box<Rule>>,
+}
+
+/// Checks volumes against a JSON Schema, written in YAML or JSON.
+///
+/// Understands the `type`, `enum`, `const`, `minimum`, `maximum`, `exclusiveMinimum`,
+/// `exclusiveMaximum`, `minLength`, `maxLength`, `pattern`, `items`, `minItems`, `maxItems`,
+/// `uniqueItems`, `properties`, `additionalProperties`, `required`, `minProperties`,
+/// `maxProperties`, `allOf`, `anyOf`, `oneOf` and `not` keywords. Others are ignored.
+pub struct Validator {
+    root: Rule,
+}
+
+impl Validator {
+    pub fn new(schema: &Value) -> Result<Validator, SchemaError> {
+        Ok(Validator {
+            root: Rule::compile(schema, &mut String::new())?,
+        })
+    }
+
+    /// Takes the schema out of the volume root
+    pub fn from_volume(volume: &Volume) -> Result<Validator, SchemaError> {
+        match Value::from_volume(volume) {
+            Some(schema) => Validator::new(&schema),
+            None => Err(SchemaError {
+                path: String::new(),
+                message: Cow::from("The schema cannot be read"),
+            }),
+        }
+    }
+
+    /// All the violations of the volume root, in the document order
+    pub fn validate(&self, volume: &Volume) -> Vec<Violation> {
+        match Pointer::new(volume) {
+            Some(pointer) => self.validate_pointer(pointer),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn validate_pointer(&self, pointer: Pointer) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        self.root
+            .check(pointer, &mut String::new(), &mut violations);
+        violations
+    }
+
+    pub fn is_valid(&self, volume: &Volume) -> bool {
+        self.validate(volume).is_empty()
+    }
+}
+
+impl Rule {
+    fn empty(accept: bool) -> Rule {
+        Rule {
+            accept,
+
+            types: None,
+            enumeration: None,
+
+            minimum: None,
+            maximum: None,
+            exclusive_minimum: None,
+            exclusive_maximum: None,
+
+            min_length: None,
+            max_length: None,
+            pattern: None,
+
+            items: None,
+            min_items: None,
+            max_items: None,
+            unique_items: false,
+
+            properties: Vec::new(),
+            additional: Additional::Any,
+            required: Vec::new(),
+            min_properties: None,
+            max_properties: None,
+
+            all_of: Vec::new(),
+            any_of: Vec::new(),
+            one_of: Vec::new(),
+            not: None,
+        }
+    }
+
+    fn compile(schema: &Value, path: &mut String) -> Result<Rule, SchemaError> {
+        let map = match *schema {
+            Value::Bool(accept) => return Ok(Rule::empty(accept)),
+            Value::Map(ref map) => map,
+            _ => return Err(failure(path, "A schema must be a map or a boolean")),
+        };
+
+        let mut rule = Rule::empty(true);
+
+        for (key, value) in map {
+            let keyword = match key.as_str() {
+                Some(keyword) => keyword,
+                None => continue,
+            };
+
+            let len = path.len();
+            push_segment(path, keyword);
+
+            match keyword {
+                "type" => {
+                    let names: Vec<&Value> = match *value {
+                        Value::Seq(ref names) => names.iter().collect(),
+                        ref name => vec![name],
+                    };
+
+                    let mut types = Vec::with_capacity(names.len());
+                    for name in names {
+                        match name.as_str().and_then(Type::parse) {
+                            Some(t) => types.push(t),
+                            None => return Err(failure(path, "Unknown type")),
+                        }
+                    }
+                    rule.types = Some(types);
+                }
+
+                "enum" => match *value {
+                    Value::Seq(ref values) => rule.enumeration = Some(values.clone()),
+                    _ => return Err(failure(path, "Expected a sequence")),
+                },
+                "const" => rule.enumeration = Some(vec![value.clone()]),
+
+                "minimum" => rule.minimum = Some(number(value, path)?),
+                "maximum" => rule.maximum = Some(number(value, path)?),
+                "exclusiveMinimum" => rule.exclusive_minimum = Some(number(value, path)?),
+                "exclusiveMaximum" => rule.exclusive_maximum = Some(number(value, path)?),
+
+                "minLength" => rule.min_length = Some(count(value, path)?),
+                "maxLength" => rule.max_length = Some(count(value, path)?),
+                "pattern" => {
+                    let pattern = value
+                        .as_str()
+                        .ok_or_else(|| failure(path, "Expected a string"))?;
+                    match Regex::new(pattern) {
+                        Ok(regex) => rule.pattern = Some(regex),
+                        Err(err) => return Err(failure(path, err.to_string())),
+                    }
+                }
+
+                "items" => rule.items = Some(Box::new(Rule::compile(value, path)?)),
+                "minItems" => rule.min_items = Some(count(value, path)?),
+                "maxItems" => rule.max_items = Some(count(value, path)?),
+                "uniqueItems" => {
+                    rule.unique_items = value
+                        .as_bool()
+                        .ok_or_else(|| failure(path, "Expected a boolean"))?
+                }
+
+                "properties" => {
+                    let properties = value
+                        .as_map()
+                        .ok_or_else(|| failure(path, "Expected a map"))?;
+
+                    for (name, schema) in properties {
+                        let name = match name.as_str() {
+                            Some(name) => name,
+                            None => return Err(failure(path, "Property names must be strings")),
+                        };
+
+                        let len = path.len();
+                        push_segment(path, name);
+                        rule.properties
+                            .push((name.to_string(), Rule::compile(schema, path)?));
+                        path.truncate(len);
+                    }
+                }
+                "additionalProperties" => {
+                    rule.additional = match *value {
+                        Value::Bool(true) => Additional::Any,
+                        Value::Bool(false) => Additional::Forbidden,
+                        ref schema => Additional::Rule(Box::new(Rule::compile(schema, path)?)),
+                    }
+                }
+                "required" => {
+                    let names = value
+                        .as_seq()
+                        .ok_or_else(|| failure(path, "Expected a sequence"))?;
+
+                    for name in names {
+                        match name.as_str() {
+                            Some(name) => rule.required.push(name.to_string()),
+                            None => return Err(failure(path, "Required keys must be strings")),
+                        }
+                    }
+                }
+                "minProperties" => rule.min_properties = Some(count(value, path)?),
+                "maxProperties" => rule.max_properties = Some(count(value, path)?),
+
+                "allOf" => rule.all_of = Rule::compile_all(value, path)?,
+                "anyOf" => rule.any_of = Rule::compile_all(value, path)?,
+                "oneOf" => rule.one_of = Rule::compile_all(value, path)?,
+                "not" => rule.not = Some(Box::new(Rule::compile(value, path)?)),
+
+                _ => (),
+            };
+
+            path.truncate(len);
+        }
+
+        Ok(rule)
+    }
+
+    fn compile_all(schemas: &Value, path: &mut String) -> Result<Vec<Rule>, SchemaError> {
+        let schemas = schemas
+            .as_seq()
+            .ok_or_else(|| failure(path, "Expected a sequence"))?;
+
+        let mut rules = Vec::with_capacity(schemas.len());
+
+        for (idx, schema) in schemas.iter().enumerate() {
+            let len = path.len();
+            push_segment(path, &idx.to_string());
+            rules.push(Rule::compile(schema, path)?);
+            path.truncate(len);
+        }
+
+        Ok(rules)
+    }
+
+    fn passes(&self, pointer: Pointer) -> bool {
+        let mut violations = Vec::new();
+        self.check(pointer, &mut String::new(), &mut violations);
+        violations.is_empty()
+    }
+
+    fn check(&self, pointer: Pointer, path: &mut String, violations: &mut Vec<Violation>) {
+        let span = pointer.span();
+        let pointer = pointer.unalias();
+
+        let mut violation = |message: Cow<'static, str>| {
+            violations.push(Violation {
+                path: path.clone(),
+                span,
+                message,
+            })
+        };
+
+        if !self.accept {
+            return violation(Cow::from("No value is allowed"));
+        }
+
+        let word = pointer.to_word();
+
+        let actual = match Type::of(word) {
+            Some(actual) => actual,
+            None => {
+                return violation(match *word {
+                    Word::Err(ref message, _) | Word::Wrn(ref message, _) => message.clone(),
+                    Word::UnboundAlias(ref alias) => Cow::from(format!("Unbound alias *{}", alias)),
+                    ref word => Cow::from(format!("Unsupported node {:?}", word)),
+                })
+            }
+        };
+
+        if let Some(ref types) = self.types {
+            if !types.iter().any(|t| t.matches(actual)) {
+                let expected: Vec<String> = types.iter().map(Type::to_string).collect();
+                return violation(Cow::from(format!(
+                    "Expected {}, found {}",
+                    expected.join(" or "),
+                    actual
+                )));
+            }
+        }
+
+        if let Some(ref values) = self.enumeration {
+            match Value::from_pointer(pointer) {
+                Some(ref value) if values.contains(value) => (),
+                _ => violation(Cow::from("The value is not one of the allowed")),
+            }
+        }
+
+        if let Some(number) = to_f64(word) {
+            if let Some(minimum) = self.minimum {
+                if number < minimum {
+                    violation(Cow::from(format!("The value is less than {}", minimum)));
+                }
+            }
+            if let Some(maximum) = self.maximum {
+                if number > maximum {
+                    violation(Cow::from(format!("The value is greater than {}", maximum)));
+                }
+            }
+            if let Some(minimum) = self.exclusive_minimum {
+                if number <= minimum {
+                    violation(Cow::from(format!(
+                        "The value must be greater than {}",
+                        minimum
+                    )));
+                }
+            }
+            if let Some(maximum) = self.exclusive_maximum {
+                if number >= maximum {
+                    violation(Cow::from(format!(
+                        "The value must be less than {}",
+                        maximum
+                    )));
+                }
+            }
+        }
+
+        if let Some(string) = to_str(word) {
+            let length = string.chars().count();
+
+            if let Some(min) = self.min_length {
+                if length < min {
+                    violation(Cow::from(format!("The string is shorter than {}", min)));
+                }
+            }
+            if let Some(max) = self.max_length {
+                if length > max {
+                    violation(Cow::from(format!("The string is longer than {}", max)));
+                }
+            }
+            if let Some(ref pattern) = self.pattern {
+                if !pattern.is_match(string) {
+                    violation(Cow::from(format!(
+                        "The string does not match /{}/",
+                        pattern.as_str()
+                    )));
+                }
+            }
+        }
+
+        match actual {
+            Type::Array => self.check_seq(pointer, path, violations),
+            Type::Object => self.check_map(pointer, path, violations),
+            _ => (),
+        };
+
+        self.check_compound(pointer, span, path, violations);
+    }
+
+    fn check_seq(&self, pointer: Pointer, path: &mut String, violations: &mut Vec<Violation>) {
+        let items = children(pointer.into_seq());
+        let span = pointer.span();
+
+        if let Some(min) = self.min_items {
+            if items.len() < min {
+                violations.push(violation(
+                    path,
+                    span,
+                    format!("Expected at least {} items", min),
+                ));
+            }
+        }
+        if let Some(max) = self.max_items {
+            if items.len() > max {
+                violations.push(violation(
+                    path,
+                    span,
+                    format!("Expected at most {} items", max),
+                ));
+            }
+        }
+
+        if self.unique_items {
+            let values: Vec<Option<Value>> = items
+                .iter()
+                .map(|item| Value::from_pointer(*item))
+                .collect();
+
+            for (idx, value) in values.iter().enumerate() {
+                if value.is_some() && values[..idx].contains(value) {
+                    let len = path.len();
+                    push_segment(path, &idx.to_string());
+                    violations.push(violation(path, items[idx].span(), "The item is not unique"));
+                    path.truncate(len);
+                }
+            }
+        }
+
+        if let Some(ref rule) = self.items {
+            for (idx, item) in items.iter().enumerate() {
+                let len = path.len();
+                push_segment(path, &idx.to_string());
+                rule.check(*item, path, violations);
+                path.truncate(len);
+            }
+        }
+    }
+
+    fn check_map(&self, pointer: Pointer, path: &mut String, violations: &mut Vec<Violation>) {
+        let nodes = children(pointer.into_map());
+        let span = pointer.span();
+
+        let pairs: Vec<(String, Pointer, Pointer)> = nodes
+            .chunks(2)
+            .filter(|pair| pair.len() == 2)
+            .map(|pair| (key_name(pair[0]), pair[0], pair[1]))
+            .collect();
+
+        if let Some(min) = self.min_properties {
+            if pairs.len() < min {
+                violations.push(violation(
+                    path,
+                    span,
+                    format!("Expected at least {} keys", min),
+                ));
+            }
+        }
+        if let Some(max) = self.max_properties {
+            if pairs.len() > max {
+                violations.push(violation(
+                    path,
+                    span,
+                    format!("Expected at most {} keys", max),
+                ));
+            }
+        }
+
+        for name in &self.required {
+            if !pairs.iter().any(|pair| pair.0 == *name) {
+                violations.push(violation(
+                    path,
+                    span,
+                    format!("Missing the required key \"{}\"", name),
+                ));
+            }
+        }
+
+        for (name, key, value) in pairs {
+            let len = path.len();
+            push_segment(path, &name);
+
+            match self.properties.iter().find(|property| property.0 == name) {
+                Some((_, rule)) => rule.check(value, path, violations),
+                None => match self.additional {
+                    Additional::Any => (),
+                    Additional::Forbidden => violations.push(violation(
+                        path,
+                        key.span(),
+                        format!("Unexpected key \"{}\"", name),
+                    )),
+                    Additional::Rule(ref rule) => rule.check(value, path, violations),
+                },
+            };
+
+            path.truncate(len);
+        }
+    }
+
+    fn check_compound(
+        &self,
+        pointer: Pointer,
+        span: Span,
+        path: &mut String,
+        violations: &mut Vec<Violation>,
+    ) {
+        for rule in &self.all_of {
+            rule.check(pointer, path, violations);
+        }
+
+        if !self.any_of.is_empty() && !self.any_of.iter().any(|rule| rule.passes(pointer)) {
+            violations.push(violation(
+                path,
+                span,
+                "The value matches none of the schemas in anyOf",
+            ));
+        }
+
+        if !self.one_of.is_empty() {
+            let passed = self
+                .one_of
+                .iter()
+                .filter(|rule| rule.passes(pointer))
+                .count();
+            if passed != 1 {
+                violations.push(violation(
+                    path,
+                    span,
+                    format!(
+                        "The value matches {} of the schemas in oneOf instead of one",
+                        passed
+                    ),
+                ));
+            }
+        }
+
+        if let Some(ref rule) = self.not {
+            if rule.passes(pointer) {
+                violations.push(violation(path, span, "The value matches the schema in not"));
+            }
+        }
+    }
+}
+
+fn violation<M: Into<Cow<'static, str>>>(path: &str, span: Span, message: M) -> Violation {
+    Violation {
+        path: path.to_string(),
+        span,
+        message: message.into(),
+    }
+}
+
+fn failure<M: Into<Cow<'static, str>>>(path: &str, message: M) -> SchemaError {
+    SchemaError {
+        path: path.to_string(),
+        message: message.into(),
+    }
+}
+
+/// Appends a JSON Pointer reference token, escaping `~` and `/`
+fn push_segment(path: &mut String, segment: &str) {
+    path.push('/');
+    path.push_str(&segment.replace('~', "~0").replace('/', "~1"));
+}
+
+fn number(value: &Value, path: &str) -> Result<f64, SchemaError> {
+    value
+        .as_f64()
+        .ok_or_else(|| failure(path, "Expected a number"))
+}
+
+fn count(value: &Value, path: &str) -> Result<usize, SchemaError> {
+    value
+        .as_u64()
+        .and_then(|count| count.to_usize())
+        .ok_or_else(|| failure(path, "Expected a non-negative integer"))
+}
+
+fn children(first: Option<Pointer>) -> Vec<Pointer> {
+    let mut result = Vec::new();
+    let mut next = first;
+
+    while let Some(pointer) = next {
+        result.push(pointer);
+        next = pointer.next_sibling();
+    }
+
+    result
+}
+
+fn to_f64(word: &Word) -> Option<f64> {
+    match *word {
+        Word::Int(ref value) => value
+            .to_f64()
+            .or_else(|| Into::<Option<&BigInt>>::into(value).and_then(|v| v.to_f64())),
+        Word::Float(ref value) => value.to_f64(),
+        _ => None,
+    }
+}
+
+fn to_str(word: &Word) -> Option<&str> {
+    match *word {
+        Word::Str(ref value) => Some(value),
+        Word::Scalar(TaggedValue::Incognitum(ref value)) => Some(IncognitumValue::get_value(value)),
+        Word::Scalar(ref value) => value.as_any().downcast_ref::<String>().map(String::as_str),
+        _ => None,
+    }
+}
+
+/// The key as a JSON Pointer reference token
+fn key_name(pointer: Pointer) -> String {
+    match Value::from_pointer(pointer) {
+        Some(Value::Str(name)) => name,
+        Some(Value::Int(value)) => value.to_string(),
+        Some(Value::Float(value)) => value.to_string(),
+        Some(Value::Bool(value)) => value.to_string(),
+        Some(Value::Null) => String::from("null"),
+        _ => String::from("?"),
+    }
+}
@@ -0,0 +1,764 @@
+extern crate num;
+
+use self::num::{BigInt, ToPrimitive};
+
+use crate::book::extractor::pointer::{Expansion, Pointer};
+use crate::book::extractor::traits::FromPointer;
+use crate::book::volume::Volume;
+use crate::book::word::Word;
+
+use crate::limits::LimitError;
+
+use crate::model::style::{CommonStyles, Style};
+use crate::model::yaml::map;
+use crate::model::yaml::seq;
+use crate::model::yaml::str::{double_quoted, is_plain, Str};
+use crate::model::yaml::timestamp::TimestampValue;
+use crate::model::yaml::yaml::YamlValue;
+use crate::model::yamlette::incognitum::IncognitumValue;
+use crate::model::{Model, Tagged, TaggedValue};
+
+use crate::orchestra::chord::{BinaryValue, Chord, EmptyDict, EmptyList};
+use crate::orchestra::{OrchError, Orchestra};
+
+use std::borrow::Cow;
+use std::error;
+use std::fmt;
+use std::ops;
+
+/// An owned document tree
+///
+/// Maps keep the order of their entries. Anchors and aliases are not preserved:
+/// an aliased node gets copied in place of every alias.
+#[derive(Clone, Debug)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Int(BigInt),
+    Float(f64),
+    Str(String),
+    Binary(Vec<u8>),
+    Timestamp(TimestampValue),
+    Seq(Vec<Value>),
+    Map(Vec<(Value, Value)>),
+
+    /// A node with a tag out of the schema.
+    /// Scalars of unknown types are kept as `Str` with their source text,
+    /// as are the ones of the types with no value of their own (`!!merge`, `!!value`, `!!yaml`).
+    Tagged(String, Box<Value>),
+}
+
+/// The reason a node cannot be built into a `Value`
+#[derive(Debug)]
+pub enum ValueError {
+    /// The tree exceeds one of the limits of its volume
+    Limit(LimitError),
+
+    /// An alias refers to a collection containing it (e.g. `&a [ *a ]`), so cannot be copied
+    Recursion,
+
+    /// The node has no value to build, such as a scalar of a type out of the `Value` variants
+    Unsupported(Cow<'static, str>),
+}
+
+impl fmt::Display for ValueError {
+    fn fmt(&self, fmtter: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ValueError::Limit(ref err) => write!(fmtter, "{}", err),
+            ValueError::Recursion => {
+                write!(fmtter, "An alias refers to a collection containing it")
+            }
+            ValueError::Unsupported(ref err) => write!(fmtter, "{}", err),
+        }
+    }
+}
+
+impl error::Error for ValueError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            ValueError::Limit(ref err) => Some(err),
+            ValueError::Recursion | ValueError::Unsupported(_) => None,
+        }
+    }
+}
+
+impl From<LimitError> for ValueError {
+    fn from(err: LimitError) -> ValueError {
+        ValueError::Limit(err)
+    }
+}
+
+static NULL: Value = Value::Null;
+
+impl Value {
+    /// Builds the tree out of the volume root
+    pub fn from_volume(volume: &Volume) -> Option<Value> {
+        Value::try_from_volume(volume).ok().flatten()
+    }
+
+    /// Builds the tree out of the volume root (`None` for an empty volume),
+    /// unless the volume exceeds one of its limits (see `Book::set_limits`)
+    /// or the tree cannot be built (see `try_from_pointer`)
+    pub fn try_from_volume(volume: &Volume) -> Result<Option<Value>, ValueError> {
+        if let Some(error) = volume.exceeded() {
+            return Err(ValueError::Limit(error.clone()));
+        }
+
+        match Pointer::new(volume) {
+            Some(pointer) => Value::try_from_pointer(pointer).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Builds the tree out of the node, unless the tree exceeds the depth or the nodes
+    /// allowed by the limits of its volume, counting the copies of the aliased nodes,
+    /// or there is a node in it with no value to build
+    pub fn try_from_pointer(pointer: Pointer) -> Result<Value, ValueError> {
+        expand(pointer, &mut Expansion::of(pointer))
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(*self.untagged(), Value::Null)
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self.untagged() {
+            Value::Bool(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match *self.untagged() {
+            Value::Int(ref value) => value.to_i64(),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match *self.untagged() {
+            Value::Int(ref value) => value.to_u64(),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self.untagged() {
+            Value::Float(value) => Some(value),
+            Value::Int(ref value) => value.to_f64(),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match *self.untagged() {
+            Value::Str(ref value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match *self.untagged() {
+            Value::Binary(ref value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_seq(&self) -> Option<&Vec<Value>> {
+        match *self.untagged() {
+            Value::Seq(ref value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_seq_mut(&mut self) -> Option<&mut Vec<Value>> {
+        match *self.untagged_mut() {
+            Value::Seq(ref mut value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_map(&self) -> Option<&Vec<(Value, Value)>> {
+        match *self.untagged() {
+            Value::Map(ref value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_map_mut(&mut self) -> Option<&mut Vec<(Value, Value)>> {
+        match *self.untagged_mut() {
+            Value::Map(ref mut value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The tag of a `Tagged` value
+    pub fn tag(&self) -> Option<&str> {
+        match *self {
+            Value::Tagged(ref tag, _) => Some(tag),
+            _ => None,
+        }
+    }
+
+    /// The value itself, or the value under the tag
+    pub fn untagged(&self) -> &Value {
+        match *self {
+            Value::Tagged(_, ref value) => value.untagged(),
+            ref value => value,
+        }
+    }
+
+    pub fn untagged_mut(&mut self) -> &mut Value {
+        match *self {
+            Value::Tagged(_, ref mut value) => value.untagged_mut(),
+            ref mut value => value,
+        }
+    }
+
+    /// A sequence element or a map value, if there is one
+    pub fn get<I: Index>(&self, index: I) -> Option<&Value> {
+        index.index_into(self)
+    }
+
+    pub fn get_mut<I: Index>(&mut self, index: I) -> Option<&mut Value> {
+        index.index_into_mut(self)
+    }
+
+    /// Puts the entry into the map, replacing the value of an equal key in place.
+    /// Returns the replaced value. Null turns into an empty map first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is neither a map nor null.
+    pub fn insert<K, V>(&mut self, key: K, value: V) -> Option<Value>
+    where
+        K: Into<Value>,
+        V: Into<Value>,
+    {
+        let key = key.into();
+        let value = value.into();
+
+        let map = self.map_or_init();
+
+        for &mut (ref k, ref mut v) in map.iter_mut() {
+            if *k == key {
+                return Some(std::mem::replace(v, value));
+            }
+        }
+
+        map.push((key, value));
+
+        None
+    }
+
+    /// Appends the element to the sequence. Null turns into an empty sequence first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is neither a sequence nor null.
+    pub fn push<V: Into<Value>>(&mut self, value: V) {
+        let value = value.into();
+
+        let target = self.untagged_mut();
+
+        if let Value::Null = *target {
+            *target = Value::Seq(Vec::new());
+        }
+
+        match *target {
+            Value::Seq(ref mut seq) => seq.push(value),
+            ref other => panic!("Cannot push into {:?}", other),
+        }
+    }
+
+    /// Takes a sequence element or a map entry out, shifting the following ones
+    pub fn remove<I: Index>(&mut self, index: I) -> Option<Value> {
+        index.remove_from(self)
+    }
+
+    fn map_or_init(&mut self) -> &mut Vec<(Value, Value)> {
+        let target = self.untagged_mut();
+
+        if let Value::Null = *target {
+            *target = Value::Map(Vec::new());
+        }
+
+        match *target {
+            Value::Map(ref mut map) => map,
+            ref other => panic!("Cannot index {:?} with a key", other),
+        }
+    }
+}
+
+/// Anything a `Value` can be indexed with:
+/// `usize` for sequences, strings and values for maps
+pub trait Index {
+    fn index_into<'v>(&self, value: &'v Value) -> Option<&'v Value>;
+
+    fn index_into_mut<'v>(&self, value: &'v mut Value) -> Option<&'v mut Value>;
+
+    /// Used by `IndexMut`, which inserts null under a missing map key
+    fn index_or_insert<'v>(&self, value: &'v mut Value) -> &'v mut Value;
+
+    fn remove_from(&self, value: &mut Value) -> Option<Value>;
+}
+
+impl Index for usize {
+    fn index_into<'v>(&self, value: &'v Value) -> Option<&'v Value> {
+        value.as_seq().and_then(|seq| seq.get(*self))
+    }
+
+    fn index_into_mut<'v>(&self, value: &'v mut Value) -> Option<&'v mut Value> {
+        value.as_seq_mut().and_then(|seq| seq.get_mut(*self))
+    }
+
+    fn index_or_insert<'v>(&self, value: &'v mut Value) -> &'v mut Value {
+        match *value.untagged_mut() {
+            Value::Seq(ref mut seq) => {
+                let len = seq.len();
+                seq.get_mut(*self).unwrap_or_else(|| {
+                    panic!("Index {} is out of bounds, the length is {}", self, len)
+                })
+            }
+            ref other => panic!("Cannot index {:?} with {}", other, self),
+        }
+    }
+
+    fn remove_from(&self, value: &mut Value) -> Option<Value> {
+        match value.as_seq_mut() {
+            Some(seq) if *self < seq.len() => Some(seq.remove(*self)),
+            _ => None,
+        }
+    }
+}
+
+impl Index for Value {
+    fn index_into<'v>(&self, value: &'v Value) -> Option<&'v Value> {
+        value
+            .as_map()
+            .and_then(|map| map.iter().find(|(k, _)| k == self))
+            .map(|(_, v)| v)
+    }
+
+    fn index_into_mut<'v>(&self, value: &'v mut Value) -> Option<&'v mut Value> {
+        value
+            .as_map_mut()
+            .and_then(|map| map.iter_mut().find(|(k, _)| k == self))
+            .map(|(_, v)| v)
+    }
+
+    fn index_or_insert<'v>(&self, value: &'v mut Value) -> &'v mut Value {
+        let map = value.map_or_init();
+
+        let position = match map.iter().position(|(k, _)| k == self) {
+            Some(position) => position,
+            None => {
+                map.push((self.clone(), Value::Null));
+                map.len() - 1
+            }
+        };
+
+        &mut map[position].1
+    }
+
+    fn remove_from(&self, value: &mut Value) -> Option<Value> {
+        let map = value.as_map_mut()?;
+        let position = map.iter().position(|(k, _)| k == self)?;
+
+        Some(map.remove(position).1)
+    }
+}
+
+impl Index for str {
+    fn index_into<'v>(&self, value: &'v Value) -> Option<&'v Value> {
+        value
+            .as_map()
+            .and_then(|map| map.iter().find(|(k, _)| k.as_str() == Some(self)))
+            .map(|(_, v)| v)
+    }
+
+    fn index_into_mut<'v>(&self, value: &'v mut Value) -> Option<&'v mut Value> {
+        value
+            .as_map_mut()
+            .and_then(|map| map.iter_mut().find(|(k, _)| k.as_str() == Some(self)))
+            .map(|(_, v)| v)
+    }
+
+    fn index_or_insert<'v>(&self, value: &'v mut Value) -> &'v mut Value {
+        let map = value.map_or_init();
+
+        let position = match map.iter().position(|(k, _)| k.as_str() == Some(self)) {
+            Some(position) => position,
+            None => {
+                map.push((Value::from(self), Value::Null));
+                map.len() - 1
+            }
+        };
+
+        &mut map[position].1
+    }
+
+    fn remove_from(&self, value: &mut Value) -> Option<Value> {
+        let map = value.as_map_mut()?;
+        let position = map.iter().position(|(k, _)| k.as_str() == Some(self))?;
+
+        Some(map.remove(position).1)
+    }
+}
+
+impl Index for String {
+    fn index_into<'v>(&self, value: &'v Value) -> Option<&'v Value> {
+        self.as_str().index_into(value)
+    }
+
+    fn index_into_mut<'v>(&self, value: &'v mut Value) -> Option<&'v mut Value> {
+        self.as_str().index_into_mut(value)
+    }
+
+    fn index_or_insert<'v>(&self, value: &'v mut Value) -> &'v mut Value {
+        self.as_str().index_or_insert(value)
+    }
+
+    fn remove_from(&self, value: &mut Value) -> Option<Value> {
+        self.as_str().remove_from(value)
+    }
+}
+
+impl<T> Index for &T
+where
+    T: ?Sized + Index,
+{
+    fn index_into<'v>(&self, value: &'v Value) -> Option<&'v Value> {
+        (**self).index_into(value)
+    }
+
+    fn index_into_mut<'v>(&self, value: &'v mut Value) -> Option<&'v mut Value> {
+        (**self).index_into_mut(value)
+    }
+
+    fn index_or_insert<'v>(&self, value: &'v mut Value) -> &'v mut Value {
+        (**self).index_or_insert(value)
+    }
+
+    fn remove_from(&self, value: &mut Value) -> Option<Value> {
+        (**self).remove_from(value)
+    }
+}
+
+/// Gives null for a missing element or key
+impl<I: Index> ops::Index<I> for Value {
+    type Output = Value;
+
+    fn index(&self, index: I) -> &Value {
+        index.index_into(self).unwrap_or(&NULL)
+    }
+}
+
+/// Inserts null under a missing map key, turning null itself into a map.
+/// Panics on a missing sequence element.
+impl<I: Index> ops::IndexMut<I> for Value {
+    fn index_mut(&mut self, index: I) -> &mut Value {
+        index.index_or_insert(self)
+    }
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::Null, Value::Null) => true,
+            (Value::Bool(l), Value::Bool(r)) => l == r,
+            (Value::Int(l), Value::Int(r)) => l == r,
+            (Value::Float(l), Value::Float(r)) => l == r,
+            (Value::Str(l), Value::Str(r)) => l == r,
+            (Value::Binary(l), Value::Binary(r)) => l == r,
+            (Value::Timestamp(l), Value::Timestamp(r)) => {
+                l.year == r.year
+                    && l.month == r.month
+                    && l.day == r.day
+                    && l.hour == r.hour
+                    && l.minute == r.minute
+                    && l.second == r.second
+                    && l.fraction.as_ref().map(|f| f.to_f64())
+                        == r.fraction.as_ref().map(|f| f.to_f64())
+                    && l.tz_hour == r.tz_hour
+                    && l.tz_minute == r.tz_minute
+            }
+            (Value::Seq(l), Value::Seq(r)) => l == r,
+            (Value::Map(l), Value::Map(r)) => l == r,
+            (Value::Tagged(lt, l), Value::Tagged(rt, r)) => lt == rt && l == r,
+            _ => false,
+        }
+    }
+}
+
+/// Gives `None` for the trees `Value::try_from_pointer` fails on
+/// (e.g. an alias within its own anchor, `&a [ *a ]`)
+impl<'a> FromPointer<'a> for Value {
+    fn from_pointer(pointer: Pointer<'a>) -> Option<Self> {
+        Value::try_from_pointer(pointer).ok()
+    }
+}
+
+fn expand(pointer: Pointer, expansion: &mut Expansion) -> Result<Value, ValueError> {
+    let pointer = pointer.unalias();
+
+    let value = match *pointer.to_word() {
+        Word::Null => Value::Null,
+        Word::Bool(value) => Value::Bool(value),
+        Word::Int(ref value) => Value::Int(value.clone().into()),
+        Word::Float(ref value) => match value.to_f64() {
+            Some(value) => Value::Float(value),
+            None => {
+                return Err(ValueError::Unsupported(Cow::from(
+                    "The float is out of the f64 range",
+                )))
+            }
+        },
+        Word::Str(ref value) => Value::Str(value.clone()),
+        Word::Bin(ref value) => Value::Binary(value.clone()),
+        Word::Timestamp(ref value) => Value::Timestamp(value.clone()),
+
+        Word::Seq(ref tag) => {
+            if !expansion.enter(pointer)? {
+                return Err(ValueError::Recursion);
+            }
+
+            let mut seq = Vec::with_capacity(0);
+            let mut item = pointer.into_seq();
+
+            while let Some(ptr) = item {
+                expansion.count()?;
+                seq.push(expand(ptr, expansion)?);
+                item = ptr.next_sibling();
+            }
+
+            expansion.leave();
+
+            tagged(tag, seq::TAG, Value::Seq(seq))
+        }
+
+        Word::Map(ref tag) => {
+            if !expansion.enter(pointer)? {
+                return Err(ValueError::Recursion);
+            }
+
+            let mut map = Vec::with_capacity(0);
+            let mut key = pointer.into_map();
+
+            while let Some(ptr) = key {
+                let val = ptr.next_sibling().ok_or(ValueError::Unsupported(Cow::from(
+                    "The map key has no value",
+                )))?;
+                expansion.count()?;
+                expansion.count()?;
+                map.push((expand(ptr, expansion)?, expand(val, expansion)?));
+                key = val.next_sibling();
+            }
+
+            expansion.leave();
+
+            tagged(tag, map::TAG, Value::Map(map))
+        }
+
+        Word::Scalar(TaggedValue::Incognitum(ref value)) => {
+            // a quoted scalar is kept as it has been written, so it is unquoted here
+            let text = match value.get_value().as_bytes().first() {
+                Some(b'"') | Some(b'\'') => match Str.decode(false, value.get_value().as_bytes()) {
+                    Ok(TaggedValue::Str(text)) => text.get_value().to_string(),
+                    _ => value.get_value().clone(),
+                },
+                _ => value.get_value().clone(),
+            };
+            let text = Value::Str(text);
+            match *value.get_tag() {
+                Some(ref tag) => Value::Tagged(tag.clone(), Box::new(text)),
+                None => text,
+            }
+        }
+        Word::Scalar(ref value @ TaggedValue::Merge(_)) => scalar(value, "<<"),
+        Word::Scalar(ref value @ TaggedValue::Value(_)) => scalar(value, "="),
+        Word::Scalar(ref value @ TaggedValue::Yaml(ref code)) => scalar(
+            value,
+            match *code {
+                YamlValue::Alias => "*",
+                YamlValue::Anchor => "&",
+                YamlValue::Tag => "!",
+            },
+        ),
+        Word::Scalar(ref value) => match value.as_any().downcast_ref::<String>() {
+            Some(value) => Value::Str(value.clone()),
+            None => {
+                return Err(ValueError::Unsupported(Cow::from(format!(
+                    "The scalar of {} has no value to build",
+                    value.get_tag()
+                ))))
+            }
+        },
+
+        Word::UnboundAlias(ref name) => {
+            return Err(ValueError::Unsupported(Cow::from(format!(
+                "The alias {} refers to no anchor",
+                name
+            ))))
+        }
+
+        Word::Err(ref message, _) | Word::Wrn(ref message, _) => {
+            return Err(ValueError::Unsupported(message.clone()))
+        }
+
+        Word::Alias(_) => {
+            return Err(ValueError::Unsupported(Cow::from(
+                "The alias is not resolved",
+            )))
+        }
+    };
+
+    Ok(value)
+}
+
+/// The scalar of a type with no value of its own, kept as its text
+fn scalar(value: &TaggedValue, text: &str) -> Value {
+    Value::Tagged(
+        value.get_tag().into_owned(),
+        Box::new(Value::Str(String::from(text))),
+    )
+}
+
+fn tagged(tag: &str, default: &str, value: Value) -> Value {
+    if tag == default {
+        value
+    } else {
+        Value::Tagged(tag.to_string(), Box::new(value))
+    }
+}
+
+/// Collection tags are not written out, since the orchestra has no way of issuing them yet
+impl Chord for Value {
+    fn chord_size(&self) -> usize {
+        match *self {
+            Value::Seq(ref seq) => 1 + seq.iter().map(Chord::chord_size).sum::<usize>(),
+            Value::Map(ref map) => {
+                1 + map
+                    .iter()
+                    .map(|(k, v)| k.chord_size() + v.chord_size())
+                    .sum::<usize>()
+            }
+            Value::Tagged(_, ref value) => value.chord_size(),
+            _ => 1,
+        }
+    }
+
+    fn play(
+        self,
+        orchestra: &Orchestra,
+        level: usize,
+        alias: Option<Cow<'static, str>>,
+        cs: CommonStyles,
+        vs: &mut [&mut dyn Style],
+    ) -> Result<(), OrchError> {
+        match self {
+            Value::Null => ().play(orchestra, level, alias, cs, vs),
+            Value::Bool(value) => value.play(orchestra, level, alias, cs, vs),
+            Value::Int(value) => match value.to_i64() {
+                Some(value) => value.play(orchestra, level, alias, cs, vs),
+                None => value.play(orchestra, level, alias, cs, vs),
+            },
+            Value::Float(value) => value.play(orchestra, level, alias, cs, vs),
+            Value::Str(value) => value.play(orchestra, level, alias, cs, vs),
+            Value::Binary(value) => BinaryValue(value).play(orchestra, level, alias, cs, vs),
+            Value::Timestamp(value) => value.play(orchestra, level, alias, cs, vs),
+            Value::Seq(seq) => {
+                EmptyList.play(orchestra, level, alias, cs, vs)?;
+
+                for item in seq {
+                    item.play(orchestra, level + 1, None, cs, vs)?;
+                }
+
+                Ok(())
+            }
+            Value::Map(map) => {
+                EmptyDict.play(orchestra, level, alias, cs, vs)?;
+
+                for (key, val) in map {
+                    key.play(orchestra, level + 1, None, cs, vs)?;
+                    val.play(orchestra, level + 1, None, cs, vs)?;
+                }
+
+                Ok(())
+            }
+            Value::Tagged(tag, value) => match *value {
+                // the text is written as it is, so the one that would not read back gets quoted
+                Value::Str(text) => IncognitumValue::new(if is_plain(&text) {
+                    text
+                } else {
+                    double_quoted(&text)
+                })
+                .set_tag(tag)
+                .play(orchestra, level, alias, cs, vs),
+                value => value.play(orchestra, level, alias, cs, vs),
+            },
+        }
+    }
+}
+
+impl From<()> for Value {
+    fn from(_: ()) -> Value {
+        Value::Null
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Value {
+        Value::Bool(value)
+    }
+}
+
+macro_rules! from_int {
+    ( $($t:ty),* ) => {
+        $(
+        impl From<$t> for Value {
+            fn from(value: $t) -> Value {
+                Value::Int(BigInt::from(value))
+            }
+        }
+        )*
+    };
+}
+
+from_int!(u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, BigInt);
+
+impl From<f32> for Value {
+    fn from(value: f32) -> Value {
+        Value::Float(value as f64)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Value {
+        Value::Float(value)
+    }
+}
+
+impl<'a> From<&'a str> for Value {
+    fn from(value: &'a str) -> Value {
+        Value::Str(String::from(value))
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Value {
+        Value::Str(value)
+    }
+}
+
+impl From<TimestampValue> for Value {
+    fn from(value: TimestampValue) -> Value {
+        Value::Timestamp(value)
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(value: Vec<T>) -> Value {
+        Value::Seq(value.into_iter().map(Into::into).collect())
+    }
+}
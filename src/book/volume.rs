@@ -1,9 +1,13 @@
 use std::collections::HashMap;
 
+use crate::reader::{Id, Span};
 use crate::sage::Idea;
 
+use crate::book::merge::Merger;
 use crate::book::word::Word;
 
+use crate::limits::{Limit, LimitError, Limits};
+
 use crate::model::yaml::map;
 use crate::model::yaml::seq;
 
@@ -12,8 +16,12 @@ use std::borrow::Cow;
 pub struct Volume {
     pub complete: bool,
     pub gist: Vec<(Option<String>, usize, Word)>,
+    pub spans: Vec<Span>,
 
     buff: Option<HashMap<usize, Idea>>,
+    merge_keys: bool,
+    limits: Limits,
+    exceeded: Option<LimitError>,
 }
 
 impl Volume {
@@ -21,10 +29,34 @@ impl Volume {
         Volume {
             complete: false,
             gist: Vec::with_capacity(0),
+            spans: Vec::with_capacity(0),
             buff: Some(HashMap::with_capacity(256)),
+            merge_keys: false,
+            limits: Limits::default(),
+            exceeded: None,
         }
     }
 
+    /// The limits on the aliases and the nodes they expand into, checked on completion
+    pub fn set_limits(&mut self, limits: Limits) {
+        self.limits = limits;
+    }
+
+    /// The limits of the volume, which the extraction keeps within as well
+    pub fn limits(&self) -> Limits {
+        self.limits
+    }
+
+    /// The limit the volume has exceeded, in which case its only word is the error
+    pub fn exceeded(&self) -> Option<&LimitError> {
+        self.exceeded.as_ref()
+    }
+
+    /// Whether the merge keys (`<<`) should be resolved on completion
+    pub fn set_merge_keys(&mut self, merge_keys: bool) {
+        self.merge_keys = merge_keys;
+    }
+
     pub fn complete(&mut self) {
         if self.complete {
             return;
@@ -33,6 +65,7 @@ impl Volume {
         let mut buff = self.buff.take().unwrap();
 
         self.gist.reserve_exact(buff.len());
+        self.spans.reserve_exact(buff.len());
 
         let mut border: usize = 0;
 
@@ -64,69 +97,68 @@ impl Volume {
             }
         }
 
+        self.stretch_spans();
+
+        // before the merge keys, since merging copies the nodes the same way the aliases expand
+        if let Err(error) = self.check_limits() {
+            self.gist = vec![(None, 0, Word::Err(Cow::from(error.to_string()), None))];
+            self.spans = vec![Span::default()];
+            self.exceeded = Some(error);
+            self.complete = true;
+            return;
+        }
+
+        if self.merge_keys {
+            let gist = std::mem::take(&mut self.gist);
+            let spans = std::mem::take(&mut self.spans);
+
+            let (gist, spans) = Merger::new(gist, spans).resolve();
+
+            self.gist = gist;
+            self.spans = spans;
+        }
+
         self.complete = true;
     }
 
     fn process(&mut self, idea: Idea) {
         match idea {
-            Idea::Error(id, value) => self
-                .gist
-                .push((None, id.level, Word::Err(Cow::from(value)))),
-            Idea::ReadError(id, _, string) => {
-                self.gist
-                    .push((None, id.level, Word::Err(Cow::from(string))))
+            Idea::Error(id, value) => self.push(None, id, Word::Err(Cow::from(value), None)),
+            Idea::ReadError(id, location, string) => {
+                self.push(None, id, Word::Err(Cow::from(string), Some(location)))
             }
-            Idea::ReadWarning(id, _, string) => {
-                self.gist
-                    .push((None, id.level, Word::Wrn(Cow::from(string))))
+            Idea::ReadWarning(id, location, string) => {
+                self.push(None, id, Word::Wrn(Cow::from(string), Some(location)))
             }
 
-            Idea::NodeLiteral(id, alias, value) => {
-                self.gist.push((alias, id.level, Word::Str(value)))
-            }
-            Idea::NodeScalar(id, alias, value) => {
-                self.gist
-                    .push((alias, id.level, Word::extract_scalar(value)))
-            }
+            Idea::NodeLiteral(id, alias, value) => self.push(alias, id, Word::Str(value)),
+            Idea::NodeScalar(id, alias, value) => self.push(alias, id, Word::extract_scalar(value)),
 
-            Idea::NodeSequence(id, alias, tag) => {
-                self.gist.push((alias, id.level, Word::Seq(Cow::from(tag))))
-            }
+            Idea::NodeSequence(id, alias, tag) => self.push(alias, id, Word::Seq(Cow::from(tag))),
             Idea::NodeMetaSeq(id, alias, None) => {
-                self.gist
-                    .push((alias, id.level, Word::Seq(Cow::from(seq::TAG))))
+                self.push(alias, id, Word::Seq(Cow::from(seq::TAG)))
             }
             Idea::NodeMetaSeq(id, alias, Some(tag)) => {
-                self.gist.push((alias, id.level, Word::Seq(Cow::from(tag))))
+                self.push(alias, id, Word::Seq(Cow::from(tag)))
             }
 
-            Idea::NodeDictionary(id, alias, _, firstborn_id) => {
-                self.gist
-                    .push((alias, id.level, Word::Map(Cow::from(map::TAG))));
+            Idea::NodeDictionary(id, alias, tag, firstborn_id) => {
+                self.push(alias, id, Word::Map(tag));
 
                 if firstborn_id.is_some() {
-                    // TODO: check whether it's ALWAYS the previous node?
-                    let ln = self.gist.len();
-                    let mut firstborn = self.gist.swap_remove(ln - 2);
-                    firstborn.1 += 1; // level up
-                    self.gist.push(firstborn);
+                    self.adopt_firstborn();
                 }
             }
 
             Idea::NodeMetaMap(id, alias, tag, firstborn_id) => {
                 if let Some(tag) = tag {
-                    self.gist.push((alias, id.level, Word::Map(Cow::from(tag))));
+                    self.push(alias, id, Word::Map(Cow::from(tag)));
                 } else {
-                    self.gist
-                        .push((alias, id.level, Word::Map(Cow::from(map::TAG))));
+                    self.push(alias, id, Word::Map(Cow::from(map::TAG)));
                 }
 
                 if firstborn_id.is_some() {
-                    // TODO: check whether it's ALWAYS the previous node?
-                    let ln = self.gist.len();
-                    let mut firstborn = self.gist.swap_remove(ln - 2);
-                    firstborn.1 += 1; // level up
-                    self.gist.push(firstborn);
+                    self.adopt_firstborn();
                 }
             }
 
@@ -143,9 +175,9 @@ impl Volume {
                 }
 
                 if narr.is_some() {
-                    self.gist.push((None, id.level, narr.take().unwrap()));
+                    self.push(None, id, narr.take().unwrap());
                 } else {
-                    self.gist.push((None, id.level, Word::UnboundAlias(value)));
+                    self.push(None, id, Word::UnboundAlias(value));
                 }
             }
 
@@ -153,6 +185,119 @@ impl Volume {
         };
     }
 
+    fn push(&mut self, anchor: Option<String>, id: Id, word: Word) {
+        self.gist.push((anchor, id.level, word));
+        self.spans.push(id.span);
+    }
+
+    /// Puts the firstborn (the first key, read before the map itself) right after the map
+    fn adopt_firstborn(&mut self) {
+        // TODO: check whether it's ALWAYS the previous node?
+        let ln = self.gist.len();
+
+        let mut firstborn = self.gist.swap_remove(ln - 2);
+        firstborn.1 += 1; // level up
+        self.gist.push(firstborn);
+
+        let firstborn = self.spans.swap_remove(ln - 2);
+        self.spans[ln - 2].start = firstborn.start;
+        self.spans.push(firstborn);
+    }
+
+    /// Counts the aliases and the nodes with every alias expanded into its anchored node,
+    /// so that the size of the expanded tree is known without expanding it
+    fn check_limits(&self) -> Result<(), LimitError> {
+        if self.limits.max_aliases.is_none() && self.limits.max_nodes.is_none() {
+            return Ok(());
+        }
+
+        let len = self.gist.len();
+
+        // the index right after the last descendant of each node
+        let mut ends: Vec<usize> = vec![len; len];
+        let mut stack: Vec<usize> = Vec::with_capacity(16);
+
+        for ix in 0..len {
+            let level = self.gist[ix].1;
+
+            while let Some(&top) = stack.last() {
+                if self.gist[top].1 < level {
+                    break;
+                }
+                ends[stack.pop().unwrap()] = ix;
+            }
+
+            stack.push(ix);
+        }
+
+        // sums[ix] is the expanded size of the nodes before ix
+        let mut sums: Vec<usize> = Vec::with_capacity(len + 1);
+        let mut aliases = 0;
+
+        sums.push(0);
+
+        for ix in 0..len {
+            let size = match self.gist[ix].2 {
+                Word::Alias(anchor) => {
+                    aliases += 1;
+                    Limit::Aliases.check(&self.limits, aliases)?;
+
+                    // an alias within its own anchor expands endlessly
+                    if ends[anchor] > ix {
+                        usize::MAX
+                    } else {
+                        sums[ends[anchor]] - sums[anchor]
+                    }
+                }
+                Word::UnboundAlias(_) => {
+                    aliases += 1;
+                    Limit::Aliases.check(&self.limits, aliases)?;
+                    1
+                }
+                _ => 1,
+            };
+
+            let total = sums[ix].saturating_add(size);
+            Limit::Nodes.check(&self.limits, total)?;
+
+            sums.push(total);
+        }
+
+        Ok(())
+    }
+
+    /// Stretches the spans of collections up to the end of their last descendants
+    fn stretch_spans(&mut self) {
+        let mut stack: Vec<usize> = Vec::with_capacity(16);
+
+        for ix in 0..self.gist.len() {
+            let level = self.gist[ix].1;
+
+            while let Some(&top) = stack.last() {
+                if self.gist[top].1 < level {
+                    break;
+                }
+                stack.pop();
+                self.stretch(&stack, top);
+            }
+
+            stack.push(ix);
+        }
+
+        while let Some(top) = stack.pop() {
+            self.stretch(&stack, top);
+        }
+    }
+
+    fn stretch(&mut self, stack: &[usize], child: usize) {
+        if let Some(&parent) = stack.last() {
+            let end = self.spans[child].end;
+            if end.offset > self.spans[parent].end.offset {
+                self.spans[parent].end = end;
+            }
+        }
+    }
+
     pub fn stamp(&mut self, idea: Idea) {
         if self.complete {
             return;
@@ -178,12 +323,12 @@ impl Volume {
         }
     }
 
-    pub fn unalias(&self, idx: usize) -> &Word {
-        let &(_, _, ref word) = &self.gist[idx];
-
-        match *word {
-            Word::Alias(idx) => self.unalias(idx),
-            _ => word,
+    pub fn unalias(&self, mut idx: usize) -> &Word {
+        loop {
+            match self.gist[idx].2 {
+                Word::Alias(anchor) => idx = anchor,
+                ref word => return word,
+            }
         }
     }
 }
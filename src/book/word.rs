@@ -10,7 +10,9 @@ use crate::model::yaml::float::FloatValue;
 use crate::model::yaml::int::IntValue;
 use crate::model::yaml::null::NullValue;
 use crate::model::yaml::str::StrValue;
+use crate::model::yaml::timestamp::TimestampValue;
 use crate::model::{Tagged, TaggedValue};
+use crate::reader::Location;
 
 use std::borrow::Cow;
 use std::cmp::PartialEq;
@@ -22,6 +24,7 @@ pub enum Word {
     Int(IntValue),
     Str(String),
     Float(FloatValue),
+    Timestamp(TimestampValue),
     Null,
 
     Alias(usize),
@@ -31,8 +34,8 @@ pub enum Word {
 
     Scalar(TaggedValue),
 
-    Err(Cow<'static, str>),
-    Wrn(Cow<'static, str>),
+    Err(Cow<'static, str>, Option<Location>),
+    Wrn(Cow<'static, str>, Option<Location>),
     UnboundAlias(String),
 }
 
@@ -74,6 +77,12 @@ impl Word {
             Err(v) => v,
         };
 
+        let tms: Result<TimestampValue, TaggedValue> = value.into();
+        let value = match tms {
+            Ok(v) => return Word::Timestamp(v),
+            Err(v) => v,
+        };
+
         let nil: Result<NullValue, TaggedValue> = value.into();
         let value = match nil {
             Ok(_) => return Word::Null,
@@ -1115,3 +1124,80 @@ impl<'a> Into<Result<(), &'a Word>> for &'a Word {
         Err(self)
     }
 }
+
+impl From<Word> for Result<TimestampValue, Word> {
+    fn from(word: Word) -> Result<TimestampValue, Word> {
+        match word {
+            Word::Timestamp(val) => Ok(val),
+            _ => Err(word),
+        }
+    }
+}
+
+impl<'a> From<&'a Word> for Result<TimestampValue, &'a Word> {
+    fn from(word: &'a Word) -> Result<TimestampValue, &'a Word> {
+        match *word {
+            Word::Timestamp(ref val) => Ok(val.clone()),
+            _ => Err(word),
+        }
+    }
+}
+
+impl<'a> From<&'a Word> for Result<&'a TimestampValue, &'a Word> {
+    fn from(word: &'a Word) -> Result<&'a TimestampValue, &'a Word> {
+        match *word {
+            Word::Timestamp(ref val) => Ok(val),
+            _ => Err(word),
+        }
+    }
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+macro_rules! timestamp_from {
+    ($t:ty) => {
+        impl From<Word> for Result<$t, Word> {
+            fn from(word: Word) -> Result<$t, Word> {
+                if let Word::Timestamp(ref val) = word {
+                    let res: Result<$t, &TimestampValue> = val.into();
+                    if let Ok(v) = res {
+                        return Ok(v);
+                    }
+                }
+
+                Err(word)
+            }
+        }
+
+        impl<'a> From<&'a Word> for Result<$t, &'a Word> {
+            fn from(word: &'a Word) -> Result<$t, &'a Word> {
+                match *word {
+                    Word::Timestamp(ref val) => {
+                        let res: Result<$t, &TimestampValue> = val.into();
+                        res.or(Err(word))
+                    }
+                    _ => Err(word),
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "chrono")]
+timestamp_from!(chrono::NaiveDate);
+#[cfg(feature = "chrono")]
+timestamp_from!(chrono::NaiveTime);
+#[cfg(feature = "chrono")]
+timestamp_from!(chrono::NaiveDateTime);
+#[cfg(feature = "chrono")]
+timestamp_from!(chrono::DateTime<chrono::FixedOffset>);
+#[cfg(feature = "chrono")]
+timestamp_from!(chrono::DateTime<chrono::Utc>);
+
+#[cfg(feature = "time")]
+timestamp_from!(time::Date);
+#[cfg(feature = "time")]
+timestamp_from!(time::Time);
+#[cfg(feature = "time")]
+timestamp_from!(time::PrimitiveDateTime);
+#[cfg(feature = "time")]
+timestamp_from!(time::OffsetDateTime);
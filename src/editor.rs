@@ -0,0 +1,546 @@
+extern crate skimmer;
+
+use self::skimmer::reader::IntoReader;
+
+use crate::book::extractor::pointer::parse_index;
+use crate::book::extractor::Pointer;
+use crate::book::value::Value;
+use crate::book::word::Word;
+use crate::book::Book;
+
+use crate::model::schema::core::Core;
+use crate::model::yaml::str::{double_quoted, QuoteAmbiguous};
+
+use crate::orchestra::chord::Chord;
+use crate::orchestra::{OrchError, Orchestra};
+
+use crate::reader::{ReadError, Reader, Span};
+use crate::savant::Savant;
+
+use std::borrow::Cow;
+use std::error;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum EditError {
+    ReadError(ReadError),
+    OrchError(OrchError),
+    NotFound,
+    Unsupported(Cow<'static, str>),
+}
+
+impl fmt::Display for EditError {
+    fn fmt(&self, fmtter: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EditError::ReadError(ref err) => write!(fmtter, "{} at {}", err, err.location),
+            EditError::OrchError(ref err) => write!(fmtter, "{}", err),
+            EditError::NotFound => write!(fmtter, "Node not found"),
+            EditError::Unsupported(ref err) => write!(fmtter, "{}", err),
+        }
+    }
+}
+
+impl error::Error for EditError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            EditError::ReadError(ref err) => Some(err),
+            EditError::OrchError(ref err) => Some(err),
+            EditError::NotFound | EditError::Unsupported(_) => None,
+        }
+    }
+}
+
+impl From<ReadError> for EditError {
+    fn from(err: ReadError) -> EditError {
+        EditError::ReadError(err)
+    }
+}
+
+impl From<OrchError> for EditError {
+    fn from(err: OrchError) -> EditError {
+        EditError::OrchError(err)
+    }
+}
+
+/// Edits a document source in place, keeping the comments, styles and whitespace.
+///
+/// Every node of the book keeps the span of its source text, so an edit only
+/// replaces the text of the node in question and leaves the rest of the source
+/// byte-for-byte. The source is read again after every edit.
+///
+/// Paths address the first document of the source: map keys are matched
+/// by their values (or source text for non-string keys), sequence items by their indexes.
+/// Nodes under an alias are the anchored ones, so editing them affects every alias.
+pub struct Editor {
+    source: String,
+    book: Book,
+}
+
+impl Editor {
+    pub fn new<S: Into<String>>(source: S) -> Result<Editor, ReadError> {
+        let source = source.into();
+        let book = read(&source)?;
+
+        Ok(Editor { source, book })
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn into_source(self) -> String {
+        self.source
+    }
+
+    pub fn book(&self) -> &Book {
+        &self.book
+    }
+
+    /// The source text of a span
+    pub fn text(&self, span: Span) -> &str {
+        &self.source[span.start.offset..span.end.offset]
+    }
+
+    /// The root node of the first document
+    pub fn root(&self) -> Option<Pointer<'_>> {
+        self.book.volumes.first().and_then(Pointer::new)
+    }
+
+    pub fn find(&self, path: &[&str]) -> Option<Pointer<'_>> {
+        let mut pointer = self.root()?;
+
+        for segment in path {
+            pointer = self.child(pointer, segment)?.1;
+        }
+
+        Some(pointer)
+    }
+
+    /// Replaces the source text of the span.
+    /// Nothing changes if the resulting source cannot be read, has errors or unbound aliases.
+    pub fn replace(&mut self, span: Span, text: &str) -> Result<(), EditError> {
+        self.splice(span.start.offset, span.end.offset, text)
+    }
+
+    /// Replaces the node with the value.
+    /// A string replacing a quoted or block scalar keeps its style.
+    pub fn set(&mut self, path: &[&str], value: Value) -> Result<(), EditError> {
+        let pointer = self.find(path).ok_or(EditError::NotFound)?;
+        let span = pointer.span();
+        let original = self.text(span);
+
+        if is_empty_node(pointer, original) {
+            let text = format!(" {}", render(value, span.end.column)?);
+            return self.splice(span.end.offset, span.end.offset, &text);
+        }
+
+        let text = match value {
+            Value::Str(ref string) => restyle(original, string, self.indent_of(span)),
+            _ => None,
+        };
+
+        match text {
+            Some(text) => {
+                let (end, text) = self.keep_end(original, span.end.offset, text);
+                self.splice(span.start.offset, end, &text)
+            }
+            None => {
+                let text = render(value, span.start.column - 1)?;
+                self.replace(span, &text)
+            }
+        }
+    }
+
+    /// Adds the entry to the end of the map, or replaces the value if the key is there already
+    pub fn insert(&mut self, path: &[&str], key: &str, value: Value) -> Result<(), EditError> {
+        let map = self.find(path).ok_or(EditError::NotFound)?.unalias();
+
+        if !matches!(*map.to_word(), Word::Map(_)) {
+            return Err(EditError::Unsupported(Cow::from("The node is not a map")));
+        }
+
+        if self.child(map, key).is_some() {
+            let mut path = path.to_vec();
+            path.push(key);
+            return self.set(&path, value);
+        }
+
+        let key = render(Value::from(key), 0)?;
+        let last = last_child(map);
+
+        if self.text(map.span()).starts_with('{') {
+            let value = render(value, 0)?;
+
+            match last {
+                Some(last) => {
+                    let offset = last.span().end.offset;
+                    self.splice(offset, offset, &format!(", {}: {}", key, value))
+                }
+                None => {
+                    let offset = map.span().start.offset + 1;
+                    self.splice(offset, offset, &format!("{}: {}", key, value))
+                }
+            }
+        } else {
+            let first = map.into_map().ok_or(EditError::NotFound)?;
+            let indent = first.span().start.column - 1;
+            let value = render(value, indent + key.chars().count() + 2)?;
+
+            let entry = format!("{:indent$}{}: {}", "", key, value, indent = indent);
+
+            self.append_line(map.span().end.offset, &entry)
+        }
+    }
+
+    /// Adds the item to the end of the sequence
+    pub fn push(&mut self, path: &[&str], value: Value) -> Result<(), EditError> {
+        let seq = self.find(path).ok_or(EditError::NotFound)?.unalias();
+
+        if !matches!(*seq.to_word(), Word::Seq(_)) {
+            return Err(EditError::Unsupported(Cow::from(
+                "The node is not a sequence",
+            )));
+        }
+
+        let last = last_child(seq);
+
+        if self.text(seq.span()).starts_with('[') {
+            let value = render(value, 0)?;
+
+            match last {
+                Some(last) => {
+                    let offset = last.span().end.offset;
+                    self.splice(offset, offset, &format!(", {}", value))
+                }
+                None => {
+                    let offset = seq.span().start.offset + 1;
+                    self.splice(offset, offset, &value)
+                }
+            }
+        } else {
+            let first = seq.into_seq().ok_or(EditError::NotFound)?.span();
+            let head = &self.source[self.line_start(first.start.offset)..first.start.offset];
+
+            let dash = match head.rfind('-') {
+                Some(dash) if head[..dash].chars().all(char::is_whitespace) => dash,
+                _ => {
+                    return Err(EditError::Unsupported(Cow::from(
+                        "Cannot find the sequence indentation",
+                    )))
+                }
+            };
+
+            let indent = head[..dash].chars().count();
+            let value = render(value, indent + 2)?;
+
+            let item = format!("{:indent$}- {}", "", value, indent = indent);
+
+            self.append_line(seq.span().end.offset, &item)
+        }
+    }
+
+    /// Removes a map entry or a sequence item along with its lines
+    pub fn remove(&mut self, path: &[&str]) -> Result<(), EditError> {
+        let (last, parent) = match path.split_last() {
+            Some(split) => split,
+            None => return Err(EditError::Unsupported(Cow::from("Cannot remove the root"))),
+        };
+
+        let parent = self.find(parent).ok_or(EditError::NotFound)?.unalias();
+        let (key, value) = self.child(parent, last).ok_or(EditError::NotFound)?;
+
+        let mut start = key.map_or(value.span(), Pointer::span).start.offset;
+        let mut end = value.span().end.offset;
+
+        let flow = {
+            let text = self.text(parent.span());
+            text.starts_with('{') || text.starts_with('[')
+        };
+
+        if flow {
+            let tail = &self.source[end..];
+            let trimmed = tail.trim_start();
+
+            if let Some(rest) = trimmed.strip_prefix(',') {
+                end += tail.len() - rest.trim_start().len();
+            } else {
+                let head = self.source[..start].trim_end();
+                if head.ends_with(',') {
+                    start = head.len() - 1;
+                }
+            }
+        } else {
+            let line = self.line_start(start);
+            let head = &self.source[line..start];
+
+            // a sequence item is preceded by its dash
+            let head = match key {
+                Some(_) => Some(head),
+                None => head.trim_end().strip_suffix('-'),
+            };
+
+            if !head.is_some_and(|head| head.chars().all(char::is_whitespace)) {
+                return Err(EditError::Unsupported(Cow::from(
+                    "The node does not start its own line",
+                )));
+            }
+
+            start = line;
+            end = match self.source[end..].find('\n') {
+                Some(pos) => end + pos + 1,
+                None => self.source.len(),
+            };
+        }
+
+        self.splice(start, end, "")
+    }
+
+    /// The key (if that's a map) and the value of a child node
+    fn child<'a>(
+        &'a self,
+        pointer: Pointer<'a>,
+        segment: &str,
+    ) -> Option<(Option<Pointer<'a>>, Pointer<'a>)> {
+        match *pointer.unalias().to_word() {
+            Word::Map(_) => {
+                let mut key = pointer.into_map();
+
+                while let Some(ptr) = key {
+                    let value = ptr.next_sibling()?;
+
+                    let matched = match *ptr.unalias().to_word() {
+                        Word::Str(ref string) => string == segment,
+                        _ => self.text(ptr.unalias().span()) == segment,
+                    };
+
+                    if matched {
+                        return Some((Some(ptr), value));
+                    }
+
+                    key = value.next_sibling();
+                }
+
+                None
+            }
+
+            Word::Seq(_) => {
+                let index = parse_index(segment)?;
+                let mut item = pointer.into_seq();
+
+                for _ in 0..index {
+                    item = item?.next_sibling();
+                }
+
+                item.map(|item| (None, item))
+            }
+
+            _ => None,
+        }
+    }
+
+    fn line_start(&self, offset: usize) -> usize {
+        self.source[..offset].rfind('\n').map_or(0, |pos| pos + 1)
+    }
+
+    /// The indentation of the line the span starts at
+    fn indent_of(&self, span: Span) -> usize {
+        let line = &self.source[self.line_start(span.start.offset)..];
+        line.len() - line.trim_start_matches(' ').len()
+    }
+
+    /// The span of a block scalar ends with its last line, so the blank lines a keeping (`+`)
+    /// one has after that are replaced as well, when either the original or the new text keeps them
+    fn keep_end(&self, original: &str, end: usize, text: String) -> (usize, String) {
+        if !keeps(original) && !keeps(&text) {
+            return (end, text);
+        }
+
+        let tail = &self.source[end..];
+        let mut last = None;
+        let mut pos = 0;
+
+        while let Some(len) = tail[pos..].find('\n') {
+            if !tail[pos..pos + len].trim().is_empty() {
+                break;
+            }
+
+            last = Some(pos + len);
+            pos += len + 1;
+        }
+
+        match last {
+            // the line break in front of the next line stays, as it ends the last line of the text
+            Some(last) => (end + last, text),
+            None => (end, text + "\n"),
+        }
+    }
+
+    /// Puts the line after the one the offset is at
+    fn append_line(&mut self, offset: usize, line: &str) -> Result<(), EditError> {
+        match self.source[offset..].find('\n') {
+            Some(pos) => {
+                let offset = offset + pos + 1;
+                self.splice(offset, offset, &format!("{}\n", line))
+            }
+            None => {
+                let offset = self.source.len();
+                self.splice(offset, offset, &format!("\n{}", line))
+            }
+        }
+    }
+
+    fn splice(&mut self, start: usize, end: usize, text: &str) -> Result<(), EditError> {
+        let mut source = String::with_capacity(self.source.len() + text.len());
+
+        source.push_str(&self.source[..start]);
+        source.push_str(text);
+        source.push_str(&self.source[end..]);
+
+        self.book = read(&source)?;
+        self.source = source;
+
+        Ok(())
+    }
+}
+
+fn read(source: &str) -> Result<Book, ReadError> {
+    let mut book = Book::new();
+    let mut reader = Reader::new();
+    let mut savant = Savant::new(Core::new());
+
+    reader.read(
+        source.as_bytes().into_reader(),
+        &mut |block| match savant.think(block) {
+            Ok(Some(idea)) => {
+                book.stamp(idea);
+                Ok(())
+            }
+            Ok(None) => Ok(()),
+            Err(error) => Err(Cow::from(error.to_string())),
+        },
+    )?;
+
+    for volume in &book.volumes {
+        for (_, _, word) in &volume.gist {
+            match *word {
+                Word::Err(ref message, ref location) => {
+                    let error = ReadError::new(message.clone());
+
+                    return Err(match *location {
+                        Some(ref location) => error.at(location.clone()),
+                        None => error,
+                    });
+                }
+                Word::UnboundAlias(ref alias) => {
+                    return Err(ReadError::new(format!("Unbound alias *{}", alias)))
+                }
+                _ => (),
+            }
+        }
+    }
+
+    Ok(book)
+}
+
+/// Renders the value in the flow style, indenting its continuation lines.
+/// The strings that would not be read back as they are from the plain form get quoted.
+fn render(value: Value, indent: usize) -> Result<String, EditError> {
+    let schema = Core::new();
+    let orchestra = Orchestra::solo(Core::new());
+
+    orchestra.volumes(1)?;
+    orchestra.vol_next()?;
+    orchestra.vol_reserve(value.chord_size())?;
+
+    let mut cs = orchestra.get_styles();
+    cs.set_flow(true);
+    cs.set_respect_threshold(false);
+
+    value.play(&orchestra, 0, None, cs, &mut [&mut QuoteAmbiguous(&schema)])?;
+
+    orchestra.vol_end()?;
+    orchestra.the_end()?;
+
+    let music =
+        String::from_utf8(orchestra.listen()?).map_err(|err| OrchError::Error(err.to_string()))?;
+
+    let padding = format!("\n{:indent$}", "", indent = indent);
+
+    Ok(music.trim_end_matches('\n').replace('\n', &padding))
+}
+
+/// Renders the string in the style of the original scalar, if that's a quoted or a block one
+fn restyle(original: &str, string: &str, indent: usize) -> Option<String> {
+    match original.chars().next() {
+        Some('\'') if !string.contains('\n') => Some(format!("'{}'", string.replace('\'', "''"))),
+
+        Some('"') => Some(double_quoted(string)),
+
+        Some('|') | Some('>') => {
+            let chomp = if string.ends_with("\n\n") {
+                "+"
+            } else if string.ends_with('\n') {
+                ""
+            } else {
+                "-"
+            };
+
+            let style = if string.contains('\n') && original.starts_with('>') {
+                '|'
+            } else {
+                original.chars().next()?
+            };
+
+            // the indentation indicator, when the first line would tell a wrong one by its spaces
+            let indicator = if string.trim_start_matches('\n').starts_with(' ') {
+                "2"
+            } else {
+                ""
+            };
+
+            let padding = format!("{:indent$}", "", indent = indent + 2);
+
+            let mut result = format!("{}{}{}", style, indicator, chomp);
+            for line in string.trim_end_matches('\n').split('\n') {
+                result.push('\n');
+                if !line.is_empty() {
+                    result.push_str(&padding);
+                    result.push_str(line);
+                }
+            }
+            for _ in 1..string.len() - string.trim_end_matches('\n').len() {
+                result.push('\n');
+            }
+
+            Some(result)
+        }
+
+        _ => None,
+    }
+}
+
+/// Whether the block scalar header has the keeping chomping indicator (`|+`, `>2+`)
+fn keeps(text: &str) -> bool {
+    match text.split(char::is_whitespace).next() {
+        Some(header) => header.starts_with(['|', '>']) && header.contains('+'),
+        None => false,
+    }
+}
+
+/// An empty node, which span is the indicator in front of it
+fn is_empty_node(pointer: Pointer<'_>, text: &str) -> bool {
+    matches!(*pointer.to_word(), Word::Null) && (text == ":" || text == "-")
+}
+
+fn last_child(pointer: Pointer<'_>) -> Option<Pointer<'_>> {
+    let mut child = match *pointer.to_word() {
+        Word::Map(_) => pointer.into_map()?,
+        _ => pointer.into_seq()?,
+    };
+
+    while let Some(next) = child.next_sibling() {
+        child = next;
+    }
+
+    Some(child)
+}
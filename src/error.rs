@@ -0,0 +1,300 @@
+#[cfg(feature = "serde")]
+use crate::book::de;
+#[cfg(feature = "validator")]
+use crate::book::validator::SchemaError;
+use crate::editor::EditError;
+use crate::limits::{Limit, LimitError};
+use crate::model::DecodeError;
+#[cfg(feature = "serde")]
+use crate::orchestra::ser;
+use crate::orchestra::OrchError;
+use crate::reader::{Location, ReadError};
+use crate::sage::SageError;
+
+use std::borrow::Cow;
+use std::error;
+use std::fmt;
+use std::io;
+
+/// What has gone wrong
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// The source or the sink has failed
+    Io,
+
+    /// The stream is not well-formed YAML
+    Read,
+
+    /// A scalar cannot be decoded by the model of its tag
+    Decode,
+
+    /// The savant or the sage cannot make sense of the read blocks
+    Sage,
+
+    /// The orchestra cannot play the music
+    Orchestra,
+
+    /// The editor cannot apply the change
+    Edit,
+
+    /// The node cannot be deserialized into the type (`book::de`)
+    Deserialize,
+
+    /// The value cannot be serialized (`orchestra::ser`)
+    Serialize,
+
+    /// The schema of the validator cannot be understood
+    Schema,
+
+    /// The document exceeds one of the `Limits`
+    Limit(Limit),
+}
+
+/// The error of any part of the processor, so that the reader, savant, sage, book
+/// and orchestra failures can go through `?` together (e.g. into `Box<dyn std::error::Error>`).
+///
+/// The message is human-readable and the location is known for the errors
+/// found in the source. The error it's been made from is kept as its source
+/// whenever that one has got more to tell (such as `DecodeError` or `io::Error`),
+/// the message only saying what has failed then.
+#[derive(Debug)]
+pub struct Error {
+    kind: ErrorKind,
+    message: Cow<'static, str>,
+    location: Option<Location>,
+    source: Option<Box<dyn error::Error + Send + Sync + 'static>>,
+}
+
+impl Error {
+    pub fn new<T>(kind: ErrorKind, message: T) -> Error
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        Error {
+            kind,
+            message: message.into(),
+            location: None,
+            source: None,
+        }
+    }
+
+    pub fn at(mut self, location: Location) -> Error {
+        self.location = Some(location);
+        self
+    }
+
+    pub fn caused_by<E>(mut self, source: E) -> Error
+    where
+        E: error::Error + Send + Sync + 'static,
+    {
+        self.source = Some(Box::new(source));
+        self
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    pub fn message(&self) -> &str {
+        self.message.as_ref()
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        self.location.as_ref()
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, fmtter: &mut fmt::Formatter) -> fmt::Result {
+        match self.location {
+            Some(ref location) => write!(fmtter, "{} at {}", self.message, location),
+            None => write!(fmtter, "{}", self.message),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self.source {
+            Some(ref source) => Some(source.as_ref()),
+            None => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Error {
+        Error::new(ErrorKind::Io, "I/O error").caused_by(err)
+    }
+}
+
+impl From<ReadError> for Error {
+    fn from(err: ReadError) -> Error {
+        let kind = match err.limit {
+            Some(limit) => ErrorKind::Limit(limit),
+            None => ErrorKind::Read,
+        };
+
+        let error = Error::new(kind, err.description);
+
+        // the location of the errors found outside of the source is the zero line
+        if err.location.line > 0 {
+            error.at(err.location)
+        } else {
+            error
+        }
+    }
+}
+
+impl From<LimitError> for Error {
+    fn from(err: LimitError) -> Error {
+        Error::new(ErrorKind::Limit(err.limit), err.to_string())
+    }
+}
+
+impl From<DecodeError> for Error {
+    fn from(err: DecodeError) -> Error {
+        Error::new(ErrorKind::Decode, "A scalar cannot be decoded").caused_by(err)
+    }
+}
+
+impl From<SageError> for Error {
+    fn from(err: SageError) -> Error {
+        match err {
+            SageError::Error(message) => Error::new(ErrorKind::Sage, message),
+            SageError::IoError(err) => Error::from(err),
+            SageError::DecodeError(err) => Error::from(err),
+        }
+    }
+}
+
+impl From<OrchError> for Error {
+    fn from(err: OrchError) -> Error {
+        match err {
+            OrchError::Error(message) => Error::new(ErrorKind::Orchestra, message),
+            OrchError::IoError(err) => Error::from(err),
+        }
+    }
+}
+
+impl From<EditError> for Error {
+    fn from(err: EditError) -> Error {
+        match err {
+            EditError::ReadError(err) => Error::from(err),
+            EditError::OrchError(err) => Error::from(err),
+            err @ EditError::NotFound | err @ EditError::Unsupported(_) => {
+                Error::new(ErrorKind::Edit, err.to_string())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<de::Error> for Error {
+    fn from(err: de::Error) -> Error {
+        let kind = match err.limit() {
+            Some(error) => ErrorKind::Limit(error.limit),
+            None => ErrorKind::Deserialize,
+        };
+
+        Error::new(kind, "The document cannot be deserialized").caused_by(err)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<ser::Error> for Error {
+    fn from(err: ser::Error) -> Error {
+        Error::new(ErrorKind::Serialize, "The value cannot be serialized").caused_by(err)
+    }
+}
+
+#[cfg(feature = "validator")]
+impl From<SchemaError> for Error {
+    fn from(err: SchemaError) -> Error {
+        Error::new(ErrorKind::Schema, "The JSON Schema cannot be understood").caused_by(err)
+    }
+}
+
+#[cfg(all(test, not(feature = "dev")))]
+mod tests {
+    use super::*;
+
+    use std::error::Error as StdError;
+
+    #[test]
+    fn display() {
+        let location = Location {
+            offset: 10,
+            line: 2,
+            column: 5,
+            text: String::from("key: [value"),
+        };
+
+        let error = Error::from(ReadError::new("Unexpected end").at(location));
+
+        assert_eq!(ErrorKind::Read, error.kind());
+        assert_eq!("Unexpected end at line 2, column 5", error.to_string());
+
+        let error = Error::from(ReadError::new("Cannot yield a block"));
+
+        assert!(error.location().is_none());
+        assert_eq!("Cannot yield a block", error.to_string());
+    }
+
+    #[test]
+    fn source() {
+        let error = Error::from(SageError::IoError(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "eof",
+        )));
+
+        assert_eq!(ErrorKind::Io, error.kind());
+        assert_eq!("I/O error", error.to_string());
+
+        let source = error.source().unwrap().downcast_ref::<io::Error>();
+        assert_eq!(
+            Some(io::ErrorKind::UnexpectedEof),
+            source.map(io::Error::kind)
+        );
+
+        let error = Error::from(OrchError::Error(String::from("orchestra vanished")));
+
+        assert_eq!(ErrorKind::Orchestra, error.kind());
+        assert!(error.source().is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let error = Error::from(de::Error::new("invalid type: string, expected u8"));
+
+        assert_eq!(ErrorKind::Deserialize, error.kind());
+        assert_eq!("The document cannot be deserialized", error.to_string());
+        assert_eq!(
+            "invalid type: string, expected u8",
+            error.source().unwrap().to_string()
+        );
+        assert!(error.source().unwrap().is::<de::Error>());
+
+        let error = Error::from(ser::Error::new("the key must be a scalar"));
+
+        assert_eq!(ErrorKind::Serialize, error.kind());
+        assert!(error.source().unwrap().is::<ser::Error>());
+    }
+
+    #[cfg(feature = "validator")]
+    #[test]
+    fn schema() {
+        let error = Error::from(SchemaError {
+            path: String::from("/properties/a/type"),
+            message: Cow::from("Unknown type"),
+        });
+
+        assert_eq!(ErrorKind::Schema, error.kind());
+        assert_eq!("The JSON Schema cannot be understood", error.to_string());
+        assert_eq!(
+            "Unknown type at #/properties/a/type",
+            error.source().unwrap().to_string()
+        );
+    }
+}
@@ -0,0 +1,482 @@
+extern crate skimmer;
+
+use self::skimmer::reader::{IntoReader, Read};
+use self::skimmer::Datum;
+
+use crate::model::schema::Schema;
+use crate::model::yaml::map;
+use crate::model::yaml::seq;
+use crate::model::yaml::str::StrValue;
+use crate::model::{Tagged, TaggedValue};
+use crate::reader::{BlockType, Id, Location, Reader, Span};
+use crate::sage::{Idea, SageError};
+use crate::savant::Savant;
+
+use std::borrow::Cow;
+use std::collections::{HashSet, VecDeque};
+use std::io;
+use std::sync::mpsc::{sync_channel, Receiver};
+use std::thread::{Builder, JoinHandle};
+
+/// The way a scalar has been written in the source
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScalarStyle {
+    Plain,
+    SingleQuoted,
+    DoubleQuoted,
+    Literal,
+    Folded,
+}
+
+#[derive(Debug)]
+pub enum Event {
+    StreamStart,
+    StreamEnd,
+
+    DocumentStart,
+    DocumentEnd,
+
+    MappingStart {
+        anchor: Option<String>,
+        tag: Cow<'static, str>,
+        span: Span,
+    },
+    MappingEnd,
+
+    SequenceStart {
+        anchor: Option<String>,
+        tag: Cow<'static, str>,
+        span: Span,
+    },
+    SequenceEnd,
+
+    Scalar {
+        anchor: Option<String>,
+        tag: Cow<'static, str>,
+        style: ScalarStyle,
+        value: TaggedValue,
+        span: Span,
+    },
+
+    Alias {
+        name: String,
+        span: Span,
+    },
+
+    Warning(Cow<'static, str>, Location),
+    Error(Cow<'static, str>, Option<Location>),
+}
+
+/// Pull-based stream of events read from the source.
+///
+/// The reader and the savant work in a separate thread, staying up to `capacity` events
+/// ahead of the consumer. No `Book` or `Volume` is being built; the events are passed on
+/// as soon as the nodes are read. The only exception is flow collections, which the reader
+/// announces after their contents, so those are held back until complete.
+pub struct Events {
+    pipe: Receiver<Event>,
+    _worker: JoinHandle<()>,
+}
+
+impl Events {
+    pub fn new<R, D, S>(source: R, schema: S) -> io::Result<Events>
+    where
+        R: IntoReader + Send + 'static,
+        R::Reader: Read<Datum = D>,
+        D: Datum + 'static,
+        S: Schema + 'static,
+    {
+        Events::with_capacity(source, schema, 256)
+    }
+
+    pub fn with_capacity<R, D, S>(source: R, schema: S, capacity: usize) -> io::Result<Events>
+    where
+        R: IntoReader + Send + 'static,
+        R::Reader: Read<Datum = D>,
+        D: Datum + 'static,
+        S: Schema + 'static,
+    {
+        let (sender, pipe) = sync_channel(capacity);
+
+        let worker = Builder::new()
+            .name("events_reader".to_string())
+            .spawn(move || {
+                Herald::new(move |event| sender.send(event).is_ok())
+                    .run(source.into_reader(), schema)
+            })?;
+
+        Ok(Events {
+            pipe,
+            _worker: worker,
+        })
+    }
+}
+
+impl Iterator for Events {
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Event> {
+        self.pipe.recv().ok()
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Collection {
+    Mapping,
+    Sequence,
+}
+
+/// Turns the ideas of the savant into events
+pub(crate) struct Herald<D> {
+    /// Passes the event on, telling whether it is still listened to
+    out: Box<dyn FnMut(Event) -> bool>,
+
+    /// The datums from the one the last scalar starts in, along with their offsets in the source
+    data: VecDeque<(usize, D)>,
+    length: usize,
+
+    stack: Vec<(usize, usize, Collection)>,
+
+    /// The ideas held back until their parents come up, since flow collections follow their children
+    held: Vec<(Idea, ScalarStyle)>,
+    held_indexes: HashSet<usize>,
+    awaited: Vec<usize>,
+
+    /// The last scalar or alias, which may turn out to be the first key of a block mapping
+    pending: Option<(Id, Event)>,
+
+    /// Whether the reader has reported an error, which it also returns in the end
+    failed: bool,
+}
+
+impl<D> Herald<D>
+where
+    D: Datum + 'static,
+{
+    pub(crate) fn new<F>(out: F) -> Herald<D>
+    where
+        F: FnMut(Event) -> bool + 'static,
+    {
+        Herald {
+            out: Box::new(out),
+            data: VecDeque::new(),
+            length: 0,
+            stack: Vec::with_capacity(16),
+            held: Vec::new(),
+            held_indexes: HashSet::new(),
+            awaited: Vec::new(),
+            pending: None,
+            failed: false,
+        }
+    }
+
+    pub(crate) fn run<R, S>(mut self, reader: R, schema: S)
+    where
+        R: Read<Datum = D>,
+        S: Schema + 'static,
+    {
+        if self.send(Event::StreamStart).is_err() {
+            return;
+        }
+
+        let mut savant: Savant<S, D> = Savant::new(schema);
+
+        let result = Reader::new().read(reader, &mut |block| {
+            if let BlockType::Datum(ref datum) = block.cargo {
+                self.data.push_back((self.length, datum.clone()));
+                self.length += datum.len();
+            }
+
+            match savant.think(block) {
+                Ok(Some(idea)) => {
+                    let style = self.look(&idea);
+                    self.hold(idea, style)
+                }
+                Ok(None) => Ok(()),
+                Err(SageError::Error(message)) => Err(message),
+                Err(error) => Err(Cow::from(error.to_string())),
+            }
+        });
+
+        if let (Err(error), false) = (result, self.failed) {
+            if self
+                .send(Event::Error(error.description, Some(error.location)))
+                .is_err()
+            {
+                return;
+            }
+        }
+
+        self.send(Event::StreamEnd).ok();
+    }
+
+    fn hold(&mut self, idea: Idea, style: ScalarStyle) -> Result<(), Cow<'static, str>> {
+        let (index, parent) = match Herald::<D>::id(&idea) {
+            Some(id) => (id.index, id.parent),
+            None => {
+                self.release()?;
+                return self.stamp(idea, style);
+            }
+        };
+
+        let arrived = parent == 0
+            || self.stack.iter().any(|&(_, ix, _)| ix == parent)
+            || self.held_indexes.contains(&parent);
+
+        if self.held.is_empty() && arrived {
+            return self.stamp(idea, style);
+        }
+
+        if !arrived && !self.awaited.contains(&parent) {
+            self.awaited.push(parent);
+        }
+        self.awaited.retain(|&ix| ix != index);
+        self.held.push((idea, style));
+        self.held_indexes.insert(index);
+
+        if self.awaited.is_empty() {
+            self.release()
+        } else {
+            Ok(())
+        }
+    }
+
+    fn release(&mut self) -> Result<(), Cow<'static, str>> {
+        let mut held = std::mem::take(&mut self.held);
+        self.held_indexes.clear();
+        self.awaited.clear();
+
+        held.sort_by_key(|(idea, _)| Herald::<D>::id(idea).map_or(0, |id| id.index));
+
+        for (idea, style) in held {
+            self.stamp(idea, style)?;
+        }
+
+        Ok(())
+    }
+
+    fn id(idea: &Idea) -> Option<&Id> {
+        match *idea {
+            Idea::Alias(ref id, _)
+            | Idea::Error(ref id, _)
+            | Idea::NodeMetaMap(ref id, _, _, _)
+            | Idea::NodeMetaSeq(ref id, _, _)
+            | Idea::NodeDictionary(ref id, _, _, _)
+            | Idea::NodeSequence(ref id, _, _)
+            | Idea::NodeScalar(ref id, _, _)
+            | Idea::NodeLiteral(ref id, _, _)
+            | Idea::ReadError(ref id, _, _)
+            | Idea::ReadWarning(ref id, _, _) => Some(id),
+
+            Idea::Done | Idea::Dawn | Idea::Dusk => None,
+        }
+    }
+
+    fn stamp(&mut self, idea: Idea, style: ScalarStyle) -> Result<(), Cow<'static, str>> {
+        match idea {
+            Idea::Done => {
+                self.flush()?;
+                self.close(0)
+            }
+
+            Idea::Dawn => self.send(Event::DocumentStart),
+
+            Idea::Dusk => {
+                self.flush()?;
+                self.close(0)?;
+                self.send(Event::DocumentEnd)
+            }
+
+            Idea::Error(_, message) => self.send(Event::Error(message, None)),
+            Idea::ReadError(_, location, message) => {
+                self.failed = true;
+                self.send(Event::Error(message, Some(location)))
+            }
+            Idea::ReadWarning(_, location, message) => self.send(Event::Warning(message, location)),
+
+            Idea::Alias(id, name) => {
+                let span = id.span;
+                self.node(id, Event::Alias { name, span })
+            }
+
+            Idea::NodeScalar(id, anchor, value) => {
+                let event = Event::Scalar {
+                    anchor,
+                    tag: value.get_tag(),
+                    style,
+                    value,
+                    span: id.span,
+                };
+                self.node(id, event)
+            }
+
+            Idea::NodeLiteral(id, anchor, value) => {
+                let value = TaggedValue::from(StrValue::from(Cow::from(value)));
+                let event = Event::Scalar {
+                    anchor,
+                    tag: value.get_tag(),
+                    style,
+                    value,
+                    span: id.span,
+                };
+                self.node(id, event)
+            }
+
+            Idea::NodeDictionary(id, anchor, tag, firstborn) => {
+                self.mapping(id, anchor, tag, firstborn)
+            }
+            Idea::NodeMetaMap(id, anchor, tag, firstborn) => {
+                let tag = tag.map_or(Cow::from(map::TAG), Cow::from);
+                self.mapping(id, anchor, tag, firstborn)
+            }
+
+            Idea::NodeSequence(id, anchor, tag) => self.sequence(id, anchor, tag),
+            Idea::NodeMetaSeq(id, anchor, tag) => {
+                let tag = tag.map_or(Cow::from(seq::TAG), Cow::from);
+                self.sequence(id, anchor, tag)
+            }
+        }
+    }
+
+    fn node(&mut self, id: Id, event: Event) -> Result<(), Cow<'static, str>> {
+        self.flush()?;
+        self.pending = Some((id, event));
+        Ok(())
+    }
+
+    fn mapping(
+        &mut self,
+        id: Id,
+        anchor: Option<String>,
+        tag: Cow<'static, str>,
+        firstborn: Option<Id>,
+    ) -> Result<(), Cow<'static, str>> {
+        let adopted = match (firstborn, &self.pending) {
+            (Some(firstborn), Some((pending, _))) if firstborn.index == pending.index => {
+                self.pending.take()
+            }
+            _ => None,
+        };
+
+        self.flush()?;
+        self.close(id.level)?;
+        self.send(Event::MappingStart {
+            anchor,
+            tag,
+            span: id.span,
+        })?;
+        self.stack.push((id.level, id.index, Collection::Mapping));
+
+        if let Some((_, key)) = adopted {
+            self.send(key)?;
+        }
+
+        Ok(())
+    }
+
+    fn sequence(
+        &mut self,
+        id: Id,
+        anchor: Option<String>,
+        tag: Cow<'static, str>,
+    ) -> Result<(), Cow<'static, str>> {
+        self.flush()?;
+        self.close(id.level)?;
+        self.send(Event::SequenceStart {
+            anchor,
+            tag,
+            span: id.span,
+        })?;
+        self.stack.push((id.level, id.index, Collection::Sequence));
+
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), Cow<'static, str>> {
+        if let Some((id, event)) = self.pending.take() {
+            self.close(id.level)?;
+            self.send(event)?;
+        }
+
+        Ok(())
+    }
+
+    /// Ends the collections at the level and deeper
+    fn close(&mut self, level: usize) -> Result<(), Cow<'static, str>> {
+        while let Some(&(lvl, _, collection)) = self.stack.last() {
+            if lvl < level {
+                break;
+            }
+
+            self.stack.pop();
+
+            self.send(match collection {
+                Collection::Mapping => Event::MappingEnd,
+                Collection::Sequence => Event::SequenceEnd,
+            })?;
+        }
+
+        Ok(())
+    }
+
+    fn send(&mut self, event: Event) -> Result<(), Cow<'static, str>> {
+        if (self.out)(event) {
+            Ok(())
+        } else {
+            Err(Cow::from("Events are not listened anymore"))
+        }
+    }
+
+    /// The style of the scalar, after which the datums before it are not needed anymore,
+    /// since the savant comes up with the scalars in the order of the source
+    fn look(&mut self, idea: &Idea) -> ScalarStyle {
+        let span = match *idea {
+            Idea::NodeScalar(ref id, _, _) | Idea::NodeLiteral(ref id, _, _) => id.span,
+            _ => return ScalarStyle::Plain,
+        };
+
+        let style = self.style(span);
+
+        while self.data.len() > 1 && self.data[1].0 <= span.start.offset {
+            self.data.pop_front();
+        }
+
+        style
+    }
+
+    /// Looks at the first byte of the node past its properties
+    fn style(&self, span: Span) -> ScalarStyle {
+        let mut offset = span.start.offset;
+
+        loop {
+            match self.byte(offset) {
+                Some(b'!') | Some(b'&') => {
+                    while let Some(byte) = self.byte(offset) {
+                        if byte == b' ' || byte == b'\t' || byte == b'\n' || byte == b'\r' {
+                            break;
+                        }
+                        offset += 1;
+                    }
+
+                    while let Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r') =
+                        self.byte(offset)
+                    {
+                        offset += 1;
+                    }
+                }
+                Some(b'"') => return ScalarStyle::DoubleQuoted,
+                Some(b'\'') => return ScalarStyle::SingleQuoted,
+                Some(b'|') => return ScalarStyle::Literal,
+                Some(b'>') => return ScalarStyle::Folded,
+                _ => return ScalarStyle::Plain,
+            }
+        }
+    }
+
+    fn byte(&self, offset: usize) -> Option<u8> {
+        let idx = self.data.partition_point(|&(start, _)| start <= offset);
+        let (start, ref datum) = *self.data.get(idx.checked_sub(1)?)?;
+
+        datum.as_slice().get(offset - start).copied()
+    }
+}
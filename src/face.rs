@@ -1,12 +1,24 @@
 pub extern crate skimmer;
 
+use crate::limits::Limits;
 use crate::model::schema::Schema;
+use crate::txt::Encoding;
 
 pub struct Options<S>
 where
     S: Schema + 'static,
 {
     pub schema: Option<S>,
+
+    /// Whether the writer plays in the caller thread instead of spawning the conductor and performers
+    pub solo: bool,
+
+    /// The encoding of the written music and whether it starts with the byte order mark
+    pub encoding: Encoding,
+    pub bom: bool,
+
+    /// The limits of the read documents
+    pub limits: Limits,
 }
 
 impl<S> Options<S>
@@ -14,7 +26,13 @@ where
     S: Schema + Clone + 'static,
 {
     pub fn new() -> Options<S> {
-        Options { schema: None }
+        Options {
+            schema: None,
+            solo: false,
+            encoding: Encoding::default(),
+            bom: false,
+            limits: Limits::default(),
+        }
     }
 }
 
@@ -26,6 +44,10 @@ where
     fn from(val: (S, Options<O>)) -> Options<S> {
         Options {
             schema: Some(val.0),
+            solo: val.1.solo,
+            encoding: val.1.encoding,
+            bom: val.1.bom,
+            limits: val.1.limits,
         }
     }
 }
@@ -42,6 +64,7 @@ macro_rules! yamlette {
 
     ( read ; warm ; $rs:expr ; $source:expr ; $rules:tt ; $options:tt ) => {
         let mut _book = $crate::book::Book::new ();
+        $crate::yamlette! ( options book ; _book ; $options );
 
         let _result/*: Result<(), Result<SageError, ReadError>>*/ = match *$rs {
             Ok ( (ref mut reader, ref mut savant) ) => {
@@ -49,7 +72,7 @@ macro_rules! yamlette {
                     $crate::face::skimmer::reader::IntoReader::into_reader ($source),
                     &mut |block| { match savant.think (block) {
                         Ok (maybe_idea) => { if let Some (idea) = maybe_idea { _book.stamp (idea); }; Ok ( () ) },
-                        Err (_) => Err (::std::borrow::Cow::from ("Cannot think of a block"))
+                        Err (error) => Err (::std::borrow::Cow::from (error.to_string ()))
                     } }
                 ) {
                     Ok (_) => Ok ( () ),
@@ -74,6 +97,7 @@ macro_rules! yamlette {
 
     ( sage ; warm ; $rs:expr ; $source:expr ; $rules:tt ; $options:tt ) => {
         let mut _book = $crate::book::Book::new ();
+        $crate::yamlette! ( options book ; _book ; $options );
 
         let _result/*: Result<(), Result<SageError, ReadError>>*/ = match *$rs {
             Ok ( (ref mut reader, ref mut sender, ref sage) ) => {
@@ -109,7 +133,50 @@ macro_rules! yamlette {
         $crate::yamlette_compose! ( orchestra ; $orchestra ; $rules );
 
         match $orchestra.listen () {
-            Ok (music) => Ok (unsafe { String::from_utf8_unchecked (music) }),
+            Ok (music) => match $orchestra.get_encoding () {
+                $crate::txt::Encoding::UTF8 (_) => Ok (unsafe { String::from_utf8_unchecked (music) }),
+                _ => Err ($crate::orchestra::OrchError::Error (String::from ("The music is not UTF-8 encoded, write bytes instead")))
+            },
+            Err (error) => Err (error)
+        }
+    }};
+
+    ( write bytes ; $rules:tt ) => {{ $crate::yamlette! ( write bytes ; $rules ; {} ) }};
+
+    ( write bytes ; $rules:tt ; $options:tt ) => {{
+        match $crate::yamlette! ( init ; writer ; $options ) {
+            Ok ( mut orch ) => yamlette! ( write bytes ; warm ; &mut orch ; $rules ),
+            Err ( err ) => Err ( err )
+        }
+    }};
+
+    ( write bytes ; warm ; $orchestra:expr ; $rules:tt ) => {{
+        $crate::yamlette_compose! ( orchestra ; $orchestra ; $rules );
+
+        $orchestra.listen ()
+    }};
+
+    ( write into ; $sink:expr ; $rules:tt ) => {{ $crate::yamlette! ( write into ; $sink ; $rules ; {} ) }};
+
+    ( write into ; $sink:expr ; $rules:tt ; $options:tt ) => {{
+        $crate::yamlette! ( options ; $options ; options );
+
+        fn into_sink_of<W: ::std::io::Write + 'static> (_: &W) -> fn ($crate::orchestra::Orchestra) -> Option<W> {
+            $crate::orchestra::Orchestra::into_sink::<W>
+        }
+
+        let sink = $sink;
+        let into_sink = into_sink_of (&sink);
+
+        let mut orch = $crate::orchestra::Orchestra::sink (options.schema.take ().unwrap (), sink);
+
+        orch.set_encoding (options.encoding);
+        orch.set_bom (options.bom);
+
+        $crate::yamlette_compose! ( orchestra ; orch ; $rules );
+
+        match orch.listen () {
+            Ok (_) => Ok (into_sink (orch).unwrap ()),
             Err (error) => Err (error)
         }
     }};
@@ -122,7 +189,7 @@ macro_rules! yamlette {
 
         let schema = options.schema.take ().unwrap ();
 
-        let reader = $crate::reader::Reader::new ();
+        let reader = $crate::reader::Reader::with_limits (options.limits);
         let savant = $crate::savant::Savant::new (schema);
 
         Ok ( (reader, savant) )
@@ -138,7 +205,7 @@ macro_rules! yamlette {
 
         let (sender, receiver) = ::std::sync::mpsc::channel ();
 
-        let reader = $crate::reader::Reader::new ();
+        let reader = $crate::reader::Reader::with_limits (options.limits);
 
         match $crate::sage::Sage::new (receiver, schema) {
             Ok (sage) => Ok ( (reader, sender, sage) ),
@@ -151,35 +218,64 @@ macro_rules! yamlette {
     ( init ; writer ; $options:tt ) => {{
         $crate::yamlette! ( options ; $options ; options );
 
-        match $crate::orchestra::Orchestra::new (options.schema.take ().unwrap ()) {
-            Ok ( orch ) => Ok ( orch ),
-            Err ( err ) => Err ( $crate::orchestra::OrchError::IoError ( err ) )
-        }
+        let orch = if options.solo {
+            Ok ( $crate::orchestra::Orchestra::solo (options.schema.take ().unwrap ()) )
+        } else {
+            match $crate::orchestra::Orchestra::new (options.schema.take ().unwrap ()) {
+                Ok ( orch ) => Ok ( orch ),
+                Err ( err ) => Err ( $crate::orchestra::OrchError::IoError ( err ) )
+            }
+        };
+
+        orch.map (|mut orch| {
+            orch.set_encoding (options.encoding);
+            orch.set_bom (options.bom);
+            orch
+        })
     }};
 
     ( options ; { $( $key:ident : $val:expr ),* } ; $var:ident ) => {
-        let mut $var: $crate::face::Options<$crate::model::schema::core::Core> = $crate::face::Options::new ();
+        // the options are shadowed rather than assigned, since the schema option changes their type
+        let $var: $crate::face::Options<$crate::model::schema::core::Core> = $crate::face::Options::from ((
+            $crate::model::schema::core::Core::new (),
+            $crate::face::Options::<$crate::model::schema::core::Core>::new ()
+        ));
 
         $(
-            $var = $crate::yamlette! ( option ; $var ; $key ; $val );
+            let $var = $crate::yamlette! ( option ; $var ; $key ; $val );
         )*
 
-        $var = if $var.schema.is_none () {
-            let schema = $crate::model::schema::core::Core::new ();
-            $crate::face::Options::from ((schema, $var))
-        } else {
-            $var
-        };
+        let mut $var = $var;
     };
 
     ( option ; $options:expr ; schema ; $schema:expr ) => {{ $crate::face::Options::from (($schema, $options)) }};
 
+    ( option ; $options:expr ; solo ; $solo:expr ) => {{ let mut options = $options; options.solo = $solo; options }};
+
+    ( option ; $options:expr ; encoding ; $encoding:expr ) => {{ let mut options = $options; options.encoding = $encoding; options }};
+
+    ( option ; $options:expr ; bom ; $bom:expr ) => {{ let mut options = $options; options.bom = $bom; options }};
+
+    ( option ; $options:expr ; limits ; $limits:expr ) => {{ let mut options = $options; options.limits = $limits; options }};
+
     ( option ; $options:expr ; $unu:tt ; $dua:tt ) => {{ $options }};
 
     ( option ; $options:expr ; $unu:expr ; $dua:expr ) => {{ $options }};
 
     ( option ; $options:expr ; $unu:ident ; $dua:ident ) => {{ $options }};
 
+    ( options book ; $book:expr ; { $( $key:ident : $val:expr ),* } ) => {
+        $(
+            $crate::yamlette! ( option book ; $book ; $key ; $val );
+        )*
+    };
+
+    ( option book ; $book:expr ; merge_keys ; $val:expr ) => { $book.set_merge_keys ($val); };
+
+    ( option book ; $book:expr ; limits ; $val:expr ) => { $book.set_limits ($val); };
+
+    ( option book ; $book:expr ; $unu:ident ; $dua:expr ) => {{ }};
+
     ( options moveout ; $book:expr ; $result:expr ; { $( $key:ident : $val:ident ),* } ) => {
         $(
             $crate::yamlette! ( option moveout ; $book ; $result ; $key ; $val );
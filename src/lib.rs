@@ -1,6 +1,12 @@
+#[cfg(feature = "tokio")]
+pub mod async_tokio;
 pub mod book;
 pub mod composer;
+pub mod editor;
+pub mod error;
+pub mod events;
 pub mod face;
+pub mod limits;
 pub mod model;
 pub mod orchestra;
 pub mod reader;
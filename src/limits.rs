@@ -0,0 +1,131 @@
+use std::error;
+use std::fmt;
+
+/// The bounds on the documents, so that the ones coming from untrusted sources
+/// cannot exhaust the memory or the stack (e.g. with the "billion laughs" of nested aliases).
+///
+/// `None` means unlimited, which is the default.
+/// The reader enforces the depth, the aliases, the scalar length and the document size,
+/// the book enforces the aliases and the number of nodes the aliases expand into,
+/// and the extraction (`Value::try_from_volume`, `book::de`) the depth and the nodes
+/// of the tree it builds.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Limits {
+    /// How deeply the nodes may nest
+    pub max_depth: Option<usize>,
+
+    /// How many aliases a document may have
+    pub max_aliases: Option<usize>,
+
+    /// How many nodes a document may have with every alias expanded into a copy of its anchor
+    pub max_nodes: Option<usize>,
+
+    /// How many bytes a scalar may take in the source
+    pub max_scalar_len: Option<usize>,
+
+    /// How many bytes a document may take in the source
+    pub max_document_size: Option<usize>,
+}
+
+impl Limits {
+    pub fn new() -> Limits {
+        Limits::default()
+    }
+
+    /// Conservative limits for the documents submitted by the users of a service
+    pub fn untrusted() -> Limits {
+        Limits {
+            max_depth: Some(128),
+            max_aliases: Some(1024),
+            max_nodes: Some(1 << 20),
+            max_scalar_len: Some(1 << 20),
+            max_document_size: Some(16 << 20),
+        }
+    }
+}
+
+/// One of the `Limits`
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Limit {
+    Depth,
+    Aliases,
+    Nodes,
+    ScalarLength,
+    DocumentSize,
+}
+
+impl Limit {
+    /// The maximum for this limit, if there is one
+    pub fn of(self, limits: &Limits) -> Option<usize> {
+        match self {
+            Limit::Depth => limits.max_depth,
+            Limit::Aliases => limits.max_aliases,
+            Limit::Nodes => limits.max_nodes,
+            Limit::ScalarLength => limits.max_scalar_len,
+            Limit::DocumentSize => limits.max_document_size,
+        }
+    }
+
+    /// The error when the value is over the maximum for this limit
+    pub fn check(self, limits: &Limits, value: usize) -> Result<(), LimitError> {
+        match self.of(limits) {
+            Some(max) if value > max => Err(LimitError { limit: self, max }),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for Limit {
+    fn fmt(&self, fmtter: &mut fmt::Formatter) -> fmt::Result {
+        fmtter.write_str(match *self {
+            Limit::Depth => "nesting depth",
+            Limit::Aliases => "aliases",
+            Limit::Nodes => "nodes",
+            Limit::ScalarLength => "scalar length",
+            Limit::DocumentSize => "document size",
+        })
+    }
+}
+
+/// A limit a document has exceeded
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LimitError {
+    pub limit: Limit,
+    pub max: usize,
+}
+
+impl fmt::Display for LimitError {
+    fn fmt(&self, fmtter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmtter,
+            "The document exceeds the limit of {} ({})",
+            self.limit, self.max
+        )
+    }
+}
+
+impl error::Error for LimitError {}
+
+#[cfg(all(test, not(feature = "dev")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check() {
+        let limits = Limits {
+            max_aliases: Some(2),
+            ..Limits::new()
+        };
+
+        assert_eq!(Ok(()), Limit::Aliases.check(&limits, 2));
+        assert_eq!(Ok(()), Limit::Nodes.check(&limits, usize::MAX));
+
+        let error = Limit::Aliases.check(&limits, 3).unwrap_err();
+
+        assert_eq!(Limit::Aliases, error.limit);
+        assert_eq!(
+            "The document exceeds the limit of aliases (2)",
+            error.to_string()
+        );
+    }
+}
@@ -11,6 +11,8 @@ extern crate skimmer;
 
 use std::any::Any;
 use std::borrow::Cow;
+use std::error;
+use std::fmt;
 use std::iter::Iterator;
 
 pub use self::renderer::{EncodedString, Node, Renderer};
@@ -19,7 +21,54 @@ pub use self::schema::Schema;
 pub use self::style::CommonStyles;
 pub use self::tagged_value::TaggedValue;
 
-// TODO: decode/encode Errors&Warnings
+/// Explains why a model could not decode a scalar
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodeError {
+    /// The tag of the model
+    pub tag: Cow<'static, str>,
+
+    /// The scalar as it was in the source (lossy if it's not valid UTF-8)
+    pub value: String,
+
+    pub reason: Cow<'static, str>,
+}
+
+impl DecodeError {
+    pub fn new<M, R>(model: &M, value: &[u8], reason: R) -> DecodeError
+    where
+        M: Model + ?Sized,
+        R: Into<Cow<'static, str>>,
+    {
+        DecodeError {
+            tag: model.get_tag(),
+            value: String::from_utf8_lossy(value).into_owned(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, fmtter: &mut fmt::Formatter) -> fmt::Result {
+        const PREVIEW: usize = 32;
+
+        let value = if self.value.chars().count() > PREVIEW {
+            Cow::from(format!(
+                "{}...",
+                self.value.chars().take(PREVIEW).collect::<String>()
+            ))
+        } else {
+            Cow::from(self.value.as_str())
+        };
+
+        write!(
+            fmtter,
+            "cannot decode \"{}\" as {}: {}",
+            value, self.tag, self.reason
+        )
+    }
+}
+
+impl error::Error for DecodeError {}
 
 pub trait Tagged: Any {
     fn get_tag(&self) -> Cow<'static, str>;
@@ -29,6 +78,9 @@ pub trait Tagged: Any {
     fn as_mut_any(&mut self) -> &mut dyn Any;
 }
 
+/// The model of the values of a tag. Whatever the model is not capable of
+/// (as told by `is_decodable`, `is_encodable`, `has_default` and so on) is refused
+/// by the default methods with an error, so that no document can abort the process.
 pub trait Model: Send + Sync {
     fn get_tag(&self) -> Cow<'static, str>;
 
@@ -64,44 +116,52 @@ pub trait Model: Send + Sync {
         false
     }
 
-    fn get_default(&self) -> TaggedValue {
-        panic!("Model does not have a default value");
+    fn get_default(&self) -> Result<TaggedValue, DecodeError> {
+        Err(DecodeError::new(
+            self,
+            b"",
+            "the model has no default value",
+        ))
     }
 
     fn meta_init(
         &self,
         _anchor: Option<String>,
         _tag: Option<String>,
-        _value: &[u8],
-    ) -> Result<TaggedValue, ()> {
-        panic!("Model is not a metamodel");
+        value: &[u8],
+    ) -> Result<TaggedValue, DecodeError> {
+        Err(DecodeError::new(
+            self,
+            value,
+            "the model is not a metamodel",
+        ))
     }
 
-    fn decode(&self, _explicit: bool, _value: &[u8]) -> Result<TaggedValue, ()> {
-        panic!("Model is not decodable");
+    fn decode(&self, _explicit: bool, value: &[u8]) -> Result<TaggedValue, DecodeError> {
+        Err(DecodeError::new(self, value, "the model is not decodable"))
     }
 
-    fn decode11(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, ()> {
+    fn decode11(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, DecodeError> {
         self.decode(explicit, value)
     }
 
     fn encode(
         &self,
         _renderer: &Renderer,
-        _value: TaggedValue,
+        value: TaggedValue,
         _tags: &mut dyn Iterator<Item = &(Cow<'static, str>, Cow<'static, str>)>,
     ) -> Result<Rope, TaggedValue> {
-        panic!("Model is not encodable");
+        Err(value)
     }
 
     fn compose(
         &self,
         _renderer: &Renderer,
-        _value: TaggedValue,
+        value: TaggedValue,
         _tags: &mut dyn Iterator<Item = &(Cow<'static, str>, Cow<'static, str>)>,
         _children: &mut [Rope],
-    ) -> Rope {
-        panic!("Model is not composable");
+    ) -> Result<Rope, TaggedValue> {
+        Err(value)
     }
 }
 
@@ -63,6 +63,13 @@ impl Rope {
         false
     }
 
+    pub fn ends_line(&self) -> bool {
+        match *self {
+            Rope::Many(ref nodes) => nodes.len() > 1 && nodes[nodes.len() - 1].is_newline(),
+            _ => false,
+        }
+    }
+
     pub fn is_flow_opening(&self) -> bool {
         match *self {
             Rope::Empty => false,
@@ -191,6 +198,27 @@ impl Rope {
         vec
     }
 
+    /// Renders the rope onto the end of the vector, keeping the rope intact
+    pub fn render_into_vec(&self, renderer: &Renderer, vec: &mut Vec<u8>) {
+        let nodes: &[Node] = match *self {
+            Rope::Empty => return,
+            Rope::Node(ref node) => node,
+            Rope::Many(ref nodes) => nodes,
+        };
+
+        let vec_len = vec.len();
+        let rope_len = self.bytes_len(renderer);
+        vec.reserve(rope_len);
+
+        unsafe {
+            let mut ptr = vec.as_mut_ptr().add(vec_len);
+            for node in nodes {
+                ptr = renderer.render_onto_ptr(ptr, node);
+            }
+            vec.set_len(vec_len + rope_len);
+        }
+    }
+
     pub fn push(&mut self, node: Node) {
         let is_many = match *self {
             Rope::Many(_) => true,
@@ -0,0 +1,233 @@
+use crate::model::schema::core::Core;
+use crate::model::schema::Schema;
+
+use crate::model::style::CommonStyles;
+use crate::model::{Model, TaggedValue};
+
+use crate::model::yaml::null::Null;
+use crate::model::yamlette::literal::Literal;
+
+use std::borrow::Cow;
+use std::sync::Arc;
+
+type TagHandle = (Cow<'static, str>, Cow<'static, str>);
+type Entry = (Cow<'static, str>, Arc<dyn Model>);
+
+/// Builds a schema out of another one (`Core` by default, `Failsafe` or `Json`) and the models of the
+/// user-defined tags, so that adding a tag doesn't take a `Schema` implementation
+pub struct SchemaBuilder<S> {
+    base: S,
+    models: Vec<Entry>,
+    implicit: Vec<Arc<dyn Model>>,
+    resolution: Option<Vec<Cow<'static, str>>>,
+    tag_handles: Vec<TagHandle>,
+}
+
+impl SchemaBuilder<Core> {
+    pub fn new() -> SchemaBuilder<Core> {
+        SchemaBuilder::on(Core::new())
+    }
+}
+
+impl Default for SchemaBuilder<Core> {
+    fn default() -> SchemaBuilder<Core> {
+        SchemaBuilder::new()
+    }
+}
+
+impl<S> SchemaBuilder<S>
+where
+    S: Schema + Clone + 'static,
+{
+    /// Starts from the base schema, which keeps the models of all the tags not registered in the builder
+    pub fn on(base: S) -> SchemaBuilder<S> {
+        SchemaBuilder {
+            base,
+            models: Vec::new(),
+            implicit: Vec::new(),
+            resolution: None,
+            tag_handles: Vec::new(),
+        }
+    }
+
+    /// Registers the model by its tag, overriding the model of the base schema with the same tag.
+    /// The model is looked up for the explicitly tagged nodes and the written values only.
+    pub fn model<M>(mut self, model: M) -> SchemaBuilder<S>
+    where
+        M: Model + 'static,
+    {
+        self.register(Arc::new(model));
+        self
+    }
+
+    /// Registers the model (as `model` does) and tries it for the plain scalars
+    /// with no tag before the implicit models of the base schema
+    pub fn implicit<M>(mut self, model: M) -> SchemaBuilder<S>
+    where
+        M: Model + 'static,
+    {
+        let model: Arc<dyn Model> = Arc::new(model);
+
+        self.register(model.clone());
+        self.implicit.push(model);
+        self
+    }
+
+    /// Overrides the order the models are tried in for the plain scalars with no tag.
+    /// Only the models of the listed tags are tried (either registered or of the base schema),
+    /// and the scalars none of them can decode are left to the metamodel.
+    pub fn resolution<I, T>(mut self, tags: I) -> SchemaBuilder<S>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Cow<'static, str>>,
+    {
+        self.resolution = Some(tags.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Adds the tag handle (e.g. `!e!` for `tag:example.com,2000:`) known without the `%TAG` directive
+    pub fn tag_handle<H, P>(mut self, handle: H, prefix: P) -> SchemaBuilder<S>
+    where
+        H: Into<Cow<'static, str>>,
+        P: Into<Cow<'static, str>>,
+    {
+        self.tag_handles.push((handle.into(), prefix.into()));
+        self
+    }
+
+    pub fn build(self) -> Custom<S> {
+        let mut tag_handles = self.tag_handles;
+        tag_handles.extend(self.base.get_tag_handles().iter().cloned());
+
+        Custom {
+            base: self.base,
+            models: Arc::new(self.models),
+            implicit: Arc::new(self.implicit),
+            resolution: self.resolution.map(Arc::new),
+            tag_handles: Arc::new(tag_handles),
+        }
+    }
+
+    fn register(&mut self, model: Arc<dyn Model>) {
+        let tag = model.get_tag();
+
+        match self.models.iter_mut().find(|entry| entry.0 == tag) {
+            Some(entry) => entry.1 = model,
+            None => self.models.push((tag, model)),
+        }
+    }
+}
+
+/// The schema made by `SchemaBuilder`
+#[derive(Clone)]
+pub struct Custom<S> {
+    base: S,
+    models: Arc<Vec<Entry>>,
+    implicit: Arc<Vec<Arc<dyn Model>>>,
+    resolution: Option<Arc<Vec<Cow<'static, str>>>>,
+    tag_handles: Arc<Vec<TagHandle>>,
+}
+
+impl<S> Custom<S>
+where
+    S: Schema,
+{
+    fn try_models<D>(&self, decode: D) -> Option<TaggedValue>
+    where
+        D: Fn(&dyn Model) -> Option<TaggedValue>,
+    {
+        match self.resolution {
+            Some(ref tags) => tags
+                .iter()
+                .filter_map(|tag| self.look_up_model(tag))
+                .filter(|model| model.is_decodable())
+                .find_map(&decode),
+            None => self
+                .implicit
+                .iter()
+                .filter(|model| model.is_decodable())
+                .find_map(|model| decode(model.as_ref())),
+        }
+    }
+}
+
+impl<S> Schema for Custom<S>
+where
+    S: Schema,
+{
+    fn get_common_styles(&self) -> CommonStyles {
+        self.base.get_common_styles()
+    }
+
+    fn get_yaml_version(&self) -> (u8, u8) {
+        self.base.get_yaml_version()
+    }
+
+    fn get_tag_handles(&self) -> &[(Cow<'static, str>, Cow<'static, str>)] {
+        &self.tag_handles
+    }
+
+    fn look_up_model<'a>(&'a self, tag: &str) -> Option<&'a dyn Model> {
+        match self.models.iter().find(|entry| entry.0 == tag) {
+            Some(entry) => Some(entry.1.as_ref()),
+            None => self.base.look_up_model(tag),
+        }
+    }
+
+    fn try_decodable_models(&self, value: &[u8]) -> Option<TaggedValue> {
+        let decoded = self.try_models(|model| model.decode(false, value).ok());
+
+        if decoded.is_none() && self.resolution.is_none() {
+            self.base.try_decodable_models(value)
+        } else {
+            decoded
+        }
+    }
+
+    fn try_decodable_models_11(&self, value: &[u8]) -> Option<TaggedValue> {
+        let decoded = self.try_models(|model| model.decode11(false, value).ok());
+
+        if decoded.is_none() && self.resolution.is_none() {
+            self.base.try_decodable_models_11(value)
+        } else {
+            decoded
+        }
+    }
+
+    fn look_up_model_callback(
+        &self,
+        predicate: &mut dyn FnMut(&dyn Model) -> bool,
+    ) -> Option<&dyn Model> {
+        for entry in self.models.iter() {
+            if predicate(entry.1.as_ref()) {
+                return Some(entry.1.as_ref());
+            }
+        }
+
+        self.base.look_up_model_callback(predicate)
+    }
+
+    fn get_metamodel(&self) -> Option<&dyn Model> {
+        self.base.get_metamodel()
+    }
+
+    fn get_model_literal(&self) -> Literal {
+        self.base.get_model_literal()
+    }
+
+    fn get_model_null(&self) -> Null {
+        self.base.get_model_null()
+    }
+
+    fn get_tag_model_map(&self) -> Cow<'static, str> {
+        self.base.get_tag_model_map()
+    }
+
+    fn get_tag_model_seq(&self) -> Cow<'static, str> {
+        self.base.get_tag_model_seq()
+    }
+
+    fn get_unresolved_reason(&self) -> Cow<'static, str> {
+        self.base.get_unresolved_reason()
+    }
+}
@@ -133,6 +133,14 @@ impl Schema for Core {
             Some(value)
         } else if let Ok(value) = self.mod_float.decode11(false, value) {
             Some(value)
+        } else if let Ok(value) = self.mod_timestamp.decode11(false, value) {
+            Some(value)
+        } else if let Ok(value) = self.mod_merge.decode11(false, value) {
+            Some(value)
+        } else if let Ok(value) = self.mod_value.decode11(false, value) {
+            Some(value)
+        } else if let Ok(value) = self.mod_yaml.decode11(false, value) {
+            Some(value)
         } else if let Ok(value) = self.mod_str.decode11(false, value) {
             Some(value)
         } else if let Ok(value) = self.mod_incognitum.decode11(false, value) {
@@ -0,0 +1,156 @@
+use crate::model::schema::Schema;
+
+use crate::model::style::CommonStyles;
+use crate::model::{Model, TaggedValue};
+
+use crate::model::yaml::map::Map;
+use crate::model::yaml::null::Null;
+use crate::model::yaml::seq::Seq;
+use crate::model::yaml::str::Str;
+
+use crate::model::yamlette::incognitum::Incognitum;
+use crate::model::yamlette::literal::Literal;
+
+use std::borrow::Cow;
+use std::clone::Clone;
+
+/// The failsafe schema of YAML 1.2 (10.1), where every scalar is a string
+/// and every collection is either a mapping or a sequence, so that nothing
+/// is coerced (`no` stays "no"). The scalars of other tags are kept as they are
+/// by the metamodel.
+pub struct Failsafe {
+    styles: CommonStyles,
+    tag_handles: [(Cow<'static, str>, Cow<'static, str>); 3],
+
+    mod_map: Map,
+    mod_seq: Seq,
+    mod_str: Str,
+    mod_null: Null,
+    mod_literal: Literal,
+    mod_incognitum: Incognitum,
+}
+
+impl Schema for Failsafe {
+    #[inline(always)]
+    fn get_common_styles(&self) -> CommonStyles {
+        self.styles
+    }
+
+    #[inline(always)]
+    fn get_yaml_version(&self) -> (u8, u8) {
+        (1, 2)
+    }
+
+    fn get_tag_handles(&self) -> &[(Cow<'static, str>, Cow<'static, str>)] {
+        &self.tag_handles
+    }
+
+    fn look_up_model<'a>(&'a self, tag: &str) -> Option<&'a dyn Model> {
+        if tag == Map::get_tag() {
+            Some(&self.mod_map)
+        } else if tag == Seq::get_tag() {
+            Some(&self.mod_seq)
+        } else if tag == Str::get_tag() {
+            Some(&self.mod_str)
+        } else if tag == Literal::get_tag() {
+            Some(&self.mod_literal)
+        } else if tag == Incognitum::get_tag() {
+            Some(&self.mod_incognitum)
+        } else {
+            None
+        }
+    }
+
+    fn try_decodable_models(&self, value: &[u8]) -> Option<TaggedValue> {
+        self.mod_str
+            .decode(false, value)
+            .or_else(|_| self.mod_incognitum.decode(false, value))
+            .ok()
+    }
+
+    fn try_decodable_models_11(&self, value: &[u8]) -> Option<TaggedValue> {
+        self.mod_str
+            .decode11(false, value)
+            .or_else(|_| self.mod_incognitum.decode11(false, value))
+            .ok()
+    }
+
+    fn look_up_model_callback(
+        &self,
+        predicate: &mut dyn FnMut(&dyn Model) -> bool,
+    ) -> Option<&dyn Model> {
+        if predicate(&self.mod_map) {
+            Some(&self.mod_map)
+        } else if predicate(&self.mod_seq) {
+            Some(&self.mod_seq)
+        } else if predicate(&self.mod_str) {
+            Some(&self.mod_str)
+        } else if predicate(&self.mod_literal) {
+            Some(&self.mod_literal)
+        } else if predicate(&self.mod_incognitum) {
+            Some(&self.mod_incognitum)
+        } else {
+            None
+        }
+    }
+
+    fn get_metamodel(&self) -> Option<&dyn Model> {
+        Some(&self.mod_incognitum)
+    }
+
+    #[inline(always)]
+    fn get_model_literal(&self) -> Literal {
+        self.mod_literal
+    }
+
+    #[inline(always)]
+    fn get_model_null(&self) -> Null {
+        self.mod_null
+    }
+
+    #[inline(always)]
+    fn get_tag_model_map(&self) -> Cow<'static, str> {
+        Map::get_tag()
+    }
+
+    #[inline(always)]
+    fn get_tag_model_seq(&self) -> Cow<'static, str> {
+        Seq::get_tag()
+    }
+}
+
+impl Failsafe {
+    pub fn new() -> Failsafe {
+        Failsafe {
+            styles: CommonStyles::default(),
+
+            tag_handles: [
+                (Cow::from("!!"), Cow::from("tag:yaml.org,2002:")),
+                (
+                    Cow::from("!"),
+                    Cow::from("tag:yaml.org,2002:str tag:yaml.org,2002:seq tag:yaml.org,2002:map"),
+                ),
+                (Cow::from(""), Cow::from("")),
+            ],
+
+            mod_map: Map,
+            mod_seq: Seq,
+            mod_str: Str,
+            mod_null: Null,
+            mod_literal: Literal,
+            mod_incognitum: Incognitum,
+        }
+    }
+}
+
+impl Default for Failsafe {
+    fn default() -> Failsafe {
+        Failsafe::new()
+    }
+}
+
+impl Clone for Failsafe {
+    fn clone(&self) -> Failsafe {
+        Failsafe::new()
+    }
+}
@@ -0,0 +1,254 @@
+use crate::model::schema::Schema;
+
+use crate::model::style::CommonStyles;
+use crate::model::{Model, TaggedValue};
+
+use crate::model::yaml::bool::Bool;
+use crate::model::yaml::float::Float;
+use crate::model::yaml::int::Int;
+use crate::model::yaml::map::Map;
+use crate::model::yaml::null::Null;
+use crate::model::yaml::seq::Seq;
+use crate::model::yaml::str::Str;
+
+use crate::model::yamlette::literal::Literal;
+
+use std::borrow::Cow;
+use std::clone::Clone;
+
+/// The JSON schema of YAML 1.2 (10.2). Only the plain scalars of JSON resolve
+/// (`null`, `true`, `false`, the JSON numbers) and the strings must be quoted,
+/// so any other plain scalar is an error, as well as the tags besides null, bool,
+/// int, float, str, seq and map.
+pub struct Json {
+    styles: CommonStyles,
+    tag_handles: [(Cow<'static, str>, Cow<'static, str>); 3],
+
+    mod_map: Map,
+    mod_seq: Seq,
+    mod_null: Null,
+    mod_bool: Bool,
+    mod_int: Int,
+    mod_float: Float,
+    mod_str: Str,
+    mod_literal: Literal,
+}
+
+impl Schema for Json {
+    #[inline(always)]
+    fn get_common_styles(&self) -> CommonStyles {
+        self.styles
+    }
+
+    #[inline(always)]
+    fn get_yaml_version(&self) -> (u8, u8) {
+        (1, 2)
+    }
+
+    fn get_tag_handles(&self) -> &[(Cow<'static, str>, Cow<'static, str>)] {
+        &self.tag_handles
+    }
+
+    fn look_up_model<'a>(&'a self, tag: &str) -> Option<&'a dyn Model> {
+        if tag == Map::get_tag() {
+            Some(&self.mod_map)
+        } else if tag == Seq::get_tag() {
+            Some(&self.mod_seq)
+        } else if tag == Null::get_tag() {
+            Some(&self.mod_null)
+        } else if tag == Bool::get_tag() {
+            Some(&self.mod_bool)
+        } else if tag == Int::get_tag() {
+            Some(&self.mod_int)
+        } else if tag == Float::get_tag() {
+            Some(&self.mod_float)
+        } else if tag == Str::get_tag() {
+            Some(&self.mod_str)
+        } else if tag == Literal::get_tag() {
+            Some(&self.mod_literal)
+        } else {
+            None
+        }
+    }
+
+    fn try_decodable_models(&self, value: &[u8]) -> Option<TaggedValue> {
+        let model: &dyn Model = match value {
+            b"null" => &self.mod_null,
+            b"true" | b"false" => &self.mod_bool,
+            _ if is_int(value) => &self.mod_int,
+            _ if is_float(value) => &self.mod_float,
+            [b'"', ..] | [b'\'', ..] => &self.mod_str,
+            _ => return None,
+        };
+
+        model.decode(false, value).ok()
+    }
+
+    fn try_decodable_models_11(&self, value: &[u8]) -> Option<TaggedValue> {
+        self.try_decodable_models(value)
+    }
+
+    fn look_up_model_callback(
+        &self,
+        predicate: &mut dyn FnMut(&dyn Model) -> bool,
+    ) -> Option<&dyn Model> {
+        if predicate(&self.mod_map) {
+            Some(&self.mod_map)
+        } else if predicate(&self.mod_seq) {
+            Some(&self.mod_seq)
+        } else if predicate(&self.mod_null) {
+            Some(&self.mod_null)
+        } else if predicate(&self.mod_bool) {
+            Some(&self.mod_bool)
+        } else if predicate(&self.mod_int) {
+            Some(&self.mod_int)
+        } else if predicate(&self.mod_float) {
+            Some(&self.mod_float)
+        } else if predicate(&self.mod_str) {
+            Some(&self.mod_str)
+        } else if predicate(&self.mod_literal) {
+            Some(&self.mod_literal)
+        } else {
+            None
+        }
+    }
+
+    fn get_metamodel(&self) -> Option<&dyn Model> {
+        None
+    }
+
+    #[inline(always)]
+    fn get_model_literal(&self) -> Literal {
+        self.mod_literal
+    }
+
+    #[inline(always)]
+    fn get_model_null(&self) -> Null {
+        self.mod_null
+    }
+
+    #[inline(always)]
+    fn get_tag_model_map(&self) -> Cow<'static, str> {
+        Map::get_tag()
+    }
+
+    #[inline(always)]
+    fn get_tag_model_seq(&self) -> Cow<'static, str> {
+        Seq::get_tag()
+    }
+
+    fn get_unresolved_reason(&self) -> Cow<'static, str> {
+        Cow::from("not a JSON-compatible plain scalar")
+    }
+}
+
+impl Json {
+    pub fn new() -> Json {
+        Json {
+            styles: CommonStyles::default(),
+
+            tag_handles: [
+                (Cow::from("!!"), Cow::from("tag:yaml.org,2002:")),
+                (
+                    Cow::from("!"),
+                    Cow::from("tag:yaml.org,2002:str tag:yaml.org,2002:seq tag:yaml.org,2002:map"),
+                ),
+                (Cow::from(""), Cow::from("")),
+            ],
+
+            mod_map: Map,
+            mod_seq: Seq,
+            mod_null: Null,
+            mod_bool: Bool,
+            mod_int: Int,
+            mod_float: Float,
+            mod_str: Str,
+            mod_literal: Literal,
+        }
+    }
+}
+
+impl Default for Json {
+    fn default() -> Json {
+        Json::new()
+    }
+}
+
+impl Clone for Json {
+    fn clone(&self) -> Json {
+        Json::new()
+    }
+}
+
+/// Skips `-?(0|[1-9][0-9]*)`, giving the rest
+fn skip_int(value: &[u8]) -> Option<&[u8]> {
+    let value = match value {
+        [b'-', rest @ ..] => rest,
+        _ => value,
+    };
+
+    match value {
+        [b'0', rest @ ..] => Some(rest),
+        [b'1'..=b'9', ..] => Some(skip_digits(value)),
+        _ => None,
+    }
+}
+
+fn skip_digits(value: &[u8]) -> &[u8] {
+    let digits = value.iter().take_while(|b| b.is_ascii_digit()).count();
+    &value[digits..]
+}
+
+fn is_int(value: &[u8]) -> bool {
+    skip_int(value).is_some_and(<[u8]>::is_empty)
+}
+
+/// `-?(0|[1-9][0-9]*)(\.[0-9]*)?([eE][-+]?[0-9]+)?`
+fn is_float(value: &[u8]) -> bool {
+    let rest = match skip_int(value) {
+        Some(rest) => rest,
+        None => return false,
+    };
+
+    let rest = match rest {
+        [b'.', fraction @ ..] => skip_digits(fraction),
+        _ => rest,
+    };
+
+    match rest {
+        [] => true,
+        [b'e', exponent @ ..] | [b'E', exponent @ ..] => {
+            let exponent = match exponent {
+                [b'-', digits @ ..] | [b'+', digits @ ..] => digits,
+                _ => exponent,
+            };
+
+            !exponent.is_empty() && skip_digits(exponent).is_empty()
+        }
+        _ => false,
+    }
+}
+
+#[cfg(all(test, not(feature = "dev")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_numbers() {
+        for value in ["0", "-0", "12", "-345"].iter() {
+            assert!(is_int(value.as_bytes()), "{}", value);
+        }
+
+        for value in ["012", "+1", "1_000", "0x1F", "", "-"].iter() {
+            assert!(!is_int(value.as_bytes()), "{}", value);
+        }
+
+        for value in ["1.", "-0.5", "2.5e10", "1E-3", "3e+2", "10"].iter() {
+            assert!(is_float(value.as_bytes()), "{}", value);
+        }
+
+        for value in [".5", "1e", "1.5.2", ".inf", "-.nan", "1_0.0"].iter() {
+            assert!(!is_float(value.as_bytes()), "{}", value);
+        }
+    }
+}
@@ -1,6 +1,10 @@
 extern crate skimmer;
 
+pub mod builder;
 pub mod core;
+pub mod failsafe;
+pub mod json;
+pub mod yaml11;
 
 use crate::model::style::CommonStyles;
 use crate::model::{Model, TaggedValue};
@@ -37,4 +41,9 @@ pub trait Schema: Send + Sync {
     fn get_tag_model_map(&self) -> Cow<'static, str>;
 
     fn get_tag_model_seq(&self) -> Cow<'static, str>;
+
+    /// Why a plain scalar is an error when none of the models decodes it and there is no metamodel
+    fn get_unresolved_reason(&self) -> Cow<'static, str> {
+        Cow::from("no model of the schema can decode it")
+    }
 }
@@ -0,0 +1,96 @@
+use crate::model::schema::core::Core;
+use crate::model::schema::Schema;
+
+use crate::model::style::CommonStyles;
+use crate::model::{Model, TaggedValue};
+
+use crate::model::yaml::null::Null;
+use crate::model::yamlette::literal::Literal;
+
+use std::borrow::Cow;
+use std::clone::Clone;
+
+/// The models of `Core` with the type resolution of YAML 1.1 for the documents with no `%YAML` directive,
+/// so that `yes`, `off`, `017` (octal), `1_000`, `1:30` (sexagesimal), `2001-12-14` (timestamp), `<<` (merge),
+/// `=` (value) are resolved as they are by the 1.1 processors. The documents with `%YAML 1.2` are
+/// still read by the rules of 1.2, and the written volumes have the `%YAML 1.1` directive.
+pub struct Yaml11 {
+    core: Core,
+}
+
+impl Schema for Yaml11 {
+    #[inline(always)]
+    fn get_common_styles(&self) -> CommonStyles {
+        self.core.get_common_styles()
+    }
+
+    #[inline(always)]
+    fn get_yaml_version(&self) -> (u8, u8) {
+        (1, 1)
+    }
+
+    fn get_tag_handles(&self) -> &[(Cow<'static, str>, Cow<'static, str>)] {
+        self.core.get_tag_handles()
+    }
+
+    fn look_up_model(&self, tag: &str) -> Option<&dyn Model> {
+        self.core.look_up_model(tag)
+    }
+
+    fn try_decodable_models(&self, value: &[u8]) -> Option<TaggedValue> {
+        self.core.try_decodable_models(value)
+    }
+
+    fn try_decodable_models_11(&self, value: &[u8]) -> Option<TaggedValue> {
+        self.core.try_decodable_models_11(value)
+    }
+
+    fn look_up_model_callback(
+        &self,
+        predicate: &mut dyn FnMut(&dyn Model) -> bool,
+    ) -> Option<&dyn Model> {
+        self.core.look_up_model_callback(predicate)
+    }
+
+    fn get_metamodel(&self) -> Option<&dyn Model> {
+        self.core.get_metamodel()
+    }
+
+    #[inline(always)]
+    fn get_model_literal(&self) -> Literal {
+        self.core.get_model_literal()
+    }
+
+    #[inline(always)]
+    fn get_model_null(&self) -> Null {
+        self.core.get_model_null()
+    }
+
+    #[inline(always)]
+    fn get_tag_model_map(&self) -> Cow<'static, str> {
+        self.core.get_tag_model_map()
+    }
+
+    #[inline(always)]
+    fn get_tag_model_seq(&self) -> Cow<'static, str> {
+        self.core.get_tag_model_seq()
+    }
+}
+
+impl Yaml11 {
+    pub fn new() -> Yaml11 {
+        Yaml11 { core: Core::new() }
+    }
+}
+
+impl Default for Yaml11 {
+    fn default() -> Yaml11 {
+        Yaml11::new()
+    }
+}
+
+impl Clone for Yaml11 {
+    fn clone(&self) -> Yaml11 {
+        Yaml11::new()
+    }
+}
@@ -124,7 +124,7 @@ impl Tagged for TaggedValue {
             TaggedValue::Literal(ref v) => v.as_any(),
             TaggedValue::Incognitum(ref v) => v.as_any(),
 
-            TaggedValue::Other(_, ref value) => value,
+            TaggedValue::Other(_, ref value) => value.as_ref(),
         }
     }
 
@@ -149,7 +149,7 @@ impl Tagged for TaggedValue {
             TaggedValue::Literal(ref mut v) => v.as_mut_any(),
             TaggedValue::Incognitum(ref mut v) => v.as_mut_any(),
 
-            TaggedValue::Other(_, ref mut value) => value,
+            TaggedValue::Other(_, ref mut value) => value.as_mut(),
         }
     }
 }
@@ -2,7 +2,7 @@ extern crate skimmer;
 
 use crate::model::style::CommonStyles;
 use crate::model::{
-    model_issue_rope, EncodedString, Model, Node, Renderer, Rope, Tagged, TaggedValue,
+    model_issue_rope, DecodeError, EncodedString, Model, Node, Renderer, Rope, Tagged, TaggedValue,
 };
 
 use std::any::Any;
@@ -136,7 +136,7 @@ impl Model for Binary {
         Ok(model_issue_rope(self, node, issue_tag, alias, tags))
     }
 
-    fn decode(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, ()> {
+    fn decode(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, DecodeError> {
         let mut production: Vec<u8> = Vec::with_capacity(value.len() / 4 * 3);
 
         let mut rem: u8 = 0;
@@ -168,7 +168,7 @@ impl Model for Binary {
                         quote_state = 3;
                         break;
                     } else {
-                        return Err(());
+                        return Err(DecodeError::new(self, value, "unexpected quote"));
                     }
                 }
 
@@ -178,7 +178,7 @@ impl Model for Binary {
                         quote_state = 3;
                         break;
                     } else {
-                        return Err(());
+                        return Err(DecodeError::new(self, value, "unexpected quote"));
                     }
                 }
 
@@ -243,7 +243,7 @@ impl Model for Binary {
                     ptr += 1;
                 }
 
-                _ => return Err(()),
+                _ => return Err(DecodeError::new(self, value, "not a base64 character")),
             }
         }
 
@@ -256,7 +256,13 @@ impl Model for Binary {
                         ptr += 1;
                     }
 
-                    _ => return Err(()),
+                    _ => {
+                        return Err(DecodeError::new(
+                            self,
+                            value,
+                            "unexpected characters after the quotes",
+                        ))
+                    }
                 }
             }
         }
@@ -2,7 +2,7 @@ extern crate skimmer;
 
 use crate::model::style::CommonStyles;
 use crate::model::{
-    model_issue_rope, EncodedString, Model, Node, Renderer, Rope, Tagged, TaggedValue,
+    model_issue_rope, DecodeError, EncodedString, Model, Node, Renderer, Rope, Tagged, TaggedValue,
 };
 
 use std::any::Any;
@@ -19,7 +19,12 @@ impl Bool {
         Cow::from(TAG)
     }
 
-    fn base_decode(&self, explicit: bool, value: &[u8], yaml_11: bool) -> Result<bool, ()> {
+    fn base_decode(
+        &self,
+        explicit: bool,
+        value: &[u8],
+        yaml_11: bool,
+    ) -> Result<bool, &'static str> {
         let mut found_val: u8 = 0;
         let mut quote_state: u8 = 0; // 1 - single, 2 - double
 
@@ -196,7 +201,11 @@ impl Bool {
         };
 
         if found_val == 0 {
-            return Err(());
+            return Err(if yaml_11 {
+                "a boolean is one of y, yes, true, on, n, no, false or off"
+            } else {
+                "a boolean is either true or false"
+            });
         }
 
         if quote_state > 0 {
@@ -207,7 +216,7 @@ impl Bool {
                 Some(b'"') if quote_state == 2 => {
                     ptr += 1;
                 }
-                _ => return Err(()),
+                _ => return Err("the quotes are not closed"),
             }
         }
 
@@ -219,7 +228,7 @@ impl Bool {
                     ptr += 1;
                 }
 
-                _ => return Err(()),
+                _ => return Err("unexpected characters after the boolean"),
             }
         }
 
@@ -270,16 +279,18 @@ impl Model for Bool {
         Ok(model_issue_rope(self, node, issue_tag, alias, tags))
     }
 
-    fn decode(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, ()> {
-        Ok(TaggedValue::from(BoolValue::from(
-            self.base_decode(explicit, value, false)?,
-        )))
+    fn decode(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, DecodeError> {
+        match self.base_decode(explicit, value, false) {
+            Ok(val) => Ok(TaggedValue::from(BoolValue::from(val))),
+            Err(reason) => Err(DecodeError::new(self, value, reason)),
+        }
     }
 
-    fn decode11(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, ()> {
-        Ok(TaggedValue::from(BoolValue::from(
-            self.base_decode(explicit, value, true)?,
-        )))
+    fn decode11(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, DecodeError> {
+        match self.base_decode(explicit, value, true) {
+            Ok(val) => Ok(TaggedValue::from(BoolValue::from(val))),
+            Err(reason) => Err(DecodeError::new(self, value, reason)),
+        }
     }
 }
 
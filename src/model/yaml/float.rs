@@ -9,7 +9,7 @@ use self::num::{BigUint, ToPrimitive};
 
 use crate::model::style::CommonStyles;
 use crate::model::{
-    model_issue_rope, EncodedString, Model, Node, Renderer, Rope, Tagged, TaggedValue,
+    model_issue_rope, DecodeError, EncodedString, Model, Node, Renderer, Rope, Tagged, TaggedValue,
 };
 
 use std::any::Any;
@@ -175,14 +175,14 @@ impl Float {
         value: &[u8],
         base60: bool,
         optional_dot: bool,
-    ) -> Result<MaybeBigFraction, ()> {
+    ) -> Result<MaybeBigFraction, &'static str> {
         if !explicit
             && !match value.get(0).map(|b| *b) {
                 Some(b'+') | Some(b'-') | Some(b'.') | Some(b'0'..=b'9') => true,
                 _ => false,
             }
         {
-            return Err(());
+            return Err("a float starts with a sign, a dot or a digit");
         }
 
         const STATE_SIGN: u8 = 1;
@@ -234,7 +234,7 @@ impl Float {
                         quote_state = 1;
                     } else if quote_state == 1 {
                         if ptr == 2 {
-                            return Err(());
+                            return Err("the quotes are empty");
                         }
                         quote_state = 0;
                         state = state | STATE_END;
@@ -251,7 +251,7 @@ impl Float {
                         quote_state = 2;
                     } else if quote_state == 2 {
                         if ptr == 2 {
-                            return Err(());
+                            return Err("the quotes are empty");
                         }
                         quote_state = 0;
                         state = state | STATE_END;
@@ -413,7 +413,7 @@ impl Float {
 
         if state & STATE_END == STATE_END {
             if ptr == 0 {
-                return Err(());
+                return Err("there are no digits");
             }
             if quote_state > 0 {
                 match value.get(ptr).map(|b| *b) {
@@ -423,7 +423,7 @@ impl Float {
                     Some(b'\'') if quote_state == 1 => {
                         ptr += 1;
                     }
-                    _ => return Err(()),
+                    _ => return Err("the quotes are not closed"),
                 };
             }
 
@@ -435,7 +435,7 @@ impl Float {
                         ptr += 1;
                     }
 
-                    _ => return Err(()),
+                    _ => return Err("unexpected characters after the number"),
                 };
             }
         }
@@ -451,7 +451,7 @@ impl Float {
         }
 
         if state & STATE_NUM != STATE_NUM {
-            return Err(());
+            return Err("there are no digits");
         }
 
         if state & STATE_E == STATE_E && exp > Mint::new() {
@@ -550,19 +550,17 @@ impl Model for Float {
         Ok(model_issue_rope(self, node, issue_tag, alias, tags))
     }
 
-    fn decode(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, ()> {
-        if let Ok(frac) = self.base_decode(explicit, value, false, true) {
-            Ok(TaggedValue::from(FloatValue::new(frac)))
-        } else {
-            Err(())
+    fn decode(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, DecodeError> {
+        match self.base_decode(explicit, value, false, true) {
+            Ok(frac) => Ok(TaggedValue::from(FloatValue::new(frac))),
+            Err(reason) => Err(DecodeError::new(self, value, reason)),
         }
     }
 
-    fn decode11(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, ()> {
-        if let Ok(frac) = self.base_decode(explicit, value, true, false) {
-            Ok(TaggedValue::from(FloatValue::new(frac)))
-        } else {
-            Err(())
+    fn decode11(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, DecodeError> {
+        match self.base_decode(explicit, value, true, false) {
+            Ok(frac) => Ok(TaggedValue::from(FloatValue::new(frac))),
+            Err(reason) => Err(DecodeError::new(self, value, reason)),
         }
     }
 }
@@ -835,6 +833,13 @@ impl ToPrimitive for FloatValue {
             MaybeBigFraction::Big(ref f) => f.to_u64(),
         }
     }
+
+    fn to_f64(&self) -> Option<f64> {
+        match self.value {
+            MaybeBigFraction::Fra(ref f) => f.to_f64(),
+            MaybeBigFraction::Big(ref f) => f.to_f64(),
+        }
+    }
 }
 
 #[cfg(all(test, not(feature = "dev")))]
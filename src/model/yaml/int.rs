@@ -5,7 +5,7 @@ use self::num::{BigInt, BigUint, ToPrimitive};
 
 use crate::model::style::CommonStyles;
 use crate::model::{
-    model_issue_rope, EncodedString, Model, Node, Renderer, Rope, Tagged, TaggedValue,
+    model_issue_rope, DecodeError, EncodedString, Model, Node, Renderer, Rope, Tagged, TaggedValue,
 };
 
 use std::any::Any;
@@ -133,14 +133,14 @@ impl Int {
         value: &[u8],
         sexagesimals: bool,
         shortocts: bool,
-    ) -> Result<Mint, ()> {
+    ) -> Result<Mint, &'static str> {
         if !explicit
             && !match value.get(0).map(|b| *b) {
                 Some(b'+') | Some(b'-') | Some(b'0'..=b'9') => true,
                 _ => false,
             }
         {
-            return Err(());
+            return Err("an integer starts with a sign or a digit");
         }
 
         const STATE_SIGN: u8 = 1;
@@ -169,7 +169,7 @@ impl Int {
                         quote_state = 1;
                     } else if quote_state == 1 {
                         if ptr == 2 {
-                            return Err(());
+                            return Err("the quotes are empty");
                         }
                         quote_state = 0;
                         state = state | STATE_END;
@@ -186,7 +186,7 @@ impl Int {
                         quote_state = 2;
                     } else if quote_state == 2 {
                         if ptr == 2 {
-                            return Err(());
+                            return Err("the quotes are empty");
                         }
                         quote_state = 0;
                         state = state | STATE_END;
@@ -364,7 +364,7 @@ impl Int {
                                 ptr += 1;
                                 (val - b'0') as i64
                             }
-                            _ => return Err(()),
+                            _ => return Err("a digit is expected after the colon"),
                         };
 
                         let mut digit2: Option<i64> = None;
@@ -408,7 +408,7 @@ impl Int {
 
         if state & STATE_END == STATE_END {
             if ptr == 0 {
-                return Err(());
+                return Err("there are no digits");
             }
             if quote_state > 0 {
                 match value.get(ptr).map(|b| *b) {
@@ -418,7 +418,7 @@ impl Int {
                     Some(b'\'') if quote_state == 1 => {
                         ptr += 1;
                     }
-                    _ => return Err(()),
+                    _ => return Err("the quotes are not closed"),
                 }
             }
 
@@ -430,13 +430,13 @@ impl Int {
                         ptr += 1;
                     }
 
-                    _ => return Err(()),
+                    _ => return Err("unexpected characters after the number"),
                 };
             }
         }
 
         if state & STATE_NUM != STATE_NUM {
-            return Err(());
+            return Err("there are no digits");
         }
         if state & STATE_SIGN_N == STATE_SIGN_N {
             val = -val;
@@ -487,16 +487,18 @@ impl Model for Int {
         Ok(model_issue_rope(self, node, issue_tag, alias, tags))
     }
 
-    fn decode(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, ()> {
-        Ok(TaggedValue::from(IntValue::new(
-            self.base_decode(explicit, value, false, false)?,
-        )))
+    fn decode(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, DecodeError> {
+        match self.base_decode(explicit, value, false, false) {
+            Ok(val) => Ok(TaggedValue::from(IntValue::new(val))),
+            Err(reason) => Err(DecodeError::new(self, value, reason)),
+        }
     }
 
-    fn decode11(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, ()> {
-        Ok(TaggedValue::from(IntValue::new(
-            self.base_decode(explicit, value, true, true)?,
-        )))
+    fn decode11(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, DecodeError> {
+        match self.base_decode(explicit, value, true, true) {
+            Ok(val) => Ok(TaggedValue::from(IntValue::new(val))),
+            Err(reason) => Err(DecodeError::new(self, value, reason)),
+        }
     }
 }
 
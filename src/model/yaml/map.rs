@@ -42,7 +42,7 @@ impl Model for Map {
         value: TaggedValue,
         tags: &mut dyn Iterator<Item = &(Cow<'static, str>, Cow<'static, str>)>,
         children: &mut [Rope],
-    ) -> Rope {
+    ) -> Result<Rope, TaggedValue> {
         compose(self, renderer, value, tags, children)
     }
 }
@@ -53,13 +53,10 @@ pub fn compose(
     value: TaggedValue,
     tags: &mut dyn Iterator<Item = &(Cow<'static, str>, Cow<'static, str>)>,
     children: &mut [Rope],
-) -> Rope {
-    let value = match <TaggedValue as Into<Result<MapValue, TaggedValue>>>::into(value) {
-        Ok(value) => value,
-        Err(_) => panic!("Not a MapValue"),
-    };
+) -> Result<Rope, TaggedValue> {
+    let value = <TaggedValue as Into<Result<MapValue, TaggedValue>>>::into(value)?;
 
-    if children.len() == 0 {
+    let rope = if children.len() == 0 {
         compose_empty(model, value, tags)
     } else if value.styles.flow() {
         if value.styles.multiline() {
@@ -71,7 +68,9 @@ pub fn compose(
         }
     } else {
         compose_block(model, value, tags, children)
-    }
+    };
+
+    Ok(rope)
 }
 
 fn compose_empty(
@@ -158,7 +157,7 @@ fn compose_block(
 
             let key = unsafe { children.get_unchecked_mut(i) };
 
-            let is_multiline = key.is_multiline();
+            let is_multiline = key.is_multiline() || key.ends_line();
             let is_flow = key.is_flow_opening();
 
             if is_multiline && !is_flow {
@@ -178,10 +177,10 @@ fn compose_block(
             break;
         }
 
-        {
+        let key_ended = {
             let key = unsafe { children.get_unchecked_mut(i) };
 
-            let is_multiline = key.is_multiline();
+            let is_multiline = key.is_multiline() || key.ends_line();
             let is_flow = key.is_flow_opening();
 
             if questioned {
@@ -198,7 +197,9 @@ fn compose_block(
             if questioned && is_multiline && is_flow {
                 rope.push(Node::Newline);
             }
-        }
+
+            questioned && is_multiline
+        };
 
         if i == last_child_idx {
             rope.push(Node::ColonNewline);
@@ -208,10 +209,16 @@ fn compose_block(
         {
             let val = unsafe { children.get_unchecked_mut(i + 1) };
 
-            let is_multiline = val.is_multiline();
+            // a block collection only goes after a colon starting its line
+            let is_multiline = val.is_multiline() || (!key_ended && val.ends_line());
             let is_flow = val.is_flow_opening();
 
             if is_multiline && !is_flow {
+                // the colon of an explicit key is not followed by a block on its line
+                if questioned && !key_ended {
+                    rope.push(Node::NewlineIndent(0));
+                }
+
                 rope.push(Node::ColonNewlineIndent(indent_len));
                 val.indent(indent_len);
                 rope.knit(val);
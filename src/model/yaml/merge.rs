@@ -1,6 +1,6 @@
 extern crate skimmer;
 
-use crate::model::{EncodedString, Model, Node, Renderer, Rope, Tagged, TaggedValue};
+use crate::model::{DecodeError, EncodedString, Model, Node, Renderer, Rope, Tagged, TaggedValue};
 
 use std::any::Any;
 use std::borrow::Cow;
@@ -52,7 +52,7 @@ impl Model for Merge {
         }
     }
 
-    fn decode(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, ()> {
+    fn decode(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, DecodeError> {
         let mut ptr = 0;
 
         // let vlen = value.len ();
@@ -93,7 +93,7 @@ impl Model for Merge {
                     Some(b'"') if quote_state == 2 => {
                         ptr += 1;
                     }
-                    _ => return Err(()),
+                    _ => return Err(DecodeError::new(self, value, "the quotes are not closed")),
                 }
             }
 
@@ -103,13 +103,19 @@ impl Model for Merge {
                     Some(b' ') | Some(b'\n') | Some(b'\t') | Some(b'\r') => {
                         ptr += 1;
                     }
-                    _ => return Err(()),
+                    _ => {
+                        return Err(DecodeError::new(
+                            self,
+                            value,
+                            "unexpected characters after the merge key",
+                        ))
+                    }
                 };
             }
 
             Ok(TaggedValue::from(MergeValue))
         } else {
-            Err(())
+            Err(DecodeError::new(self, value, "a merge key is <<"))
         }
     }
 }
@@ -2,7 +2,7 @@ extern crate skimmer;
 
 use crate::model::style::CommonStyles;
 use crate::model::{
-    model_issue_rope, EncodedString, Model, Node, Renderer, Rope, Tagged, TaggedValue,
+    model_issue_rope, DecodeError, EncodedString, Model, Node, Renderer, Rope, Tagged, TaggedValue,
 };
 
 use std::any::Any;
@@ -69,8 +69,8 @@ impl Model for Null {
         true
     }
 
-    fn get_default(&self) -> TaggedValue {
-        TaggedValue::from(NullValue::default())
+    fn get_default(&self) -> Result<TaggedValue, DecodeError> {
+        Ok(TaggedValue::from(NullValue::default()))
     }
 
     fn encode(
@@ -93,7 +93,7 @@ impl Model for Null {
         Ok(model_issue_rope(self, node, issue_tag, alias, tags))
     }
 
-    fn decode(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, ()> {
+    fn decode(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, DecodeError> {
         if value.len() == 0 {
             return Ok(TaggedValue::from(NullValue::default()));
         }
@@ -124,7 +124,7 @@ impl Model for Null {
                 match value.get(ptr).map(|b| *b) {
                     Some(b'\'') if quote_state == 1 => (),
                     Some(b'"') if quote_state == 2 => (),
-                    _ => return Err(()),
+                    _ => return Err(DecodeError::new(self, value, "the quotes are not closed")),
                 };
             }
 
@@ -135,10 +135,18 @@ impl Model for Null {
             match value.get(ptr).map(|b| *b) {
                 Some(b'\'') if quote_state == 1 => Ok(TaggedValue::from(NullValue::default())),
                 Some(b'"') if quote_state == 2 => Ok(TaggedValue::from(NullValue::default())),
-                _ => Err(()),
+                _ => Err(DecodeError::new(
+                    self,
+                    value,
+                    "a null is ~, null, Null, NULL or empty",
+                )),
             }
         } else {
-            Err(())
+            Err(DecodeError::new(
+                self,
+                value,
+                "a null is ~, null, Null, NULL or empty",
+            ))
         }
     }
 }
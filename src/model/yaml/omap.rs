@@ -41,11 +41,11 @@ impl Model for Omap {
         value: TaggedValue,
         tags: &mut dyn Iterator<Item = &(Cow<'static, str>, Cow<'static, str>)>,
         children: &mut [Rope],
-    ) -> Rope {
+    ) -> Result<Rope, TaggedValue> {
         let value: PairsValue =
             match <TaggedValue as Into<Result<OmapValue, TaggedValue>>>::into(value) {
                 Ok(value) => value.into(),
-                Err(_) => panic!("Not an OmapValue"),
+                Err(value) => return Err(value),
             };
 
         compose(self, renderer, TaggedValue::from(value), tags, children)
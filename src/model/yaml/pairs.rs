@@ -42,7 +42,7 @@ impl Model for Pairs {
         value: TaggedValue,
         tags: &mut dyn Iterator<Item = &(Cow<'static, str>, Cow<'static, str>)>,
         children: &mut [Rope],
-    ) -> Rope {
+    ) -> Result<Rope, TaggedValue> {
         compose(self, renderer, value, tags, children)
     }
 }
@@ -53,18 +53,14 @@ pub fn compose(
     value: TaggedValue,
     tags: &mut dyn Iterator<Item = &(Cow<'static, str>, Cow<'static, str>)>,
     children: &mut [Rope],
-) -> Rope {
-    let value: PairsValue =
-        match <TaggedValue as Into<Result<PairsValue, TaggedValue>>>::into(value) {
-            Ok(value) => value,
-            Err(_) => panic!("Not a PairsValue"),
-        };
+) -> Result<Rope, TaggedValue> {
+    let value: PairsValue = <TaggedValue as Into<Result<PairsValue, TaggedValue>>>::into(value)?;
 
     if children.len() == 0 {
-        return compose_empty(model, value, tags);
+        return Ok(compose_empty(model, value, tags));
     }
 
-    if value.styles.flow() {
+    let rope = if value.styles.flow() {
         if value.styles.multiline() {
             compose_flow_multiline(model, value, tags, children)
         } else if value.styles.respect_threshold() {
@@ -74,7 +70,9 @@ pub fn compose(
         }
     } else {
         compose_block(model, value, tags, children)
-    }
+    };
+
+    Ok(rope)
 }
 
 fn compose_empty(
@@ -485,7 +483,7 @@ fn compose_block(
         {
             let key = unsafe { children.get_unchecked_mut(i) };
 
-            let is_multiline = key.is_multiline();
+            let is_multiline = key.is_multiline() || key.ends_line();
             let is_flow = key.is_flow_opening();
 
             if is_multiline && !is_flow {
@@ -504,7 +502,7 @@ fn compose_block(
         {
             let val = unsafe { children.get_unchecked_mut(i + 1) };
 
-            let is_multiline = val.is_multiline();
+            let is_multiline = val.is_multiline() || val.ends_line();
             let is_flow = val.is_flow_opening();
 
             if is_multiline && !is_flow {
@@ -512,7 +510,9 @@ fn compose_block(
                 val.indent(indent_len);
                 rope.knit(val);
 
-                rope.push(Node::IndentHyphenSpace(0));
+                if i != penult_child_idx {
+                    rope.push(Node::IndentHyphenSpace(0));
+                }
             } else {
                 rope.push(Node::ColonSpace);
                 rope.knit(val);
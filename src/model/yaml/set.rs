@@ -42,7 +42,7 @@ impl Model for Set {
         value: TaggedValue,
         tags: &mut dyn Iterator<Item = &(Cow<'static, str>, Cow<'static, str>)>,
         children: &mut [Rope],
-    ) -> Rope {
+    ) -> Result<Rope, TaggedValue> {
         compose(self, renderer, value, tags, children)
     }
 }
@@ -53,17 +53,14 @@ pub fn compose(
     value: TaggedValue,
     tags: &mut dyn Iterator<Item = &(Cow<'static, str>, Cow<'static, str>)>,
     children: &mut [Rope],
-) -> Rope {
-    let value: SetValue = match <TaggedValue as Into<Result<SetValue, TaggedValue>>>::into(value) {
-        Ok(value) => value,
-        Err(_) => panic!("Not a SeqValue"),
-    };
+) -> Result<Rope, TaggedValue> {
+    let value: SetValue = <TaggedValue as Into<Result<SetValue, TaggedValue>>>::into(value)?;
 
     if children.len() == 0 {
-        return compose_empty(model, value, tags);
+        return Ok(compose_empty(model, value, tags));
     }
 
-    if value.styles.flow() {
+    let rope = if value.styles.flow() {
         if value.styles.multiline() {
             compose_flow_multiline(model, value, tags, children)
         } else if value.styles.respect_threshold() {
@@ -73,7 +70,9 @@ pub fn compose(
         }
     } else {
         compose_block(model, value, tags, children)
-    }
+    };
+
+    Ok(rope)
 }
 
 fn compose_empty(
@@ -365,7 +364,7 @@ fn compose_block(
             rope.push(Node::QuestionSpace);
         }
 
-        let is_multiline = child.is_multiline();
+        let is_multiline = child.is_multiline() || child.ends_line();
 
         rope.knit(child);
 
@@ -3,8 +3,9 @@ extern crate skimmer;
 use crate::txt::encoding::{Unicode, UTF8};
 
 use crate::model::renderer::{EncodedString, Node, Renderer};
+use crate::model::schema::Schema;
 use crate::model::style::{CommonStyles, Style};
-use crate::model::{model_issue_rope, Model, Rope, Tagged, TaggedValue};
+use crate::model::{model_issue_rope, DecodeError, Model, Rope, Tagged, TaggedValue};
 
 use std::any::Any;
 use std::borrow::Cow;
@@ -418,8 +419,8 @@ impl Model for Str {
         true
     }
 
-    fn get_default(&self) -> TaggedValue {
-        TaggedValue::from(StrValue::from(String::new()))
+    fn get_default(&self) -> Result<TaggedValue, DecodeError> {
+        Ok(TaggedValue::from(StrValue::from(String::new())))
     }
 
     fn encode(
@@ -438,7 +439,7 @@ impl Model for Str {
     }
 
     // TODO: check if value.get_unchecked goes faster
-    fn decode(&self, _explicit: bool, value: &[u8]) -> Result<TaggedValue, ()> {
+    fn decode(&self, _explicit: bool, value: &[u8]) -> Result<TaggedValue, DecodeError> {
         let mut ptr: usize = 0;
         let mut state: u8 = 0;
 
@@ -504,7 +505,11 @@ impl Model for Str {
                             if ptr == value.len() {
                                 break;
                             } else {
-                                return Err(());
+                                return Err(DecodeError::new(
+                                    self,
+                                    value,
+                                    "unexpected characters after the closing quote",
+                                ));
                             }
                         }
 
@@ -679,7 +684,11 @@ impl Model for Str {
 
                 match String::from_utf8(result) {
                     Ok(s) => Ok(TaggedValue::from(StrValue::from(s))),
-                    _ => Err(()),
+                    _ => Err(DecodeError::new(
+                        self,
+                        value,
+                        "the string is not valid UTF-8",
+                    )),
                 }
             }
 
@@ -745,7 +754,13 @@ impl Model for Str {
                                     result.push(b'\'');
                                 }
                                 None => break,
-                                _ => return Err(()),
+                                _ => {
+                                    return Err(DecodeError::new(
+                                        self,
+                                        value,
+                                        "unexpected characters after the closing quote",
+                                    ))
+                                }
                             };
                         }
 
@@ -768,13 +783,21 @@ impl Model for Str {
 
                 match String::from_utf8(result) {
                     Ok(s) => Ok(TaggedValue::from(StrValue::from(s))),
-                    _ => Err(()),
+                    _ => Err(DecodeError::new(
+                        self,
+                        value,
+                        "the string is not valid UTF-8",
+                    )),
                 }
             }
 
             _ => match String::from_utf8(Vec::from(value)) {
                 Ok(s) => Ok(TaggedValue::from(StrValue::from(s))),
-                _ => Err(()),
+                _ => Err(DecodeError::new(
+                    self,
+                    value,
+                    "the string is not valid UTF-8",
+                )),
             },
         }
     }
@@ -1069,6 +1092,82 @@ impl Style for ForceQuotes {
     }
 }
 
+/// Quotes the strings the schema would not read back as the same strings from their plain form,
+/// such as `"true"`, `"1"`, `"a: b"` or `"#c"`
+pub struct QuoteAmbiguous<'a>(pub &'a dyn Schema);
+
+impl<'a> Style for QuoteAmbiguous<'a> {
+    fn tagged_styles_apply(&mut self, value: &mut dyn Tagged) {
+        if value.get_tag().as_ref() != TAG {
+            return;
+        }
+
+        if let Some(ref mut str_val) = value.as_mut_any().downcast_mut::<StrValue>() {
+            let resolved = if is_plain(str_val.get_value()) {
+                self.0.try_decodable_models(str_val.get_value().as_bytes())
+            } else {
+                None
+            };
+
+            if !matches!(resolved, Some(TaggedValue::Str(_))) {
+                str_val.set_force_quotes(true);
+            }
+        }
+    }
+}
+
+/// Whether the text is a plain scalar by itself, in both the block and the flow context.
+/// It cannot start with an indicator or have a line break, leading or trailing spaces,
+/// flow indicators, comments (` #`) or mapping values (`: `) in it.
+pub fn is_plain(text: &str) -> bool {
+    let mut bytes = text.bytes();
+
+    let first = match bytes.next() {
+        Some(first) => first,
+        None => return false,
+    };
+
+    let starts = match first {
+        b'-' | b'?' | b':' => {
+            matches!(bytes.next(), Some(next) if !b" \t,[]{}".contains(&next))
+        }
+        b',' | b'[' | b']' | b'{' | b'}' | b'#' | b'&' | b'*' | b'!' | b'|' | b'>' | b'\''
+        | b'"' | b'%' | b'@' | b'`' | b' ' | b'\t' => false,
+        _ => true,
+    };
+
+    starts
+        && !text.starts_with("---")
+        && !text.starts_with("...")
+        && !text.ends_with([' ', '\t', ':'])
+        && !text.contains([',', '[', ']', '{', '}', '\n', '\r'])
+        && !text.contains(": ")
+        && !text.contains(":\t")
+        && !text.contains(" #")
+        && !text.contains("\t#")
+}
+
+/// The text as a double-quoted scalar, with the quotes, the backslashes and the control characters escaped
+pub fn double_quoted(text: &str) -> String {
+    let mut result = String::with_capacity(text.len() + 2);
+
+    result.push('"');
+    for ch in text.chars() {
+        match ch {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            ch if ch.is_control() => result.push_str(&format!("\\u{:04X}", ch as u32)),
+            ch => result.push(ch),
+        }
+    }
+    result.push('"');
+
+    result
+}
+
 pub const PREFER_DOUBLE_QUOTES: PreferDoubleQuotes = PreferDoubleQuotes(true);
 pub const NO_PREFER_DOUBLE_QUOTES: PreferDoubleQuotes = PreferDoubleQuotes(false);
 
@@ -1144,6 +1243,10 @@ impl StrValue {
         }
     }
 
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+
     pub fn take_twine(&mut self) -> Cow<'static, str> {
         mem::replace(&mut self.value, Cow::from(String::with_capacity(0)))
     }
@@ -5,12 +5,15 @@ extern crate skimmer;
 use self::fraction::{BigFraction, Fraction};
 use self::num::BigUint;
 
-use crate::model::{EncodedString, Model, Node, Renderer, Rope, Tagged, TaggedValue};
+use crate::model::{DecodeError, EncodedString, Model, Node, Renderer, Rope, Tagged, TaggedValue};
 
 use crate::model::yaml::float::FloatValue;
 
+use self::num::ToPrimitive;
+
 use std::any::Any;
 use std::borrow::Cow;
+use std::fmt;
 use std::i32;
 use std::iter::Iterator;
 
@@ -161,76 +164,12 @@ impl Model for Timestamp {
                 Err(value) => return Err(value),
             };
 
-        let mut src = String::with_capacity(32);
-
-        if value.year.is_some() && value.month.is_some() && value.day.is_some() {
-            src.push_str(&format!(
-                "{:04}-{:02}-{:02}",
-                value.year.as_ref().unwrap(),
-                value.month.as_ref().unwrap(),
-                value.day.as_ref().unwrap()
-            ));
-        }
-
-        if value.hour.is_some() || value.minute.is_some() || value.second.is_some() {
-            if src.len() > 0 {
-                src.push_str("T")
-            };
-
-            src.push_str(&format!(
-                "{:02}:{:02}:{:02}",
-                if let Some(h) = value.hour.as_ref() {
-                    *h
-                } else {
-                    0
-                },
-                if let Some(m) = value.minute.as_ref() {
-                    *m
-                } else {
-                    0
-                },
-                if let Some(s) = value.second.as_ref() {
-                    *s
-                } else {
-                    0
-                }
-            ));
-
-            let fi = if let Some(f) = value.fraction.as_ref() {
-                let f = f.clone().format_as_float();
-                if let Some(f) = f {
-                    src.push_str(&f[1..]);
-                    true
-                } else {
-                    false
-                }
-            } else {
-                true
-            };
-
-            if !fi {
-                return Err(TaggedValue::from(value));
-            }
-
-            if let Some(h) = value.tz_hour.as_ref() {
-                if *h > 0 {
-                    src.push_str(&format!("{:+02}", h));
-                } else {
-                    src.push_str(&format!("{:+03}", h));
-                }
-
-                if let Some(ref m) = value.tz_minute.as_ref() {
-                    src.push_str(&format!(":{:02}", m));
-                }
-            }
-        }
-
         Ok(Rope::from(Node::String(EncodedString::from(
-            src.into_bytes(),
+            value.to_string().into_bytes(),
         ))))
     }
 
-    fn decode(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, ()> {
+    fn decode(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, DecodeError> {
         let mut ptr: usize = 0;
 
         let mut state: u8 = 0;
@@ -280,7 +219,11 @@ impl Model for Timestamp {
                 let figure = self.parse_figure(&mut ptr, value);
 
                 if figure.is_none() {
-                    return Err(());
+                    return Err(DecodeError::new(
+                        self,
+                        value,
+                        "a timestamp starts with the year",
+                    ));
                 }
 
                 if !ltz && figure.unwrap() >= 0 && figure.unwrap() < 25 {
@@ -306,17 +249,29 @@ impl Model for Timestamp {
                     let figure = self.parse_figure(&mut ptr, value);
 
                     if figure.is_none() {
-                        return Err(());
+                        return Err(DecodeError::new(
+                            self,
+                            value,
+                            "the month is expected after the year",
+                        ));
                     }
 
                     if figure.unwrap() > 0 && figure.unwrap() < 13 {
                         state = state | STATE_MONTH;
                         dt = dt.month(figure.unwrap() as u8);
                     } else {
-                        return Err(());
+                        return Err(DecodeError::new(
+                            self,
+                            value,
+                            "the month is out of the 1..12 range",
+                        ));
                     }
                 } else {
-                    return Err(());
+                    return Err(DecodeError::new(
+                        self,
+                        value,
+                        "the month is expected after the year",
+                    ));
                 }
 
                 continue;
@@ -327,17 +282,29 @@ impl Model for Timestamp {
                     let figure = self.parse_figure(&mut ptr, value);
 
                     if figure.is_none() {
-                        return Err(());
+                        return Err(DecodeError::new(
+                            self,
+                            value,
+                            "the day is expected after the month",
+                        ));
                     }
 
                     if figure.unwrap() > 0 && figure.unwrap() < 32 {
                         state = state | STATE_DAY;
                         dt = dt.day(figure.unwrap() as u8);
                     } else {
-                        return Err(());
+                        return Err(DecodeError::new(
+                            self,
+                            value,
+                            "the day is out of the 1..31 range",
+                        ));
                     }
                 } else {
-                    return Err(());
+                    return Err(DecodeError::new(
+                        self,
+                        value,
+                        "the day is expected after the month",
+                    ));
                 }
 
                 continue;
@@ -346,20 +313,34 @@ impl Model for Timestamp {
                     Some(b'T') | Some(b' ') | Some(b't') | Some(b'\t') => {
                         ptr += 1;
                     }
-                    _ => return Err(()),
+                    _ => {
+                        return Err(DecodeError::new(
+                            self,
+                            value,
+                            "the time is expected after the date",
+                        ))
+                    }
                 };
 
                 let figure = self.parse_figure(&mut ptr, value);
 
                 if figure.is_none() {
-                    return Err(());
+                    return Err(DecodeError::new(
+                        self,
+                        value,
+                        "the hour is expected after the date",
+                    ));
                 }
 
                 if figure.unwrap() >= 0 && figure.unwrap() < 25 {
                     state = state | STATE_HOUR;
                     dt = dt.hour(figure.unwrap() as u8);
                 } else {
-                    return Err(());
+                    return Err(DecodeError::new(
+                        self,
+                        value,
+                        "the hour is out of the 0..24 range",
+                    ));
                 }
 
                 continue;
@@ -370,17 +351,29 @@ impl Model for Timestamp {
                     let figure = self.parse_figure(&mut ptr, value);
 
                     if figure.is_none() {
-                        return Err(());
+                        return Err(DecodeError::new(
+                            self,
+                            value,
+                            "the minutes are expected after the hour",
+                        ));
                     }
 
                     if figure.unwrap() >= 0 && figure.unwrap() < 61 {
                         state = state | STATE_MINUTE;
                         dt = dt.minute(figure.unwrap() as u8);
                     } else {
-                        return Err(());
+                        return Err(DecodeError::new(
+                            self,
+                            value,
+                            "the minutes are out of the 0..60 range",
+                        ));
                     }
                 } else {
-                    return Err(());
+                    return Err(DecodeError::new(
+                        self,
+                        value,
+                        "the minutes are expected after the hour",
+                    ));
                 }
 
                 continue;
@@ -393,17 +386,29 @@ impl Model for Timestamp {
                     let figure = self.parse_figure(&mut ptr, value);
 
                     if figure.is_none() {
-                        return Err(());
+                        return Err(DecodeError::new(
+                            self,
+                            value,
+                            "the seconds are expected after the minutes",
+                        ));
                     }
 
                     if figure.unwrap() >= 0 && figure.unwrap() < 61 {
                         state = state | STATE_SECOND;
                         dt = dt.second(figure.unwrap() as u8);
                     } else {
-                        return Err(());
+                        return Err(DecodeError::new(
+                            self,
+                            value,
+                            "the seconds are out of the 0..60 range",
+                        ));
                     }
                 } else {
-                    return Err(());
+                    return Err(DecodeError::new(
+                        self,
+                        value,
+                        "the seconds are expected after the minutes",
+                    ));
                 }
 
                 continue;
@@ -416,7 +421,11 @@ impl Model for Timestamp {
                     let fraction = self.parse_fraction(&mut ptr, value);
 
                     if fraction.is_none() {
-                        return Err(());
+                        return Err(DecodeError::new(
+                            self,
+                            value,
+                            "the fraction of the second is expected after the dot",
+                        ));
                     }
 
                     dt = dt.fraction(fraction.unwrap());
@@ -445,26 +454,42 @@ impl Model for Timestamp {
                         ptr += 1;
                         let figure = self.parse_figure(&mut ptr, value);
                         if figure.is_none() {
-                            return Err(());
+                            return Err(DecodeError::new(
+                                self,
+                                value,
+                                "the time zone hour is expected",
+                            ));
                         }
                         if figure.unwrap() >= 0 && figure.unwrap() < 25 {
                             state = state | STATE_TZ_HOUR;
                             dt = dt.tz_hour((figure.unwrap() as i8) * -1);
                         } else {
-                            return Err(());
+                            return Err(DecodeError::new(
+                                self,
+                                value,
+                                "the time zone hour is out of the 0..24 range",
+                            ));
                         }
                     }
                     Some(b'+') => {
                         ptr += 1;
                         let figure = self.parse_figure(&mut ptr, value);
                         if figure.is_none() {
-                            return Err(());
+                            return Err(DecodeError::new(
+                                self,
+                                value,
+                                "the time zone hour is expected",
+                            ));
                         }
                         if figure.unwrap() >= 0 && figure.unwrap() < 25 {
                             state = state | STATE_TZ_HOUR;
                             dt = dt.tz_hour(figure.unwrap() as i8);
                         } else {
-                            return Err(());
+                            return Err(DecodeError::new(
+                                self,
+                                value,
+                                "the time zone hour is out of the 0..24 range",
+                            ));
                         }
                     }
                     _ => (),
@@ -474,7 +499,11 @@ impl Model for Timestamp {
                     let figure = self.parse_figure(&mut ptr, value);
 
                     if figure.is_none() {
-                        return Err(());
+                        return Err(DecodeError::new(
+                            self,
+                            value,
+                            "the time zone hour is expected",
+                        ));
                     }
 
                     if figure.unwrap() >= 0 && figure.unwrap() < 25 {
@@ -482,7 +511,11 @@ impl Model for Timestamp {
 
                         dt = dt.tz_hour(figure.unwrap() as i8);
                     } else {
-                        return Err(());
+                        return Err(DecodeError::new(
+                            self,
+                            value,
+                            "the time zone hour is out of the 0..24 range",
+                        ));
                     }
 
                     continue;
@@ -493,13 +526,23 @@ impl Model for Timestamp {
                         Some(b':') => {
                             ptr += 1;
                         }
-                        _ => return Err(()),
+                        _ => {
+                            return Err(DecodeError::new(
+                                self,
+                                value,
+                                "the time zone minutes are expected after the colon",
+                            ))
+                        }
                     };
 
                     let figure = self.parse_figure(&mut ptr, value);
 
                     if figure.is_none() {
-                        return Err(());
+                        return Err(DecodeError::new(
+                            self,
+                            value,
+                            "the time zone minutes are expected after the colon",
+                        ));
                     }
 
                     if figure.unwrap() >= 0 && figure.unwrap() < 61 {
@@ -507,13 +550,21 @@ impl Model for Timestamp {
 
                         dt = dt.tz_minute(figure.unwrap() as u8);
                     } else {
-                        return Err(());
+                        return Err(DecodeError::new(
+                            self,
+                            value,
+                            "the time zone minutes are out of the 0..60 range",
+                        ));
                     }
 
                     continue;
                 }
             } else {
-                return Err(());
+                return Err(DecodeError::new(
+                    self,
+                    value,
+                    "unexpected characters after the timestamp",
+                ));
             }
 
             break;
@@ -524,13 +575,17 @@ impl Model for Timestamp {
                 match value.get(ptr).map(|b| *b) {
                     Some(b'\'') if quote_state == 1 => (),
                     Some(b'"') if quote_state == 2 => (),
-                    _ => return Err(()),
+                    _ => return Err(DecodeError::new(self, value, "the quotes are not closed")),
                 };
             }
 
+            if let Err(reason) = dt.validate() {
+                return Err(DecodeError::new(self, value, reason));
+            }
+
             Ok(TaggedValue::from(dt))
         } else {
-            Err(())
+            Err(DecodeError::new(self, value, "the value is empty"))
         }
     }
 }
@@ -607,6 +662,272 @@ impl TimestampValue {
         self.tz_minute = Some(val);
         self
     }
+
+    /// The date checked against the calendar
+    pub fn date(year: i32, month: u8, day: u8) -> Option<TimestampValue> {
+        TimestampValue::new()
+            .year(year)
+            .month(month)
+            .day(day)
+            .validated()
+    }
+
+    /// The date and the time of day checked against the calendar and the clock
+    pub fn date_time(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Option<TimestampValue> {
+        TimestampValue::new()
+            .year(year)
+            .month(month)
+            .day(day)
+            .hour(hour)
+            .minute(minute)
+            .second(second)
+            .validated()
+    }
+
+    /// Sets the time zone as an offset from UTC in seconds, which is truncated to minutes
+    pub fn offset(mut self, seconds: i32) -> TimestampValue {
+        let minutes = seconds / 60;
+
+        self.tz_hour = Some((minutes / 60) as i8);
+        self.tz_minute = if minutes % 60 == 0 {
+            None
+        } else {
+            Some((minutes % 60).unsigned_abs() as u8)
+        };
+        self
+    }
+
+    pub fn validated(self) -> Option<TimestampValue> {
+        match self.validate() {
+            Ok(()) => Some(self),
+            Err(_) => None,
+        }
+    }
+
+    /// Checks the date against the calendar (the day must exist in the month of the year)
+    /// and the time against the clock (the second 60 is a leap one)
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if self.month.is_some() != self.day.is_some() {
+            return Err("the date must have both the month and the day");
+        }
+
+        if let Some(month) = self.month {
+            if !(1..=12).contains(&month) {
+                return Err("the month is out of the 1..12 range");
+            }
+        }
+
+        if let (Some(month), Some(day)) = (self.month, self.day) {
+            if !(1..=days_in_month(self.year.unwrap_or(0), month)).contains(&day) {
+                return Err("the day is out of the month");
+            }
+        }
+
+        if self.hour.is_some_and(|hour| hour > 23) {
+            return Err("the hour is out of the 0..23 range");
+        }
+
+        if self.minute.is_some_and(|minute| minute > 59) {
+            return Err("the minutes are out of the 0..59 range");
+        }
+
+        if self.second.is_some_and(|second| second > 60) {
+            return Err("the seconds are out of the 0..60 range");
+        }
+
+        if let Some(ref fraction) = self.fraction {
+            match fraction.to_f64() {
+                Some(f) if (0.0..1.0).contains(&f) => (),
+                _ => return Err("the fraction of the second is out of the 0..1 range"),
+            }
+        }
+
+        if self.tz_hour.is_some_and(|hour| !(-23..=23).contains(&hour)) {
+            return Err("the time zone hour is out of the -23..23 range");
+        }
+
+        if self.tz_minute.is_some_and(|minute| minute > 59) {
+            return Err("the time zone minutes are out of the 0..59 range");
+        }
+
+        Ok(())
+    }
+
+    /// Whether that's a date with no time of day
+    pub fn is_date(&self) -> bool {
+        self.year.is_some() && self.day.is_some() && !self.has_time()
+    }
+
+    pub fn has_time(&self) -> bool {
+        self.hour.is_some() || self.minute.is_some() || self.second.is_some()
+    }
+
+    /// The offset of the time zone from UTC in seconds (a timestamp with no time zone is in UTC)
+    pub fn offset_seconds(&self) -> i32 {
+        let hours = i32::from(self.tz_hour.unwrap_or(0));
+        let minutes = i32::from(self.tz_minute.unwrap_or(0));
+
+        hours * 3600 + if hours < 0 { -minutes } else { minutes } * 60
+    }
+
+    /// The fraction of the second in nanoseconds
+    pub fn nanosecond(&self) -> u32 {
+        match self.fraction.as_ref().and_then(|f| f.to_f64()) {
+            Some(f) => ((f * 1e9).round() as u32).min(999_999_999),
+            None => 0,
+        }
+    }
+
+    /// Sets the fraction of the second in nanoseconds
+    pub fn nanoseconds(self, nanos: u32) -> TimestampValue {
+        if nanos == 0 {
+            self
+        } else {
+            self.fraction(FloatValue::from(Fraction::new(
+                u64::from(nanos),
+                1_000_000_000u64,
+            )))
+        }
+    }
+
+    /// Moves the date and the time into UTC, so that the time zone is `+00`.
+    /// That's None for a time of day with an offset but no date.
+    pub fn to_utc(&self) -> Option<TimestampValue> {
+        let mut utc = self.clone();
+
+        if !self.has_time() {
+            return Some(utc);
+        }
+
+        utc.tz_hour = Some(0);
+        utc.tz_minute = None;
+
+        let offset = self.offset_seconds() / 60;
+
+        if offset == 0 {
+            return Some(utc);
+        }
+
+        let minutes = i64::from(self.hour.unwrap_or(0)) * 60 + i64::from(self.minute.unwrap_or(0))
+            - i64::from(offset);
+
+        utc.hour = Some((minutes.rem_euclid(1440) / 60) as u8);
+        utc.minute = Some((minutes.rem_euclid(1440) % 60) as u8);
+
+        let days = minutes.div_euclid(1440);
+
+        if days != 0 {
+            let (year, month, day) = match (self.year, self.month, self.day) {
+                (Some(year), Some(month), Some(day)) => {
+                    civil_from_days(days_from_civil(year, month, day) + days)?
+                }
+                _ => return None,
+            };
+
+            utc.year = Some(year);
+            utc.month = Some(month);
+            utc.day = Some(day);
+        }
+
+        Some(utc)
+    }
+}
+
+impl Default for TimestampValue {
+    fn default() -> TimestampValue {
+        TimestampValue::new()
+    }
+}
+
+impl fmt::Display for TimestampValue {
+    fn fmt(&self, fmtter: &mut fmt::Formatter) -> fmt::Result {
+        let date = if let (Some(year), Some(month), Some(day)) = (self.year, self.month, self.day) {
+            write!(fmtter, "{:04}-{:02}-{:02}", year, month, day)?;
+            true
+        } else {
+            false
+        };
+
+        if self.has_time() {
+            if date {
+                fmtter.write_str("T")?;
+            }
+
+            write!(
+                fmtter,
+                "{:02}:{:02}:{:02}",
+                self.hour.unwrap_or(0),
+                self.minute.unwrap_or(0),
+                self.second.unwrap_or(0)
+            )?;
+
+            if let Some(fraction) = self.fraction.as_ref().and_then(|f| f.format_as_float()) {
+                fmtter.write_str(&fraction[1..])?;
+            }
+
+            if let Some(hour) = self.tz_hour {
+                if hour > 0 {
+                    write!(fmtter, "{:+02}", hour)?;
+                } else {
+                    write!(fmtter, "{:+03}", hour)?;
+                }
+
+                if let Some(minute) = self.tz_minute {
+                    write!(fmtter, ":{:02}", minute)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// The days in the month, where a month of an unknown year (0 is a leap one) may have 29 days of February
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// The days since 1970-01-01 of the proleptic Gregorian calendar
+fn days_from_civil(year: i32, month: u8, day: u8) -> i64 {
+    let year = i64::from(year) - if month <= 2 { 1 } else { 0 };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let month = i64::from(month);
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * 146_097 + doe - 719_468
+}
+
+/// The date of the days since 1970-01-01, or None if the year is out of i32
+fn civil_from_days(days: i64) -> Option<(i32, u8, u8)> {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    i32::try_from(year).ok().map(|year| (year, month, day))
 }
 
 impl Tagged for TimestampValue {
@@ -623,6 +944,239 @@ impl Tagged for TimestampValue {
     }
 }
 
+#[cfg(feature = "chrono")]
+mod with_chrono {
+    extern crate chrono;
+
+    use self::chrono::{
+        DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone,
+        Timelike, Utc,
+    };
+
+    use super::TimestampValue;
+
+    fn naive_date(value: &TimestampValue) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(value.year?, u32::from(value.month?), u32::from(value.day?))
+    }
+
+    /// The leap second is the 59th one with more than 10^9 nanoseconds
+    fn naive_time(value: &TimestampValue) -> Option<NaiveTime> {
+        let (second, nanos) = match value.second.unwrap_or(0) {
+            60 => (59, value.nanosecond() + 1_000_000_000),
+            second => (u32::from(second), value.nanosecond()),
+        };
+
+        NaiveTime::from_hms_nano_opt(
+            u32::from(value.hour.unwrap_or(0)),
+            u32::from(value.minute.unwrap_or(0)),
+            second,
+            nanos,
+        )
+    }
+
+    fn naive_date_time(value: &TimestampValue) -> Option<NaiveDateTime> {
+        Some(naive_date(value)?.and_time(naive_time(value)?))
+    }
+
+    impl<'a> From<&'a TimestampValue> for Result<NaiveDate, &'a TimestampValue> {
+        fn from(value: &'a TimestampValue) -> Result<NaiveDate, &'a TimestampValue> {
+            if !value.is_date() {
+                return Err(value);
+            }
+
+            naive_date(value).ok_or(value)
+        }
+    }
+
+    impl<'a> From<&'a TimestampValue> for Result<NaiveTime, &'a TimestampValue> {
+        fn from(value: &'a TimestampValue) -> Result<NaiveTime, &'a TimestampValue> {
+            if value.year.is_some() || !value.has_time() || value.offset_seconds() != 0 {
+                return Err(value);
+            }
+
+            naive_time(value).ok_or(value)
+        }
+    }
+
+    /// The date and time in UTC
+    impl<'a> From<&'a TimestampValue> for Result<NaiveDateTime, &'a TimestampValue> {
+        fn from(value: &'a TimestampValue) -> Result<NaiveDateTime, &'a TimestampValue> {
+            value
+                .to_utc()
+                .as_ref()
+                .and_then(naive_date_time)
+                .ok_or(value)
+        }
+    }
+
+    impl<'a> From<&'a TimestampValue> for Result<DateTime<FixedOffset>, &'a TimestampValue> {
+        fn from(value: &'a TimestampValue) -> Result<DateTime<FixedOffset>, &'a TimestampValue> {
+            FixedOffset::east_opt(value.offset_seconds())
+                .and_then(|offset| {
+                    offset
+                        .from_local_datetime(&naive_date_time(value)?)
+                        .single()
+                })
+                .ok_or(value)
+        }
+    }
+
+    impl<'a> From<&'a TimestampValue> for Result<DateTime<Utc>, &'a TimestampValue> {
+        fn from(value: &'a TimestampValue) -> Result<DateTime<Utc>, &'a TimestampValue> {
+            let utc: Result<NaiveDateTime, &'a TimestampValue> = value.into();
+            utc.map(|utc| Utc.from_utc_datetime(&utc))
+        }
+    }
+
+    impl From<NaiveDate> for TimestampValue {
+        fn from(date: NaiveDate) -> TimestampValue {
+            TimestampValue::new()
+                .year(date.year())
+                .month(date.month() as u8)
+                .day(date.day() as u8)
+        }
+    }
+
+    impl From<NaiveTime> for TimestampValue {
+        fn from(time: NaiveTime) -> TimestampValue {
+            let (second, nanos) = match time.nanosecond() {
+                nanos if nanos >= 1_000_000_000 => (60, nanos - 1_000_000_000),
+                nanos => (time.second() as u8, nanos),
+            };
+
+            TimestampValue::new()
+                .hour(time.hour() as u8)
+                .minute(time.minute() as u8)
+                .second(second)
+                .nanoseconds(nanos)
+        }
+    }
+
+    impl From<NaiveDateTime> for TimestampValue {
+        fn from(date_time: NaiveDateTime) -> TimestampValue {
+            let time = TimestampValue::from(date_time.time());
+            let mut value = TimestampValue::from(date_time.date());
+
+            value.hour = time.hour;
+            value.minute = time.minute;
+            value.second = time.second;
+            value.fraction = time.fraction;
+            value
+        }
+    }
+
+    impl<Tz: TimeZone> From<DateTime<Tz>> for TimestampValue {
+        fn from(date_time: DateTime<Tz>) -> TimestampValue {
+            let offset = date_time.offset().fix().local_minus_utc();
+
+            TimestampValue::from(date_time.naive_local()).offset(offset)
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+mod with_time {
+    extern crate time;
+
+    use self::time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+    use super::TimestampValue;
+
+    fn date(value: &TimestampValue) -> Option<Date> {
+        let month = Month::try_from(value.month?).ok()?;
+        Date::from_calendar_date(value.year?, month, value.day?).ok()
+    }
+
+    /// There are no leap seconds in `time`
+    fn time_of_day(value: &TimestampValue) -> Option<Time> {
+        Time::from_hms_nano(
+            value.hour.unwrap_or(0),
+            value.minute.unwrap_or(0),
+            value.second.unwrap_or(0),
+            value.nanosecond(),
+        )
+        .ok()
+    }
+
+    fn date_time(value: &TimestampValue) -> Option<PrimitiveDateTime> {
+        Some(PrimitiveDateTime::new(date(value)?, time_of_day(value)?))
+    }
+
+    impl<'a> From<&'a TimestampValue> for Result<Date, &'a TimestampValue> {
+        fn from(value: &'a TimestampValue) -> Result<Date, &'a TimestampValue> {
+            if !value.is_date() {
+                return Err(value);
+            }
+
+            date(value).ok_or(value)
+        }
+    }
+
+    impl<'a> From<&'a TimestampValue> for Result<Time, &'a TimestampValue> {
+        fn from(value: &'a TimestampValue) -> Result<Time, &'a TimestampValue> {
+            if value.year.is_some() || !value.has_time() || value.offset_seconds() != 0 {
+                return Err(value);
+            }
+
+            time_of_day(value).ok_or(value)
+        }
+    }
+
+    /// The date and time in UTC
+    impl<'a> From<&'a TimestampValue> for Result<PrimitiveDateTime, &'a TimestampValue> {
+        fn from(value: &'a TimestampValue) -> Result<PrimitiveDateTime, &'a TimestampValue> {
+            value.to_utc().as_ref().and_then(date_time).ok_or(value)
+        }
+    }
+
+    impl<'a> From<&'a TimestampValue> for Result<OffsetDateTime, &'a TimestampValue> {
+        fn from(value: &'a TimestampValue) -> Result<OffsetDateTime, &'a TimestampValue> {
+            UtcOffset::from_whole_seconds(value.offset_seconds())
+                .ok()
+                .and_then(|offset| Some(date_time(value)?.assume_offset(offset)))
+                .ok_or(value)
+        }
+    }
+
+    impl From<Date> for TimestampValue {
+        fn from(date: Date) -> TimestampValue {
+            TimestampValue::new()
+                .year(date.year())
+                .month(u8::from(date.month()))
+                .day(date.day())
+        }
+    }
+
+    impl From<Time> for TimestampValue {
+        fn from(time: Time) -> TimestampValue {
+            TimestampValue::new()
+                .hour(time.hour())
+                .minute(time.minute())
+                .second(time.second())
+                .nanoseconds(time.nanosecond())
+        }
+    }
+
+    impl From<PrimitiveDateTime> for TimestampValue {
+        fn from(date_time: PrimitiveDateTime) -> TimestampValue {
+            TimestampValue::from(date_time.date())
+                .hour(date_time.hour())
+                .minute(date_time.minute())
+                .second(date_time.second())
+                .nanoseconds(date_time.nanosecond())
+        }
+    }
+
+    impl From<OffsetDateTime> for TimestampValue {
+        fn from(date_time: OffsetDateTime) -> TimestampValue {
+            let offset = date_time.offset().whole_seconds();
+
+            TimestampValue::from(PrimitiveDateTime::new(date_time.date(), date_time.time()))
+                .offset(offset)
+        }
+    }
+}
+
 #[cfg(all(test, not(feature = "dev")))]
 mod tests {
     // TODO: tests on parsing failures
@@ -790,4 +1344,46 @@ mod tests {
             assert!(false)
         }
     }
+
+    #[test]
+    fn calendar() {
+        for &(y, m, d) in [(1970, 1, 1), (2000, 2, 29), (1600, 12, 31), (-44, 3, 15)].iter() {
+            assert_eq!(Some((y, m, d)), civil_from_days(days_from_civil(y, m, d)));
+        }
+
+        assert_eq!(0, days_from_civil(1970, 1, 1));
+        assert_eq!(11_017, days_from_civil(2000, 3, 1));
+
+        assert!(TimestampValue::date(1900, 2, 29).is_none());
+        assert!(TimestampValue::new().month(2).day(29).validate().is_ok());
+        assert!(TimestampValue::new()
+            .year(2001)
+            .month(2)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn to_utc() {
+        let dt = TimestampValue::date_time(2001, 12, 31, 23, 30, 0)
+            .unwrap()
+            .tz_hour(-1)
+            .tz_minute(30);
+
+        assert_eq!(-5400, dt.offset_seconds());
+        assert_eq!("2002-01-01T01:00:00+00", dt.to_utc().unwrap().to_string());
+
+        let dt = TimestampValue::date_time(2000, 3, 1, 0, 15, 0)
+            .unwrap()
+            .offset(3600);
+
+        assert_eq!("2000-02-29T23:15:00+00", dt.to_utc().unwrap().to_string());
+
+        assert!(TimestampValue::new()
+            .hour(23)
+            .minute(0)
+            .tz_hour(-2)
+            .to_utc()
+            .is_none());
+    }
 }
@@ -1,6 +1,6 @@
 extern crate skimmer;
 
-use crate::model::{EncodedString, Model, Node, Renderer, Rope, Tagged, TaggedValue};
+use crate::model::{DecodeError, EncodedString, Model, Node, Renderer, Rope, Tagged, TaggedValue};
 
 use std::any::Any;
 use std::borrow::Cow;
@@ -52,7 +52,7 @@ impl Model for Value {
         }
     }
 
-    fn decode(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, ()> {
+    fn decode(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, DecodeError> {
         // let vlen = value.len ();
 
         let mut ptr = 0;
@@ -76,7 +76,7 @@ impl Model for Value {
             Some(b'=') => {
                 ptr += 1;
             }
-            _ => return Err(()),
+            _ => return Err(DecodeError::new(self, value, "a value key is =")),
         }
 
         if quote_state > 0 {
@@ -87,12 +87,16 @@ impl Model for Value {
                 Some(b'"') if quote_state == 2 => {
                     ptr += 1;
                 }
-                _ => return Err(()),
+                _ => return Err(DecodeError::new(self, value, "the quotes are not closed")),
             };
         }
 
         if value.len() > ptr {
-            return Err(());
+            return Err(DecodeError::new(
+                self,
+                value,
+                "unexpected characters after the value key",
+            ));
         }
 
         Ok(TaggedValue::from(ValueValue))
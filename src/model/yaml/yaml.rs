@@ -1,6 +1,6 @@
 extern crate skimmer;
 
-use crate::model::{EncodedString, Model, Node, Renderer, Rope, Tagged, TaggedValue};
+use crate::model::{DecodeError, EncodedString, Model, Node, Renderer, Rope, Tagged, TaggedValue};
 
 use std::any::Any;
 use std::borrow::Cow;
@@ -54,7 +54,7 @@ impl Model for Yaml {
         }
     }
 
-    fn decode(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, ()> {
+    fn decode(&self, explicit: bool, value: &[u8]) -> Result<TaggedValue, DecodeError> {
         let mut ptr = 0;
         let mut quote_state = 0; // 1 - single, 2 - double
 
@@ -85,7 +85,13 @@ impl Model for Yaml {
                 ptr += 1;
                 YamlValue::Tag
             }
-            _ => return Err(()),
+            _ => {
+                return Err(DecodeError::new(
+                    self,
+                    value,
+                    "a yaml code is one of *, & or !",
+                ))
+            }
         };
 
         if quote_state > 0 {
@@ -93,12 +99,16 @@ impl Model for Yaml {
                 Some(b'\'') | Some(b'"') => {
                     ptr += 1;
                 }
-                _ => return Err(()),
+                _ => return Err(DecodeError::new(self, value, "the quotes are not closed")),
             }
         }
 
         if value.len() > ptr {
-            return Err(());
+            return Err(DecodeError::new(
+                self,
+                value,
+                "unexpected characters after the yaml code",
+            ));
         }
 
         Ok(TaggedValue::from(val))
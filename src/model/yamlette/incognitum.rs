@@ -1,6 +1,6 @@
 extern crate skimmer;
 
-use crate::model::{EncodedString, Model, Node, Renderer, Rope, Tagged, TaggedValue};
+use crate::model::{DecodeError, EncodedString, Model, Node, Renderer, Rope, Tagged, TaggedValue};
 
 use std::any::Any;
 use std::borrow::Cow;
@@ -87,10 +87,14 @@ impl Model for Incognitum {
         ))))
     }
 
-    fn decode(&self, _: bool, value: &[u8]) -> Result<TaggedValue, ()> {
+    fn decode(&self, _: bool, value: &[u8]) -> Result<TaggedValue, DecodeError> {
         match String::from_utf8(Vec::from(value)) {
             Ok(s) => Ok(TaggedValue::from(IncognitumValue::new(s))),
-            _ => Err(()),
+            _ => Err(DecodeError::new(
+                self,
+                value,
+                "the value is not valid UTF-8",
+            )),
         }
     }
 
@@ -99,10 +103,16 @@ impl Model for Incognitum {
         anchor: Option<String>,
         tag: Option<String>,
         value: &[u8],
-    ) -> Result<TaggedValue, ()> {
+    ) -> Result<TaggedValue, DecodeError> {
         let string = match String::from_utf8(Vec::from(value)) {
             Ok(s) => s,
-            _ => return Err(()),
+            _ => {
+                return Err(DecodeError::new(
+                    self,
+                    value,
+                    "the value is not valid UTF-8",
+                ))
+            }
         };
 
         let mut value = IncognitumValue::new(string);
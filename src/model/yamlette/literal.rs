@@ -1,6 +1,6 @@
 extern crate skimmer;
 
-use crate::model::{EncodedString, Model, Node, Renderer, Rope, Tagged, TaggedValue};
+use crate::model::{DecodeError, EncodedString, Model, Node, Renderer, Rope, Tagged, TaggedValue};
 
 use std::any::Any;
 use std::borrow::Cow;
@@ -70,10 +70,10 @@ impl Model for Literal {
         true
     }
 
-    fn get_default(&self) -> TaggedValue {
-        TaggedValue::from(LiteralValue {
+    fn get_default(&self) -> Result<TaggedValue, DecodeError> {
+        Ok(TaggedValue::from(LiteralValue {
             value: Cow::from(String::with_capacity(0)),
-        })
+        }))
     }
 
     fn encode(
@@ -93,9 +93,15 @@ impl Model for Literal {
         }
     }
 
-    fn decode(&self, _: bool, value: &[u8]) -> Result<TaggedValue, ()> {
-        let string = self.bytes_to_string(value)?;
-        Ok(TaggedValue::from(LiteralValue::from(string)))
+    fn decode(&self, _: bool, value: &[u8]) -> Result<TaggedValue, DecodeError> {
+        match self.bytes_to_string(value) {
+            Ok(string) => Ok(TaggedValue::from(LiteralValue::from(string))),
+            Err(()) => Err(DecodeError::new(
+                self,
+                value,
+                "the value is not valid UTF-8",
+            )),
+        }
     }
 }
 
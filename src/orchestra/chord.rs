@@ -18,6 +18,7 @@ use crate::model::yaml::pairs::PairsValue;
 use crate::model::yaml::seq::SeqValue;
 use crate::model::yaml::set::SetValue;
 use crate::model::yaml::str::StrValue;
+use crate::model::yaml::timestamp::TimestampValue;
 
 use crate::model::yamlette::incognitum::IncognitumValue;
 
@@ -27,6 +28,9 @@ use std::borrow::{Borrow, Cow};
 use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
 use std::hash::Hash;
 
+#[cfg(feature = "derive")]
+pub use yamlette_derive::Chord;
+
 pub struct EmptyList;
 pub struct EmptyDict;
 pub struct BinaryValue(pub Vec<u8>);
@@ -40,6 +44,36 @@ pub fn apply_styles(tagged: &mut dyn Tagged, styles: &mut [&mut dyn Style]) {
     }
 }
 
+/// Applies the styles to the common ones and adds them on top of the inherited, the same way
+/// `yamlette_compose!` does for `( # styles => element )`
+pub fn with_styles<F, R>(
+    cs: CommonStyles,
+    vs: &mut [&mut dyn Style],
+    styles: &mut [&mut dyn Style],
+    play: F,
+) -> R
+where
+    F: FnOnce(CommonStyles, &mut [&mut dyn Style]) -> R,
+{
+    let mut cs = cs;
+
+    for style in styles.iter_mut() {
+        style.common_styles_apply(&mut cs);
+    }
+
+    let mut all: Vec<&mut dyn Style> = Vec::with_capacity(vs.len() + styles.len());
+
+    for style in vs.iter_mut() {
+        all.push(&mut **style);
+    }
+
+    for style in styles.iter_mut() {
+        all.push(&mut **style);
+    }
+
+    play(cs, &mut all)
+}
+
 pub trait Chord {
     fn chord_size(&self) -> usize;
 
@@ -278,6 +312,57 @@ impl Chord for BinaryValue {
     }
 }
 
+impl Chord for TimestampValue {
+    fn chord_size(&self) -> usize {
+        1
+    }
+
+    fn play(
+        mut self,
+        orchestra: &Orchestra,
+        level: usize,
+        _alias: Option<Cow<'static, str>>,
+        _cs: CommonStyles,
+        vs: &mut [&mut dyn Style],
+    ) -> Result<(), OrchError> {
+        apply_styles(&mut self, vs);
+
+        orchestra.play(level, TaggedValue::from(self))
+    }
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+macro_rules! timestamp_impl_for {
+    ( $($t:ty),* ) => {
+        $(
+        impl Chord for $t {
+            fn chord_size (&self) -> usize { 1 }
+
+            fn play (self, orchestra: &Orchestra, level: usize, alias: Option<Cow<'static, str>>, cs: CommonStyles, vs: &mut [&mut dyn Style]) -> Result<(), OrchError> {
+                TimestampValue::from (self).play (orchestra, level, alias, cs, vs)
+            }
+        }
+        )*
+    };
+}
+
+#[cfg(feature = "chrono")]
+timestamp_impl_for!(
+    chrono::NaiveDate,
+    chrono::NaiveTime,
+    chrono::NaiveDateTime,
+    chrono::DateTime<chrono::FixedOffset>,
+    chrono::DateTime<chrono::Utc>
+);
+
+#[cfg(feature = "time")]
+timestamp_impl_for!(
+    time::Date,
+    time::Time,
+    time::PrimitiveDateTime,
+    time::OffsetDateTime
+);
+
 impl<T> Chord for Vec<T>
 where
     T: Chord,
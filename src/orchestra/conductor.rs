@@ -2,7 +2,7 @@ use crate::model::renderer::{Node, Renderer};
 use crate::model::{Rope, Schema, TaggedValue};
 
 use crate::orchestra::performer::{Performer, PerformerId, Play};
-use crate::orchestra::OrchError;
+use crate::orchestra::{OrchError, Performance};
 
 use std::borrow::Cow;
 use std::io;
@@ -202,7 +202,7 @@ impl Record {
 }
 
 #[derive(Debug)]
-struct Volume {
+pub struct Volume {
     styles: u8,
     styled: u8,
     init: bool,
@@ -245,6 +245,74 @@ impl Volume {
         self.init
     }
 
+    pub fn is_flat(&self) -> bool {
+        self.flat
+    }
+
+    pub fn bytes_len(&self) -> usize {
+        self.bytes_len
+    }
+
+    pub fn get_tags(&self) -> Option<Arc<Vec<(Cow<'static, str>, Cow<'static, str>)>>> {
+        self.tags.clone()
+    }
+
+    /// Applies the styling hints of the volume
+    pub fn hint(&mut self, hint: Hint) {
+        match hint {
+            Hint::DirectiveYaml(print) => {
+                if print {
+                    self.styles |= VOLUME_STYLE_DIR_YAML;
+                } else {
+                    self.styles &= !VOLUME_STYLE_DIR_YAML;
+                }
+            }
+            Hint::BorderTop(print) => {
+                if print {
+                    self.styles |= VOLUME_STYLE_TOP_BORDER;
+                } else {
+                    self.styles &= !VOLUME_STYLE_TOP_BORDER;
+                }
+            }
+            Hint::BorderBot(print) => {
+                if print {
+                    self.styles |= VOLUME_STYLE_BOT_BORDER;
+                } else {
+                    self.styles |= VOLUME_STYLE_BOT_BORDER_EXPLICIT_NO;
+                }
+            }
+            Hint::DirectiveTags(tags) => {
+                self.tags = Some(Arc::new(tags));
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Drops the records of the volume once it has been rendered
+    pub fn release(&mut self) {
+        self.records = Vec::new();
+        self.legatos = Vec::new();
+    }
+
+    /// Renders the zero level records onto the end of the music
+    pub fn render(&self, renderer: &Renderer, music: &mut Vec<u8>) {
+        let mut count = 0;
+
+        for record in self.records.iter().take(self.size) {
+            if count == self.zero_level_nodes {
+                break;
+            }
+
+            if record.level != 0 {
+                continue;
+            }
+
+            count += 1;
+
+            record.get_rope().render_into_vec(renderer, music);
+        }
+    }
+
     pub fn init(&mut self, mut size: usize, prefer_bot_border: bool) {
         self.legatos = Vec::with_capacity(size);
 
@@ -546,11 +614,12 @@ pub struct Conductor {
     pipe: Receiver<Message>,
     cin: Receiver<(PerformerId, Play)>,
 
-    out: SyncSender<Vec<u8>>,
+    out: SyncSender<Performance>,
 
     performers: [(SyncSender<Gesture>, SyncSender<Signal>, JoinHandle<()>); PERFORMERS_NUMBER],
     msgs: usize,
     buff: Option<Play>,
+    fault: Option<String>,
 
     renderer: Renderer,
 }
@@ -560,8 +629,8 @@ impl Conductor {
         pipe: Receiver<Message>,
         renderer: Renderer,
         schema: S,
-    ) -> io::Result<(JoinHandle<Result<(), OrchError>>, Receiver<Vec<u8>>)> {
-        let (out_sdr, out_rvr): (SyncSender<Vec<u8>>, Receiver<Vec<u8>>) = sync_channel(1);
+    ) -> io::Result<(JoinHandle<Result<(), OrchError>>, Receiver<Performance>)> {
+        let (out_sdr, out_rvr): (SyncSender<Performance>, Receiver<Performance>) = sync_channel(1);
 
         let handle = Builder::new()
             .name("conductor".to_string())
@@ -589,6 +658,7 @@ impl Conductor {
                     renderer: renderer,
 
                     buff: None,
+                    fault: None,
                 })
                 .execute()
             })?;
@@ -610,7 +680,17 @@ impl Conductor {
         result
     }
 
-    fn play_to_volume(play: Play, volumes: &mut Vec<Volume>) {
+    fn play_to_volume(&mut self, play: Play, volumes: &mut Vec<Volume>) {
+        let play = match play {
+            Play::Fault(play, message) => {
+                if self.fault.is_none() {
+                    self.fault = Some(message);
+                }
+                *play
+            }
+            play => play,
+        };
+
         let vol = play.get_coord().vol;
         unsafe {
             volumes.get_unchecked_mut(vol).play(play);
@@ -634,7 +714,7 @@ impl Conductor {
 
         'main_loop: loop {
             if let Some(play) = self.listen_to_play(wait)? {
-                Self::play_to_volume(play, volumes);
+                self.play_to_volume(play, volumes);
                 continue 'main_loop;
             }
 
@@ -694,11 +774,17 @@ impl Conductor {
                     panic!("Someone still plays something")
                 }
 
-                self.do_the_music(music, performer_buffers, volumes)?;
+                let result = if let Some(message) = self.fault.take() {
+                    // no music of the silent plays
+                    music.clear();
+                    self.out.send(Err(OrchError::Error(message)))
+                } else {
+                    self.do_the_music(music, performer_buffers, volumes)?;
 
-                let music = mem::replace(music, Vec::with_capacity(0));
+                    let music = mem::replace(music, Vec::with_capacity(0));
 
-                let result = self.out.send(music);
+                    self.out.send(Ok(music))
+                };
                 if result.is_err() {
                     return Err(OrchError::Error(String::from(
                         "Could not send out the music",
@@ -744,31 +830,7 @@ impl Conductor {
                         Hint::TheEnd => {
                             flush = true;
                         }
-                        Hint::DirectiveYaml(print) => {
-                            if print {
-                                volumes.last_mut().unwrap().styles |= VOLUME_STYLE_DIR_YAML;
-                            } else {
-                                volumes.last_mut().unwrap().styles &= !VOLUME_STYLE_DIR_YAML;
-                            }
-                        }
-                        Hint::BorderTop(print) => {
-                            if print {
-                                volumes.last_mut().unwrap().styles |= VOLUME_STYLE_TOP_BORDER;
-                            } else {
-                                volumes.last_mut().unwrap().styles &= !VOLUME_STYLE_TOP_BORDER;
-                            }
-                        }
-                        Hint::BorderBot(print) => {
-                            if print {
-                                volumes.last_mut().unwrap().styles |= VOLUME_STYLE_BOT_BORDER;
-                            } else {
-                                volumes.last_mut().unwrap().styles |=
-                                    VOLUME_STYLE_BOT_BORDER_EXPLICIT_NO;
-                            }
-                        }
-                        Hint::DirectiveTags(tags) => {
-                            volumes.last_mut().unwrap().tags = Some(Arc::new(tags));
-                        }
+                        hint => volumes.last_mut().unwrap().hint(hint),
                     },
 
                     Message::Value(level, value) => {
@@ -1013,7 +1075,7 @@ impl Conductor {
                             TrySendError::Full(s) => {
                                 sig = s;
                                 if let Some(play) = self.listen_to_play(true)? {
-                                    Self::play_to_volume(play, volumes)
+                                    self.play_to_volume(play, volumes)
                                 }
                             }
                         },
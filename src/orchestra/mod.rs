@@ -1,23 +1,43 @@
 mod conductor;
 mod performer;
+mod soloist;
 
 pub mod chord;
+#[cfg(feature = "serde")]
+pub mod ser;
 
 use self::conductor::{Conductor, Hint, Message};
+use self::soloist::Soloist;
 
 use crate::model::{CommonStyles, Renderer, Schema, TaggedValue};
+use crate::txt::Encoding;
 
 use std::borrow::Cow;
+use std::cell::RefCell;
+use std::error;
+use std::fmt;
 use std::io;
 use std::sync::mpsc::{sync_channel, Receiver, SyncSender};
 use std::thread::JoinHandle;
 
 pub type Music = Vec<u8>;
 
+/// The music or the reason it could not be played
+type Performance = Result<Music, OrchError>;
+
 pub struct Orchestra {
     styles: CommonStyles,
-    pipe: SyncSender<Message>,
-    cond: (JoinHandle<Result<(), OrchError>>, Receiver<Music>),
+    band: Band,
+    encoding: Encoding,
+    bom: bool,
+}
+
+enum Band {
+    Conductor(
+        SyncSender<Message>,
+        (JoinHandle<Result<(), OrchError>>, Receiver<Performance>),
+    ),
+    Soloist(RefCell<Soloist>),
 }
 
 impl Orchestra {
@@ -36,91 +56,182 @@ impl Orchestra {
 
         Ok(Orchestra {
             styles: styles,
-            pipe: sender,
-            cond: cond,
+            band: Band::Conductor(sender, cond),
+            encoding: Encoding::default(),
+            bom: false,
         })
     }
 
+    /// Makes an orchestra playing everything in the caller thread, without spawning
+    /// the conductor and the performers. The music is the same, byte for byte.
+    pub fn solo<S>(schema: S) -> Orchestra
+    where
+        S: Schema + 'static,
+    {
+        Orchestra {
+            styles: schema.get_common_styles(),
+            band: Band::Soloist(RefCell::new(Soloist::new(Box::new(schema), Renderer))),
+            encoding: Encoding::default(),
+            bom: false,
+        }
+    }
+
+    /// Makes an orchestra playing in the caller thread (as the solo one does) and writing
+    /// every volume into the sink as soon as it is complete, so that the whole music
+    /// is never kept in memory. Nothing is written after the first I/O error of the sink
+    /// or the first value that cannot be played, and the error is returned by `listen`.
+    pub fn sink<S, W>(schema: S, sink: W) -> Orchestra
+    where
+        S: Schema + 'static,
+        W: io::Write + 'static,
+    {
+        let styles = schema.get_common_styles();
+        let soloist = Soloist::with_sink(Box::new(schema), Renderer, Box::new(sink));
+
+        Orchestra {
+            styles,
+            band: Band::Soloist(RefCell::new(soloist)),
+            encoding: Encoding::default(),
+            bom: false,
+        }
+    }
+
+    /// Takes the sink back from the orchestra made with `Orchestra::sink`
+    pub fn into_sink<W>(self) -> Option<W>
+    where
+        W: io::Write + 'static,
+    {
+        match self.band {
+            Band::Soloist(soloist) => soloist
+                .into_inner()
+                .into_output()
+                .and_then(|out| out.into_any().downcast::<W>().ok())
+                .map(|sink| *sink),
+            _ => None,
+        }
+    }
+
     pub fn get_styles(&self) -> CommonStyles {
         self.styles
     }
 
+    /// Sets the encoding of the music (UTF-8 by default)
+    pub fn set_encoding(&mut self, encoding: Encoding) {
+        self.encoding = encoding;
+        self.tune();
+    }
+
+    pub fn get_encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    /// Whether the music starts with the byte order mark of its encoding
+    pub fn set_bom(&mut self, bom: bool) {
+        self.bom = bom;
+        self.tune();
+    }
+
     pub fn play(&self, level: usize, value: TaggedValue) -> Result<(), OrchError> {
-        self.pipe
-            .send(Message::Value(level, value))
-            .or_else(|_| Err(OrchError::Error("Conductor has quit already".to_string())))
+        self.send(Message::Value(level, value))
     }
 
     pub fn volume_border_top(&self, print: bool) -> Result<(), OrchError> {
-        self.pipe
-            .send(Message::Hint(Hint::BorderTop(print)))
-            .or_else(|_| Err(OrchError::Error("Conductor has quit already".to_string())))
+        self.send(Message::Hint(Hint::BorderTop(print)))
     }
 
     pub fn volume_border_bot(&self, print: bool) -> Result<(), OrchError> {
-        self.pipe
-            .send(Message::Hint(Hint::BorderBot(print)))
-            .or_else(|_| Err(OrchError::Error("Conductor has quit already".to_string())))
+        self.send(Message::Hint(Hint::BorderBot(print)))
     }
 
     pub fn directive_yaml(&self, print: bool) -> Result<(), OrchError> {
-        self.pipe
-            .send(Message::Hint(Hint::DirectiveYaml(print)))
-            .or_else(|_| Err(OrchError::Error("Conductor has quit already".to_string())))
+        self.send(Message::Hint(Hint::DirectiveYaml(print)))
     }
 
     pub fn directive_tags(
         &self,
         tags: Vec<(Cow<'static, str>, Cow<'static, str>)>,
     ) -> Result<(), OrchError> {
-        self.pipe
-            .send(Message::Hint(Hint::DirectiveTags(tags)))
-            .or_else(|_| Err(OrchError::Error("Conductor has quit already".to_string())))
+        self.send(Message::Hint(Hint::DirectiveTags(tags)))
     }
 
     pub fn volumes(&self, size: usize) -> Result<(), OrchError> {
-        self.pipe
-            .send(Message::Hint(Hint::Volumes(size)))
-            .or_else(|_| Err(OrchError::Error("Conductor has quit already".to_string())))
+        self.send(Message::Hint(Hint::Volumes(size)))
     }
 
     pub fn vol_next(&self) -> Result<(), OrchError> {
-        self.pipe
-            .send(Message::Hint(Hint::VolumeNext))
-            .or_else(|_| Err(OrchError::Error("Conductor has quit already".to_string())))
+        self.send(Message::Hint(Hint::VolumeNext))
     }
 
     pub fn vol_end(&self) -> Result<(), OrchError> {
-        self.pipe
-            .send(Message::Hint(Hint::VolumeEnd))
-            .or_else(|_| Err(OrchError::Error("Conductor has quit already".to_string())))
+        self.send(Message::Hint(Hint::VolumeEnd))
     }
 
     pub fn vol_reserve(&self, size: usize) -> Result<(), OrchError> {
-        self.pipe
-            .send(Message::Hint(Hint::VolumeSize(size)))
-            .or_else(|_| Err(OrchError::Error("Conductor has quit already".to_string())))
+        self.send(Message::Hint(Hint::VolumeSize(size)))
     }
 
     pub fn the_end(&self) -> Result<(), OrchError> {
-        self.pipe
-            .send(Message::Hint(Hint::TheEnd))
-            .or_else(|_| Err(OrchError::Error("Conductor has quit already".to_string())))
+        self.send(Message::Hint(Hint::TheEnd))
     }
 
     pub fn listen(&self) -> Result<Music, OrchError> {
-        match self.cond.1.recv() {
-            Ok(music) => Ok(music),
-            Err(_) => Err(OrchError::Error(String::from("orchestra vanished"))),
+        let music = match self.band {
+            Band::Conductor(_, ref cond) => match cond.1.recv() {
+                Ok(music) => music,
+                Err(_) => Err(OrchError::Error(String::from("orchestra vanished"))),
+            },
+            // the sink has got the music encoded already
+            Band::Soloist(ref soloist) if soloist.borrow().has_sink() => {
+                return soloist.borrow_mut().listen()
+            }
+            Band::Soloist(ref soloist) => soloist.borrow_mut().listen(),
+        }?;
+
+        self.encoding
+            .encode(music, self.bom)
+            .map_err(|error| OrchError::Error(format!("The music cannot be encoded: {}", error)))
+    }
+
+    fn tune(&mut self) {
+        if let Band::Soloist(ref mut soloist) = self.band {
+            soloist.get_mut().set_encoding(self.encoding, self.bom);
+        }
+    }
+
+    fn send(&self, message: Message) -> Result<(), OrchError> {
+        match self.band {
+            Band::Conductor(ref pipe, _) => pipe
+                .send(message)
+                .map_err(|_| OrchError::Error("Conductor has quit already".to_string())),
+            Band::Soloist(ref soloist) => soloist.borrow_mut().take(message),
         }
     }
 }
 
+#[derive(Debug)]
 pub enum OrchError {
     Error(String),
     IoError(io::Error),
 }
 
+impl fmt::Display for OrchError {
+    fn fmt(&self, fmtter: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            OrchError::Error(ref message) => write!(fmtter, "{}", message),
+            OrchError::IoError(ref error) => write!(fmtter, "{}", error),
+        }
+    }
+}
+
+impl error::Error for OrchError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            OrchError::Error(_) => None,
+            OrchError::IoError(ref error) => Some(error),
+        }
+    }
+}
+
 impl From<io::Error> for OrchError {
     fn from(err: io::Error) -> OrchError {
         OrchError::IoError(err)
@@ -23,6 +23,10 @@ pub enum Play {
 
     Chord(Coord, Rope, BytesLength),
 
+    /// The value could not be played, so the play is silent (to keep the volume complete)
+    /// and the orchestra gives the message instead of the music
+    Fault(Box<Play>, String),
+
     Rendered,
 }
 
@@ -32,6 +36,7 @@ impl Play {
             Play::Legato(ref c, _) => c,
             Play::Note(ref c, _, _) => c,
             Play::Chord(ref c, _, _) => c,
+            Play::Fault(ref p, _) => p.get_coord(),
             _ => panic!("no coords in here"),
         }
     }
@@ -149,95 +154,25 @@ where
         &self,
         coord: Coord,
         value: TaggedValue,
-        tags: &Vec<Option<Arc<Vec<(Cow<'static, str>, Cow<'static, str>)>>>>,
+        tags: &[Option<Arc<Vec<(Cow<'static, str>, Cow<'static, str>)>>>],
     ) {
-        if let Some(model) = self.schema.look_up_model(value.get_tag().as_ref()) {
-            if model.is_collection() {
-                let res = self.out.send((self.id, Play::Legato(coord, value)));
-                if res.is_err() {
-                    unimplemented!()
-                };
-            } else {
-                if !model.is_encodable() {
-                    unimplemented!()
-                }
-
-                let encoded = match *unsafe { tags.get_unchecked(coord.vol) } {
-                    Some(ref arc) => model.encode(
-                        &self.renderer,
-                        value,
-                        &mut arc
-                            .as_ref()
-                            .iter()
-                            .chain(self.schema.get_tag_handles().iter()),
-                    ),
-                    None => model.encode(
-                        &self.renderer,
-                        value,
-                        &mut self.schema.get_tag_handles().iter(),
-                    ),
-                };
-
-                if let Ok(rope) = encoded {
-                    let len = if coord.lvl == 0 {
-                        rope.bytes_len(&self.renderer)
-                    } else {
-                        0
-                    };
-                    let res = self.out.send((self.id, Play::Note(coord, rope, len)));
-                    if res.is_err() {
-                        unimplemented!()
-                    };
-                } else {
-                    unimplemented!()
-                }
-            }
-        } else {
+        let play = note(&self.schema, &self.renderer, coord, value, tags);
+        let res = self.out.send((self.id, play));
+        if res.is_err() {
             unimplemented!()
-        }
+        };
     }
 
     fn play_chord(
         &self,
         coord: Coord,
         value: TaggedValue,
-        tags: &Vec<Option<Arc<Vec<(Cow<'static, str>, Cow<'static, str>)>>>>,
-        mut children: Vec<Rope>,
+        tags: &[Option<Arc<Vec<(Cow<'static, str>, Cow<'static, str>)>>>],
+        children: Vec<Rope>,
     ) {
-        if let Some(model) = self.schema.look_up_model(value.get_tag().as_ref()) {
-            if !model.is_collection() {
-                unimplemented!();
-            }
-
-            let rope = match *unsafe { tags.get_unchecked(coord.vol) } {
-                Some(ref arc) => model.compose(
-                    &self.renderer,
-                    value,
-                    &mut arc
-                        .as_ref()
-                        .iter()
-                        .chain(self.schema.get_tag_handles().iter()),
-                    children.as_mut_slice(),
-                ),
-                None => model.compose(
-                    &self.renderer,
-                    value,
-                    &mut self.schema.get_tag_handles().iter(),
-                    children.as_mut_slice(),
-                ),
-            };
-
-            let len = if coord.lvl == 0 {
-                rope.bytes_len(&self.renderer)
-            } else {
-                0
-            };
-            let res = self.out.send((self.id, Play::Chord(coord, rope, len)));
-
-            if res.is_err() {
-                unimplemented!()
-            }
-        } else {
+        let play = chord(&self.schema, &self.renderer, coord, value, tags, children);
+        let res = self.out.send((self.id, play));
+        if res.is_err() {
             unimplemented!()
         }
     }
@@ -246,63 +181,10 @@ where
         &self,
         coord: Coord,
         style: VolumeStyle,
-        tags: &Vec<Option<Arc<Vec<(Cow<'static, str>, Cow<'static, str>)>>>>,
+        tags: &[Option<Arc<Vec<(Cow<'static, str>, Cow<'static, str>)>>>],
     ) {
-        let rope = match style {
-            VolumeStyle::Yaml => {
-                let (maj, min) = self.schema.get_yaml_version();
-
-                let encoding = UTF8; // schema.get_encoding ();
-
-                let node = if maj == 1 && min == 2 {
-                    match encoding.str_to_bytes("%YAML 1.2") {
-                        Ok(s) => Node::StringNewline(EncodedString::from(s)),
-                        Err(s) => Node::StringNewline(EncodedString::from(s)),
-                    }
-                } else if maj == 1 && min == 1 {
-                    match encoding.str_to_bytes("%YAML 1.1") {
-                        Ok(s) => Node::StringNewline(EncodedString::from(s)),
-                        Err(s) => Node::StringNewline(EncodedString::from(s)),
-                    }
-                } else {
-                    Node::StringNewline(EncodedString::from(
-                        encoding.string_to_bytes(format!("%YAML {}.{}", maj, min)),
-                    ))
-                };
-
-                Rope::from(node)
-            }
-
-            VolumeStyle::Tags => {
-                let encoding = UTF8; // schema.get_encoding ();
-
-                match *unsafe { tags.get_unchecked(coord.vol) } {
-                    Some(ref arc) => {
-                        let tags: &Vec<(Cow<'static, str>, Cow<'static, str>)> = arc.as_ref();
-                        let mut nodes: Vec<Node> = Vec::with_capacity(tags.len());
-
-                        for &(ref shortcut, ref handle) in tags {
-                            nodes.push(Node::StringNewline(EncodedString::from(
-                                encoding.string_to_bytes(format!(
-                                    "%TAG {} {}",
-                                    shortcut.as_ref(),
-                                    handle.as_ref()
-                                )),
-                            )));
-                        }
-
-                        Rope::from(nodes)
-                    }
-                    None => Rope::Empty,
-                }
-            }
-
-            VolumeStyle::TopBorder => Rope::from(Node::TripleHyphenNewline),
-            VolumeStyle::BotBorder => Rope::from(Node::TripleDotNewline),
-        };
-
-        let len = rope.bytes_len(&self.renderer);
-        let res = self.out.send((self.id, Play::Note(coord, rope, len)));
+        let play = volume_style(&self.schema, &self.renderer, coord, style, tags);
+        let res = self.out.send((self.id, play));
         if res.is_err() {
             unimplemented!()
         };
@@ -325,3 +207,177 @@ where
         }
     }
 }
+
+fn fault(play: Play, message: String) -> Play {
+    Play::Fault(Box::new(play), message)
+}
+
+pub fn note(
+    schema: &dyn Schema,
+    renderer: &Renderer,
+    coord: Coord,
+    value: TaggedValue,
+    tags: &[Option<Arc<Vec<(Cow<'static, str>, Cow<'static, str>)>>>],
+) -> Play {
+    if let Some(model) = schema.look_up_model(value.get_tag().as_ref()) {
+        if model.is_collection() {
+            Play::Legato(coord, value)
+        } else {
+            if !model.is_encodable() {
+                return fault(
+                    Play::Note(coord, Rope::with_capacity(0), 0),
+                    format!("The model {} is not encodable", model.get_tag()),
+                );
+            }
+
+            let encoded = match *unsafe { tags.get_unchecked(coord.vol) } {
+                Some(ref arc) => model.encode(
+                    renderer,
+                    value,
+                    &mut arc.as_ref().iter().chain(schema.get_tag_handles().iter()),
+                ),
+                None => model.encode(renderer, value, &mut schema.get_tag_handles().iter()),
+            };
+
+            if let Ok(rope) = encoded {
+                let len = if coord.lvl == 0 {
+                    rope.bytes_len(renderer)
+                } else {
+                    0
+                };
+                Play::Note(coord, rope, len)
+            } else {
+                fault(
+                    Play::Note(coord, Rope::with_capacity(0), 0),
+                    format!("The model {} cannot encode the value", model.get_tag()),
+                )
+            }
+        }
+    } else {
+        fault(
+            Play::Note(coord, Rope::with_capacity(0), 0),
+            format!("Could not find appropriate model (tag {})", value.get_tag()),
+        )
+    }
+}
+
+pub fn chord(
+    schema: &dyn Schema,
+    renderer: &Renderer,
+    coord: Coord,
+    value: TaggedValue,
+    tags: &[Option<Arc<Vec<(Cow<'static, str>, Cow<'static, str>)>>>],
+    mut children: Vec<Rope>,
+) -> Play {
+    if let Some(model) = schema.look_up_model(value.get_tag().as_ref()) {
+        if !model.is_collection() {
+            return fault(
+                Play::Chord(coord, Rope::with_capacity(0), 0),
+                format!("The model {} is not a collection", model.get_tag()),
+            );
+        }
+
+        let composed = match *unsafe { tags.get_unchecked(coord.vol) } {
+            Some(ref arc) => model.compose(
+                renderer,
+                value,
+                &mut arc.as_ref().iter().chain(schema.get_tag_handles().iter()),
+                children.as_mut_slice(),
+            ),
+            None => model.compose(
+                renderer,
+                value,
+                &mut schema.get_tag_handles().iter(),
+                children.as_mut_slice(),
+            ),
+        };
+
+        let rope = match composed {
+            Ok(rope) => rope,
+            Err(_) => {
+                return fault(
+                    Play::Chord(coord, Rope::with_capacity(0), 0),
+                    format!("The model {} cannot compose the value", model.get_tag()),
+                )
+            }
+        };
+
+        let len = if coord.lvl == 0 {
+            rope.bytes_len(renderer)
+        } else {
+            0
+        };
+
+        Play::Chord(coord, rope, len)
+    } else {
+        fault(
+            Play::Chord(coord, Rope::with_capacity(0), 0),
+            format!("Could not find appropriate model (tag {})", value.get_tag()),
+        )
+    }
+}
+
+pub fn volume_style(
+    schema: &dyn Schema,
+    renderer: &Renderer,
+    coord: Coord,
+    style: VolumeStyle,
+    tags: &[Option<Arc<Vec<(Cow<'static, str>, Cow<'static, str>)>>>],
+) -> Play {
+    let rope = match style {
+        VolumeStyle::Yaml => {
+            let (maj, min) = schema.get_yaml_version();
+
+            let encoding = UTF8; // schema.get_encoding ();
+
+            let node = if maj == 1 && min == 2 {
+                match encoding.str_to_bytes("%YAML 1.2") {
+                    Ok(s) => Node::StringNewline(EncodedString::from(s)),
+                    Err(s) => Node::StringNewline(EncodedString::from(s)),
+                }
+            } else if maj == 1 && min == 1 {
+                match encoding.str_to_bytes("%YAML 1.1") {
+                    Ok(s) => Node::StringNewline(EncodedString::from(s)),
+                    Err(s) => Node::StringNewline(EncodedString::from(s)),
+                }
+            } else {
+                Node::StringNewline(EncodedString::from(
+                    encoding.string_to_bytes(format!("%YAML {}.{}", maj, min)),
+                ))
+            };
+
+            Rope::from(node)
+        }
+
+        VolumeStyle::Tags => {
+            let encoding = UTF8; // schema.get_encoding ();
+
+            match *unsafe { tags.get_unchecked(coord.vol) } {
+                Some(ref arc) => {
+                    let tags: &Vec<(Cow<'static, str>, Cow<'static, str>)> = arc.as_ref();
+                    let mut nodes: Vec<Node> = Vec::with_capacity(tags.len());
+
+                    for &(ref shortcut, ref handle) in tags {
+                        nodes.push(Node::StringNewline(EncodedString::from(
+                            encoding.string_to_bytes(format!(
+                                "%TAG {} {}",
+                                shortcut.as_ref(),
+                                handle.as_ref()
+                            )),
+                        )));
+                    }
+
+                    Rope::from(nodes)
+                }
+                None => Rope::Empty,
+            }
+        }
+
+        VolumeStyle::TopBorder => Rope::from(Node::TripleHyphenNewline),
+        VolumeStyle::BotBorder => Rope::from(Node::TripleDotNewline),
+    };
+
+    let len = rope.bytes_len(renderer);
+
+    Play::Note(coord, rope, len)
+}
@@ -0,0 +1,479 @@
+extern crate num;
+extern crate serde;
+
+use self::num::{BigInt, BigUint};
+
+use self::serde::ser::{
+    self, Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant,
+    SerializeTuple, SerializeTupleStruct, SerializeTupleVariant,
+};
+
+use crate::model::schema::core::Core;
+use crate::model::style::{CommonStyles, Style};
+
+use crate::orchestra::chord::{BinaryValue, Chord, EmptyDict, EmptyList};
+use crate::orchestra::{OrchError, Orchestra};
+
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::error;
+use std::fmt;
+
+#[derive(Debug)]
+pub struct Error {
+    message: Cow<'static, str>,
+}
+
+impl Error {
+    pub fn new<T>(message: T) -> Error
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        Error {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, fmtter: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmtter, "{}", self.message)
+    }
+}
+
+impl error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::new(msg.to_string())
+    }
+}
+
+impl From<OrchError> for Error {
+    fn from(err: OrchError) -> Error {
+        match err {
+            OrchError::Error(message) => Error::new(message),
+            OrchError::IoError(err) => Error::new(err.to_string()),
+        }
+    }
+}
+
+/// Wraps any `Serialize` value so it can be played as a `Chord`
+pub struct Serde<T>(pub T);
+
+impl<T> Chord for Serde<T>
+where
+    T: Serialize,
+{
+    fn chord_size(&self) -> usize {
+        let size = Cell::new(0);
+
+        self.0
+            .serialize(Serializer::counter(&size, CommonStyles::default(), &mut []))
+            .ok();
+
+        size.get()
+    }
+
+    fn play(
+        self,
+        orchestra: &Orchestra,
+        level: usize,
+        alias: Option<Cow<'static, str>>,
+        cs: CommonStyles,
+        vs: &mut [&mut dyn Style],
+    ) -> Result<(), OrchError> {
+        let size = Cell::new(0);
+
+        self.0
+            .serialize(Serializer::new(orchestra, &size, level, alias, cs, vs))
+            .map_err(|err| OrchError::Error(err.to_string()))
+    }
+}
+
+/// Renders the value as a single YAML document with the Core schema
+pub fn to_string<T>(value: &T) -> Result<String, Error>
+where
+    T: Serialize,
+{
+    let orchestra = Orchestra::new(Core::new()).map_err(OrchError::IoError)?;
+    let chord = Serde(value);
+
+    orchestra.volumes(1)?;
+    orchestra.vol_next()?;
+    orchestra.vol_reserve(chord.chord_size())?;
+
+    let cs = orchestra.get_styles();
+    chord.play(&orchestra, 0, None, cs, &mut [])?;
+
+    orchestra.vol_end()?;
+    orchestra.the_end()?;
+
+    String::from_utf8(orchestra.listen()?).map_err(|err| Error::new(err.to_string()))
+}
+
+pub struct Serializer<'a, 'b, 'c> {
+    orchestra: Option<&'a Orchestra>,
+    size: &'a Cell<usize>,
+    level: usize,
+    alias: Option<Cow<'static, str>>,
+    cs: CommonStyles,
+    vs: &'b mut [&'c mut dyn Style],
+}
+
+impl<'a, 'b, 'c> Serializer<'a, 'b, 'c> {
+    pub fn new(
+        orchestra: &'a Orchestra,
+        size: &'a Cell<usize>,
+        level: usize,
+        alias: Option<Cow<'static, str>>,
+        cs: CommonStyles,
+        vs: &'b mut [&'c mut dyn Style],
+    ) -> Serializer<'a, 'b, 'c> {
+        Serializer {
+            orchestra: Some(orchestra),
+            size,
+            level,
+            alias,
+            cs,
+            vs,
+        }
+    }
+
+    /// A serializer that only counts the nodes, which is what `Chord::chord_size` needs
+    pub fn counter(
+        size: &'a Cell<usize>,
+        cs: CommonStyles,
+        vs: &'b mut [&'c mut dyn Style],
+    ) -> Serializer<'a, 'b, 'c> {
+        Serializer {
+            orchestra: None,
+            size,
+            level: 0,
+            alias: None,
+            cs,
+            vs,
+        }
+    }
+
+    fn note<C: Chord>(&mut self, chord: C) -> Result<(), Error> {
+        self.size.set(self.size.get() + 1);
+
+        match self.orchestra {
+            Some(orchestra) => Ok(chord.play(
+                orchestra,
+                self.level,
+                self.alias.take(),
+                self.cs,
+                &mut *self.vs,
+            )?),
+            None => Ok(()),
+        }
+    }
+
+    fn child(&mut self) -> Serializer<'a, '_, 'c> {
+        Serializer {
+            orchestra: self.orchestra,
+            size: self.size,
+            level: self.level + 1,
+            alias: None,
+            cs: self.cs,
+            vs: &mut *self.vs,
+        }
+    }
+
+    fn variant(mut self, variant: &'static str) -> Result<Serializer<'a, 'b, 'c>, Error> {
+        self.note(EmptyDict)?;
+        self.child().note(variant)?;
+        self.level += 1;
+        Ok(self)
+    }
+}
+
+impl<'a, 'b, 'c> ser::Serializer for Serializer<'a, 'b, 'c> {
+    type Ok = ();
+    type Error = Error;
+
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    fn serialize_bool(mut self, v: bool) -> Result<(), Error> {
+        self.note(v)
+    }
+
+    fn serialize_i8(mut self, v: i8) -> Result<(), Error> {
+        self.note(v)
+    }
+
+    fn serialize_i16(mut self, v: i16) -> Result<(), Error> {
+        self.note(v)
+    }
+
+    fn serialize_i32(mut self, v: i32) -> Result<(), Error> {
+        self.note(v)
+    }
+
+    fn serialize_i64(mut self, v: i64) -> Result<(), Error> {
+        self.note(v)
+    }
+
+    fn serialize_i128(mut self, v: i128) -> Result<(), Error> {
+        self.note(BigInt::from(v))
+    }
+
+    fn serialize_u8(mut self, v: u8) -> Result<(), Error> {
+        self.note(v)
+    }
+
+    fn serialize_u16(mut self, v: u16) -> Result<(), Error> {
+        self.note(v)
+    }
+
+    fn serialize_u32(mut self, v: u32) -> Result<(), Error> {
+        self.note(v)
+    }
+
+    fn serialize_u64(mut self, v: u64) -> Result<(), Error> {
+        self.note(v)
+    }
+
+    fn serialize_u128(mut self, v: u128) -> Result<(), Error> {
+        self.note(BigUint::from(v))
+    }
+
+    fn serialize_f32(mut self, v: f32) -> Result<(), Error> {
+        self.note(v)
+    }
+
+    fn serialize_f64(mut self, v: f64) -> Result<(), Error> {
+        self.note(v)
+    }
+
+    fn serialize_char(mut self, v: char) -> Result<(), Error> {
+        self.note(v)
+    }
+
+    fn serialize_str(mut self, v: &str) -> Result<(), Error> {
+        self.note(String::from(v))
+    }
+
+    fn serialize_bytes(mut self, v: &[u8]) -> Result<(), Error> {
+        self.note(BinaryValue(v.to_vec()))
+    }
+
+    fn serialize_none(mut self) -> Result<(), Error> {
+        self.note(())
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(mut self) -> Result<(), Error> {
+        self.note(())
+    }
+
+    fn serialize_unit_struct(mut self, _name: &'static str) -> Result<(), Error> {
+        self.note(())
+    }
+
+    fn serialize_unit_variant(
+        mut self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<(), Error> {
+        self.note(variant)
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self.variant(variant)?)
+    }
+
+    fn serialize_seq(mut self, _len: Option<usize>) -> Result<Self, Error> {
+        self.note(EmptyList)?;
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<Self, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self, Error> {
+        self.variant(variant)?.serialize_seq(Some(len))
+    }
+
+    fn serialize_map(mut self, _len: Option<usize>) -> Result<Self, Error> {
+        self.note(EmptyDict)?;
+        Ok(self)
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<Self, Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self, Error> {
+        self.variant(variant)?.serialize_map(Some(len))
+    }
+}
+
+impl<'a, 'b, 'c> SerializeSeq for Serializer<'a, 'b, 'c> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self.child())
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<'a, 'b, 'c> SerializeTuple for Serializer<'a, 'b, 'c> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self.child())
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<'a, 'b, 'c> SerializeTupleStruct for Serializer<'a, 'b, 'c> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self.child())
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<'a, 'b, 'c> SerializeTupleVariant for Serializer<'a, 'b, 'c> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self.child())
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<'a, 'b, 'c> SerializeMap for Serializer<'a, 'b, 'c> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        key.serialize(self.child())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self.child())
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<'a, 'b, 'c> SerializeStruct for Serializer<'a, 'b, 'c> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.child().note(key)?;
+        value.serialize(self.child())
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<'a, 'b, 'c> SerializeStructVariant for Serializer<'a, 'b, 'c> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.child().note(key)?;
+        value.serialize(self.child())
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
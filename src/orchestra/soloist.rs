@@ -0,0 +1,331 @@
+use crate::model::{Renderer, Schema, TaggedValue};
+
+use crate::orchestra::conductor::{Coord, Gesture, Hint, Message, Record, Volume};
+use crate::orchestra::performer::{self, Play};
+use crate::orchestra::{Music, OrchError, Performance};
+use crate::txt::Encoding;
+
+use std::any::Any;
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::io;
+use std::sync::Arc;
+
+type Tags = Option<Arc<Vec<(Cow<'static, str>, Cow<'static, str>)>>>;
+
+/// Writer the music is streamed into, which can be taken back afterwards
+pub trait Output: io::Write {
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+}
+
+impl<W> Output for W
+where
+    W: io::Write + 'static,
+{
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+struct Sink {
+    out: Box<dyn Output>,
+    encoding: Encoding,
+    bom: bool,
+    started: bool,
+
+    /// The first failure of the output; nothing is written after that
+    error: Option<io::Error>,
+}
+
+impl Sink {
+    fn write(&mut self, music: Music) {
+        if self.error.is_some() {
+            return;
+        }
+
+        let bom = self.bom && !self.started;
+        self.started = true;
+
+        let result = match self.encoding.encode(music, bom) {
+            Ok(music) => self.out.write_all(&music),
+            Err(error) => Err(io::Error::new(io::ErrorKind::InvalidData, error)),
+        };
+
+        if let Err(error) = result {
+            self.error = Some(error);
+        }
+    }
+
+    fn flush(&mut self) {
+        if self.error.is_none() {
+            if let Err(error) = self.out.flush() {
+                self.error = Some(error);
+            }
+        }
+    }
+}
+
+/// Plays the same score as the conductor with its performers, but all by itself
+/// in the caller thread, so that no threads are spawned and no channels are involved
+pub struct Soloist {
+    schema: Box<dyn Schema>,
+    renderer: Renderer,
+
+    vols_num: usize,
+    volumes: Vec<Volume>,
+    tags: Vec<Tags>,
+
+    music: VecDeque<Performance>,
+    fault: Option<String>,
+
+    sink: Option<Sink>,
+    written: usize,
+}
+
+impl Soloist {
+    pub fn new(schema: Box<dyn Schema>, renderer: Renderer) -> Soloist {
+        Soloist {
+            schema,
+            renderer,
+
+            vols_num: 0,
+            volumes: Vec::new(),
+            tags: Vec::new(),
+
+            music: VecDeque::new(),
+            fault: None,
+
+            sink: None,
+            written: 0,
+        }
+    }
+
+    /// Makes the soloist writing every volume into the output as soon as it's complete
+    pub fn with_sink(schema: Box<dyn Schema>, renderer: Renderer, out: Box<dyn Output>) -> Soloist {
+        let mut soloist = Soloist::new(schema, renderer);
+
+        soloist.sink = Some(Sink {
+            out,
+            encoding: Encoding::default(),
+            bom: false,
+            started: false,
+            error: None,
+        });
+
+        soloist
+    }
+
+    pub fn set_encoding(&mut self, encoding: Encoding, bom: bool) {
+        if let Some(ref mut sink) = self.sink {
+            sink.encoding = encoding;
+            sink.bom = bom;
+        }
+    }
+
+    pub fn has_sink(&self) -> bool {
+        self.sink.is_some()
+    }
+
+    pub fn into_output(self) -> Option<Box<dyn Output>> {
+        self.sink.map(|sink| sink.out)
+    }
+
+    pub fn take(&mut self, message: Message) -> Result<(), OrchError> {
+        match message {
+            Message::Hint(hint) => self.hint(hint),
+            Message::Value(level, value) => self.value(level, value),
+        }
+    }
+
+    pub fn listen(&mut self) -> Result<Music, OrchError> {
+        if let Some(ref mut sink) = self.sink {
+            if let Some(message) = self.fault.take() {
+                return Err(OrchError::Error(message));
+            }
+
+            return match sink.error.take() {
+                Some(error) => Err(OrchError::IoError(error)),
+                None => Ok(Music::new()),
+            };
+        }
+
+        match self.music.pop_front() {
+            Some(music) => music,
+            None => Err(OrchError::Error(String::from("no music has been played"))),
+        }
+    }
+
+    fn hint(&mut self, hint: Hint) -> Result<(), OrchError> {
+        match hint {
+            Hint::Volumes(size) => {
+                self.vols_num = size;
+                self.volumes.reserve_exact(size);
+                self.tags = Vec::with_capacity(size);
+            }
+            Hint::VolumeNext => {
+                let idx = self.volumes.len();
+                self.volumes.push(Volume::new(idx));
+            }
+            Hint::VolumeSize(size) => {
+                let prefer_bot_border = self.volumes.len() < self.vols_num;
+                let volume = self.last_volume()?;
+
+                let tags = volume.get_tags();
+                volume.init(size, prefer_bot_border);
+
+                self.tags.push(tags);
+                self.conduct();
+                self.drain();
+            }
+            Hint::VolumeEnd => (),
+            Hint::TheEnd => self.flush()?,
+            hint => self.last_volume()?.hint(hint),
+        };
+
+        Ok(())
+    }
+
+    fn value(&mut self, level: usize, value: TaggedValue) -> Result<(), OrchError> {
+        let vol = self.volumes.len().saturating_sub(1);
+        let volume = self.last_volume()?;
+
+        let coord = Coord::new(vol, volume.len(), level);
+        volume.push(Record::new(level));
+
+        self.perform(Gesture::Value(coord, value));
+        self.conduct();
+        self.drain();
+
+        Ok(())
+    }
+
+    fn last_volume(&mut self) -> Result<&mut Volume, OrchError> {
+        self.volumes
+            .last_mut()
+            .ok_or_else(|| OrchError::Error(String::from("no volume has been started")))
+    }
+
+    /// Styles and flattens the volumes for as long as there is something to do
+    fn conduct(&mut self) {
+        'main_loop: loop {
+            for idx in 0..self.volumes.len() {
+                let volume = &mut self.volumes[idx];
+
+                if !volume.initialized() {
+                    continue;
+                }
+
+                if let Some(gesture) = volume.style() {
+                    self.perform(gesture);
+                    continue 'main_loop;
+                }
+
+                if volume.is_flat() {
+                    continue;
+                }
+
+                if let Some(gesture) = volume.flatten() {
+                    self.perform(gesture);
+                    continue 'main_loop;
+                }
+            }
+
+            break;
+        }
+    }
+
+    fn perform(&mut self, gesture: Gesture) {
+        let schema: &dyn Schema = &*self.schema;
+
+        let play = match gesture {
+            Gesture::Value(coord, value) => {
+                performer::note(schema, &self.renderer, coord, value, &self.tags)
+            }
+            Gesture::Chord(coord, value, children) => {
+                performer::chord(schema, &self.renderer, coord, value, &self.tags, children)
+            }
+            Gesture::Style(coord, style) => {
+                performer::volume_style(schema, &self.renderer, coord, style, &self.tags)
+            }
+            Gesture::LookForSignal | Gesture::Render(_, _) => return,
+        };
+
+        self.play_to_volume(play);
+    }
+
+    fn play_to_volume(&mut self, play: Play) {
+        let play = match play {
+            Play::Fault(play, message) => {
+                if self.fault.is_none() {
+                    self.fault = Some(message);
+                }
+                *play
+            }
+            play => play,
+        };
+
+        let vol = play.get_coord().vol;
+        self.volumes[vol].play(play);
+    }
+
+    fn flush(&mut self) -> Result<(), OrchError> {
+        self.conduct();
+
+        if self.volumes.iter().any(|volume| !volume.is_flat()) {
+            return Err(OrchError::Error(String::from(
+                "The volumes are incomplete at the end",
+            )));
+        }
+
+        if self.sink.is_some() {
+            self.drain();
+            self.sink.as_mut().unwrap().flush();
+
+            self.volumes = Vec::with_capacity(0);
+            self.written = 0;
+
+            return Ok(());
+        }
+
+        if let Some(message) = self.fault.take() {
+            self.volumes = Vec::with_capacity(0);
+            self.music.push_back(Err(OrchError::Error(message)));
+
+            return Ok(());
+        }
+
+        let length = self.volumes.iter().map(Volume::bytes_len).sum();
+        let mut music: Music = Vec::with_capacity(length);
+
+        for volume in self.volumes.iter() {
+            volume.render(&self.renderer, &mut music);
+        }
+
+        self.volumes = Vec::with_capacity(0);
+        self.music.push_back(Ok(music));
+
+        Ok(())
+    }
+
+    /// Writes the complete volumes into the sink in their order and drops their records.
+    /// Nothing is written after a fault, since the volumes have got silent plays.
+    fn drain(&mut self) {
+        let sink = match self.sink {
+            Some(ref mut sink) if self.fault.is_none() => sink,
+            _ => return,
+        };
+
+        while let Some(volume) = self.volumes.get_mut(self.written) {
+            if !volume.is_flat() {
+                break;
+            }
+
+            let mut music = Music::with_capacity(volume.bytes_len());
+            volume.render(&self.renderer, &mut music);
+            volume.release();
+
+            sink.write(music);
+            self.written += 1;
+        }
+    }
+}
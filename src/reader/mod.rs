@@ -1,4 +1,4 @@
 pub mod tokenizer;
 pub mod reader;
 
-pub use self::reader::{ Reader, ReadError, Id, Block, BlockType, Node, NodeKind };
+pub use self::reader::{ Reader, ReadError, Location, Mark, Span, Id, Block, BlockType, Node, NodeKind };
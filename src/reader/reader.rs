@@ -1,8 +1,11 @@
 extern crate skimmer;
+extern crate stacker;
 
 use self::skimmer::{ Data, Datum, Marker, Read };
 
+use crate::limits::{ Limit, LimitError, Limits };
 use crate::reader::tokenizer::{ self, Token };
+use crate::txt::Encoding;
 
 use std::borrow::Cow;
 use std::error::Error;
@@ -14,6 +17,23 @@ use std::ops::Not;
 
 
 
+/// The stack one level of nesting may take between two checks, which is about the frames
+/// of `read_node_` and the collection around it in a debug build
+const STACK_RED_ZONE: usize = 256 * 1024;
+
+/// The size of the stack segments allocated for deeply nested documents
+const STACK_SEGMENT: usize = 2 * 1024 * 1024;
+
+
+
+/// Reads a level of nesting on the stack left to the thread, or on a new segment of it
+/// allocated on the heap when there is less left than the level may take.
+/// The limits on the depth bound the levels, but not the stack the thread has got.
+#[inline (always)]
+fn nested<T, F: FnOnce () -> T> (read: F) -> T { stacker::maybe_grow (STACK_RED_ZONE, STACK_SEGMENT, read) }
+
+
+
 #[inline (always)]
 fn is<T: BitAnd<Output=T> + Eq + Copy> (state: T, val: T) -> bool { val == state & val }
 
@@ -28,10 +48,71 @@ fn off<T: BitAnd<Output=T> + Not<Output=T> + Eq + Copy> (state: &mut T, val: T)
 
 
 
+/// A place within the source stream
+///
+/// `line` and `column` are 1-based, `column` is counted in characters.
+/// `text` is the line the location points to, without the line break.
+#[derive (Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Location {
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+    pub text: String
+}
+
+
+
+impl fmt::Display for Location {
+    fn fmt (&self, fmtter: &mut fmt::Formatter) -> fmt::Result {
+        write! (fmtter, "line {}, column {}", self.line, self.column)
+    }
+}
+
+
+
+impl From<Mark> for Location {
+    fn from (mark: Mark) -> Location {
+        Location { offset: mark.offset, line: mark.line, column: mark.column, text: String::new () }
+    }
+}
+
+
+
+/// A point between two bytes of the source stream
+///
+/// `line` and `column` are 1-based, `column` is counted in characters.
+#[derive (Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Mark {
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize
+}
+
+
+
+impl fmt::Display for Mark {
+    fn fmt (&self, fmtter: &mut fmt::Formatter) -> fmt::Result {
+        write! (fmtter, "line {}, column {}", self.line, self.column)
+    }
+}
+
+
+
+/// The source range a node has been read from
+#[derive (Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub start: Mark,
+    pub end: Mark
+}
+
+
+
+/// `limit` tells which of the `Limits` has been exceeded, if that's the reason
 #[derive (Debug)]
 pub struct ReadError {
-    pub position: usize,
-    pub description: Cow<'static, str>
+    pub location: Location,
+    pub description: Cow<'static, str>,
+    pub limit: Option<Limit>
 }
 
 
@@ -53,22 +134,31 @@ impl Error for ReadError {
 
 
 impl ReadError {
-    pub fn new<T> (description: T) -> ReadError where T: Into<Cow<'static, str>> { ReadError { description: description.into (), position: 0 } }
+    pub fn new<T> (description: T) -> ReadError where T: Into<Cow<'static, str>> { ReadError { description: description.into (), location: Location::default (), limit: None } }
 
-    pub fn pos (mut self, pos: usize) -> ReadError {
-        self.position = pos;
+    pub fn at (mut self, location: Location) -> ReadError {
+        self.location = location;
         self
     }
 }
 
 
 
+impl From<LimitError> for ReadError {
+    fn from (error: LimitError) -> ReadError {
+        ReadError { description: Cow::from (error.to_string ()), location: Location::default (), limit: Some (error.limit) }
+    }
+}
+
+
+
 
 #[derive (Clone, Debug, Hash)]
 pub struct Id {
     pub level: usize,
     pub parent: usize,
-    pub index: usize
+    pub index: usize,
+    pub span: Span
 }
 
 
@@ -115,8 +205,8 @@ pub enum BlockType<D> {
 
     Node (Node),
 
-    Error (Cow<'static, str>, usize),
-    Warning (Cow<'static, str>, usize),
+    Error (Cow<'static, str>, Location),
+    Warning (Cow<'static, str>, Location),
 
     StreamEnd,
     Datum (D)
@@ -221,6 +311,16 @@ impl<D> Context<D>
     pub fn get_parent_kind (&self) -> Option<ContextKind> {
         self.parent.as_ref ().map (|p| unsafe { (**p).kind })
     }
+
+
+    /// The indentation of the nearest block collection, which an indentation indicator of a block scalar is relative to
+    pub fn get_block_indent (&self) -> Option<usize> {
+        match self.kind {
+            ContextKind::MappingBlock |
+            ContextKind::SequenceBlock => Some (self.indent),
+            _ => self.parent.as_ref ().and_then (|p| unsafe { (**p).get_block_indent () })
+        }
+    }
 }
 
 
@@ -230,6 +330,18 @@ pub struct Reader {
     line: usize,
     cursor: usize,
     position: usize,
+
+    breaks: usize,
+    column: usize,
+    last_cr: bool,
+    line_start: usize,
+    token: Span,
+
+    limits: Limits,
+    document_start: usize,
+    aliases: usize,
+    literal_len: Option<usize>,
+    datum_lens: Vec<usize>,
 }
 
 
@@ -241,25 +353,300 @@ impl Reader {
             line: 0,
             cursor: 0,
             position: 0,
+
+            breaks: 0,
+            column: 0,
+            last_cr: false,
+            line_start: 0,
+            token: Span::default (),
+
+            limits: Limits::default (),
+            document_start: 0,
+            aliases: 0,
+            literal_len: None,
+            datum_lens: Vec::new (),
+        }
+    }
+
+
+    pub fn with_limits (limits: Limits) -> Reader {
+        let mut reader = Reader::new ();
+        reader.set_limits (limits);
+        reader
+    }
+
+
+    /// The limits on the depth, aliases, scalars and size of the documents read after the call
+    pub fn set_limits (&mut self, limits: Limits) {
+        self.limits = limits;
+    }
+
+
+    /// Location of the reader's current position, with its line text (see `line_text`)
+    fn location<D: Datum + 'static, R: Read<Datum=D>> (&self, reader: &mut R) -> Location {
+        Location {
+            offset: self.position,
+            line: self.breaks + 1,
+            column: self.column + 1,
+            text: self.line_text (reader, self.position)
+        }
+    }
+
+
+    /// The line the offset lies at, not further than the reader's current position.
+    /// The passed bytes are taken back from the data they lie in and the rest of the current line is looked up ahead.
+    /// The sources that let go of the passed data (such as `txt::Chunks`) may cut the head short.
+    fn line_text<D: Datum + 'static, R: Read<Datum=D>> (&self, reader: &mut R, offset: usize) -> String {
+        let mut text = Vec::new ();
+        let mut text_start = 0;
+
+        let mut start = 0;
+        let mut index = 0;
+        while start < self.position {
+            let datum = reader.get_datum (index);
+            let len = match datum {
+                Some (ref datum) => datum.len (),
+                None => if let Some (len) = self.datum_lens.get (index) { *len } else { break }
+            };
+
+            match datum {
+                Some (datum) => {
+                    let to = len.min (self.position - start);
+                    text.extend_from_slice (&datum.as_slice ()[.. to]);
+                }
+                None => {
+                    text.clear ();
+                    text_start = start + len;
+                }
+            }
+
+            start += len;
+            index += 1;
+        }
+
+        let mut at = 0;
+        while let Some (byte) = reader.get_byte_at (at) {
+            if byte == b'\n' || byte == b'\r' { break; }
+            text.push (byte);
+            at += 1;
+        }
+
+        if offset < text_start { return String::new (); }
+
+        let offset = (offset - text_start).min (text.len ());
+        let is_break = |byte: &u8| *byte == b'\n' || *byte == b'\r';
+        let head = text[.. offset].iter ().rposition (is_break).map_or (0, |at| at + 1);
+        let tail = text[offset ..].iter ().position (is_break).map_or (text.len (), |at| offset + at);
+
+        String::from_utf8_lossy (&text[head .. tail]).into_owned ()
+    }
+
+
+    /// Zero-width span of the reader's current position
+    fn here (&self) -> Span {
+        let mark = Mark { offset: self.position, line: self.breaks + 1, column: self.column + 1 };
+        Span { start: mark, end: mark }
+    }
+
+
+    /// Keeps the line, the column and the start of the current line up to date with the bytes being passed by.
+    /// Unless the bytes are all blank, remembers their span as the one of the latest token.
+    fn track<D: Datum + 'static, R: Read<Datum=D>> (&mut self, reader: &mut R, len: usize, token: bool) {
+        // the stream may end before `len`, then only the bytes left are passed by
+        let len = if reader.has_long (len) { len } else { (0 .. len).take_while (|at| reader.get_byte_at (*at).is_some ()).count () };
+
+        let bytes = if let Some (bytes) = reader.slice (len) { bytes } else { return };
+
+        let mut span: Option<Span> = None;
+
+        for (idx, byte) in bytes.iter ().enumerate () {
+            let offset = self.position + idx;
+
+            match *byte {
+                b'\n' => {
+                    if !self.last_cr { self.breaks += 1; }
+                    self.last_cr = false;
+                    self.column = 0;
+                    self.line_start = offset + 1;
+                }
+                b'\r' => {
+                    self.breaks += 1;
+                    self.last_cr = true;
+                    self.column = 0;
+                    self.line_start = offset + 1;
+                }
+                b' ' | b'\t' => {
+                    self.last_cr = false;
+                    self.column += 1;
+                }
+                byte => {
+                    self.last_cr = false;
+
+                    let start = Mark { offset: offset, line: self.breaks + 1, column: self.column + 1 };
+                    if byte & 0xC0 != 0x80 { self.column += 1; }
+                    let end = Mark { offset: offset + 1, line: self.breaks + 1, column: self.column + 1 };
+
+                    span = Some (match span {
+                        Some (span) => Span { start: span.start, end: end },
+                        None => Span { start: start, end: end }
+                    });
+                }
+            }
+        }
+
+        if token {
+            if let Some (span) = span { self.token = span; }
         }
     }
 
 
     #[inline (always)]
     fn yield_block<D: Datum + 'static> (&mut self, block: Block<D>, callback: &mut dyn FnMut (Block<D>) -> Result<(), Cow<'static, str>>) -> Result<(), ReadError> {
+        let start = block.id.span.start;
+
+        if let Err (error) = self.check_limits (&block) {
+            return Err (self.exceeded (error, block.id, callback));
+        }
+
         if let Err (error) = callback (block) {
-            Err (ReadError::new (error))
+            let error = ReadError::new (error);
+
+            // the blocks without a span (such as data chunks) have the zero line
+            Err (if start.line > 0 { error.at (Location::from (start)) } else { error })
         } else {
             Ok ( () )
         }
     }
 
 
+    /// Checks the depth of a flow collection before reading into it, since its node is only yielded afterwards
+    fn check_depth<D: Datum + 'static> (&mut self, id: Id, callback: &mut dyn FnMut (Block<D>) -> Result<(), Cow<'static, str>>) -> Result<(), ReadError> {
+        match Limit::Depth.check (&self.limits, id.level) {
+            Ok ( () ) => Ok ( () ),
+            Err (error) => Err (self.exceeded (error, id, callback))
+        }
+    }
+
+
+    /// Reports the exceeded limit to the callback and turns it into the error of the reader
+    fn exceeded<D: Datum + 'static> (&mut self, error: LimitError, id: Id, callback: &mut dyn FnMut (Block<D>) -> Result<(), Cow<'static, str>>) -> ReadError {
+        let start = id.span.start;
+        let error = ReadError::from (error).at (if start.line > 0 { Location::from (start) } else { self.here ().start.into () });
+        callback (Block::new (id, BlockType::Error (error.description.clone (), error.location.clone ()))).ok ();
+        error
+    }
+
+
+    /// Keeps count of what the limits are about, and fails as soon as one of them is exceeded
+    fn check_limits<D: Datum + 'static> (&mut self, block: &Block<D>) -> Result<(), LimitError> {
+        match block.cargo {
+            BlockType::DocStart => {
+                self.document_start = self.position;
+                self.aliases = 0;
+            }
+
+            BlockType::Datum (ref datum) => {
+                let index = block.id.index;
+                if self.datum_lens.len () <= index { self.datum_lens.resize (index + 1, 0); }
+                self.datum_lens[index] = datum.len ();
+            }
+
+            BlockType::Alias (_) => {
+                self.aliases += 1;
+                Limit::Aliases.check (&self.limits, self.aliases)?;
+            }
+
+            BlockType::Node (ref node) => {
+                match node.content {
+                    NodeKind::LiteralBlockOpen => { self.literal_len = Some (0); }
+                    NodeKind::LiteralBlockClose => { self.literal_len = None; }
+                    NodeKind::Scalar (ref marker) => {
+                        let len = self.marker_len (marker);
+                        self.check_scalar_len (len)?;
+                    }
+                    _ => ()
+                }
+            }
+
+            BlockType::Literal (ref marker) => {
+                let len = self.marker_len (marker);
+                self.check_scalar_len (len)?;
+            }
+
+            _ => ()
+        };
+
+        match block.cargo {
+            BlockType::Node (_) | BlockType::BlockMap (..) | BlockType::Literal (_) | BlockType::Alias (_) => {
+                Limit::Depth.check (&self.limits, block.id.level)?;
+            }
+            _ => ()
+        };
+
+        Limit::DocumentSize.check (&self.limits, self.position.saturating_sub (self.document_start))
+    }
+
+
+    /// Literal blocks come in chunks, which add up until the block is closed
+    fn check_scalar_len (&mut self, len: usize) -> Result<(), LimitError> {
+        let len = match self.literal_len {
+            Some (ref mut total) => { *total += len; *total }
+            None => len
+        };
+
+        Limit::ScalarLength.check (&self.limits, len)
+    }
+
+
+    /// The same as `Data::marker_len`, but with the lengths of the data passed by
+    fn marker_len (&self, marker: &Marker) -> usize {
+        let len = |index: usize| self.datum_lens.get (index).cloned ().unwrap_or (0);
+
+        if marker.pos1.0 == marker.pos2.0 {
+            marker.pos2.1 - marker.pos1.1
+        } else {
+            let mut total = len (marker.pos1.0).saturating_sub (marker.pos1.1) + marker.pos2.1;
+            for index in marker.pos1.0 + 1 .. marker.pos2.0 { total += len (index); }
+            total
+        }
+    }
+
+
     pub fn read<D: Datum + 'static, R: Read<Datum=D>> (&mut self, mut reader: R, callback: &mut dyn FnMut (Block<D>) -> Result<(), Cow<'static, str>>) -> Result<(), ReadError> {
         let mut ctx: Context<D> = Context::zero ();
 
-        let mut cur_idx = self.index;
-        let result = self.read_layer (&mut reader, callback, &mut ctx, 0, 0, &mut cur_idx, 0, &mut None, &mut None);
+        let mut head = Vec::with_capacity (4);
+        while head.len () < 4 {
+            match reader.get_byte_at (head.len ()) {
+                Some (byte) => head.push (byte),
+                None => break
+            }
+        }
+
+        let result = match Encoding::detect (&head) {
+            Encoding::UTF8 (_) => {
+                let mut cur_idx = self.index;
+                self.read_layer (&mut reader, callback, &mut ctx, 0, 0, &mut cur_idx, 0, &mut None, &mut None)
+            }
+            encoding => {
+                let idx = self.get_idx ();
+                let span = self.here ();
+                self.yield_error (
+                    &mut reader, callback,
+                    Id { level: 0, parent: 0, index: idx, span },
+                    Cow::from (format! ("The stream is {} encoded and has to be transcoded into UTF-8 first (see txt::Decoder)", encoding.name ()))
+                )
+            }
+        };
+
+        // the errors of the callback are located at the start of their block, with no line text yet
+        let result = result.map_err (|mut error| {
+            if error.location.line > 0 && error.location.text.is_empty () {
+                error.location.text = self.line_text (&mut reader, error.location.offset);
+            }
+            error
+        });
+
         self.yield_stream_end (callback).ok ();
 
         result
@@ -282,6 +669,8 @@ impl Reader {
 
     #[inline (always)]
     fn skip<D: Datum + 'static, R: Read<Datum=D>> (&mut self, reader: &mut R, len: usize, chars: usize) {
+        let comment = reader.get_byte_at (0) == Some (b'#');
+        self.track (reader, len, !comment);
         self.cursor += chars;
         self.position += len;
         reader.skip_long (len);
@@ -293,6 +682,7 @@ impl Reader {
         D: Datum + 'static,
         R: Read<Datum=D>
     {
+        self.track (reader, len, true);
         self.cursor += chars;
         self.position += len;
         let marker = reader.consume_long (len);
@@ -300,15 +690,9 @@ impl Reader {
         {
             let data = ctx.get_data ();
 
-            if marker.pos2.0 > data.amount () {
-                for i in data.amount () .. marker.pos2.0 + 1 {
-                    let datum: D = reader.get_datum (i).unwrap ();
-                    self.yield_block (Block::new (Id { level: 0, parent: 0, index: i }, BlockType::Datum (datum)), callback) ?;
-                    data.push (reader.get_datum (i).unwrap ());
-                }
-            } else if data.amount () == 0 {
-                let datum = reader.get_datum (0).unwrap ();
-                self.yield_block (Block::new (Id { level: 0, parent: 0, index: 0 }, BlockType::Datum (datum.clone ())), callback) ?;
+            for i in data.amount () .. marker.pos2.0 + 1 {
+                let datum: D = reader.get_datum (i).unwrap ();
+                self.yield_block (Block::new (Id { level: 0, parent: 0, index: i, span: self.token }, BlockType::Datum (datum.clone ())), callback) ?;
                 data.push (datum);
             }
         }
@@ -322,14 +706,23 @@ impl Reader {
         self.line = 0;
         self.cursor = 0;
         self.position = 0;
-        self.yield_block (Block::new (Id { level: 0, parent: 0, index: 0 }, BlockType::StreamEnd), callback)
+        self.breaks = 0;
+        self.column = 0;
+        self.last_cr = false;
+        self.line_start = 0;
+        self.token = Span::default ();
+        self.document_start = 0;
+        self.aliases = 0;
+        self.literal_len = None;
+        self.datum_lens.clear ();
+        self.yield_block (Block::new (Id { level: 0, parent: 0, index: 0, span: self.token }, BlockType::StreamEnd), callback)
     }
 
 
     #[inline (always)]
     fn yield_null<D: Datum + 'static> (&mut self, callback: &mut dyn FnMut (Block<D>) -> Result<(), Cow<'static, str>>, level: usize, parent_idx: usize) -> Result<(), ReadError> {
         let idx = self.get_idx ();
-        self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx }, BlockType::Node (Node {
+        self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx, span: self.token }, BlockType::Node (Node {
             anchor: None,
             tag: None,
             content: NodeKind::Null
@@ -338,18 +731,18 @@ impl Reader {
 
 
     #[inline (always)]
-    fn yield_error<D: Datum + 'static> (&mut self, callback: &mut dyn FnMut (Block<D>) -> Result<(), Cow<'static, str>>, id: Id, message: Cow<'static, str>) -> Result<(), ReadError> {
-        let pos = self.position;
-        self.yield_block (Block::new (id, BlockType::Error (message.clone (), pos)), callback) ?;
+    fn yield_error<D: Datum + 'static, R: Read<Datum=D>> (&mut self, reader: &mut R, callback: &mut dyn FnMut (Block<D>) -> Result<(), Cow<'static, str>>, id: Id, message: Cow<'static, str>) -> Result<(), ReadError> {
+        let location = self.location (reader);
+        self.yield_block (Block::new (id, BlockType::Error (message.clone (), location.clone ())), callback) ?;
 
-        Err (ReadError::new (message).pos (pos))
+        Err (ReadError::new (message).at (location))
     }
 
 
     #[inline (always)]
-    fn yield_warning<D: Datum + 'static> (&mut self, callback: &mut dyn FnMut (Block<D>) -> Result<(), Cow<'static, str>>, id: Id, message: Cow<'static, str>) -> Result<(), ReadError> {
-        let pos = self.position;
-        self.yield_block (Block::new (id, BlockType::Warning (message.clone (), pos)), callback)
+    fn yield_warning<D: Datum + 'static, R: Read<Datum=D>> (&mut self, reader: &mut R, callback: &mut dyn FnMut (Block<D>) -> Result<(), Cow<'static, str>>, id: Id, message: Cow<'static, str>) -> Result<(), ReadError> {
+        let location = self.location (reader);
+        self.yield_block (Block::new (id, BlockType::Warning (message, location)), callback)
     }
 
 
@@ -376,7 +769,25 @@ impl Reader {
     }
 
 
+    /// The block mappings nest through here (see `nested`)
     fn read_layer<D: Datum + 'static, R: Read<Datum=D>> (
+        &mut self,
+        reader: &mut R,
+        callback: &mut dyn FnMut (Block<D>) -> Result<(), Cow<'static, str>>,
+        ctx: &mut Context<D>,
+        level: usize,
+        parent_idx: usize,
+        cur_idx: &mut usize,
+        state: u8,
+        anchor: &mut Option<Marker>,
+        tag: &mut Option<Marker>
+    ) -> Result<(), ReadError> {
+        nested (|| self.read_layer_body (reader, callback, ctx, level, parent_idx, cur_idx, state, anchor, tag))
+    }
+
+
+    #[allow (clippy::too_many_arguments)]
+    fn read_layer_body<D: Datum + 'static, R: Read<Datum=D>> (
         &mut self,
         reader: &mut R,
         callback: &mut dyn FnMut (Block<D>) -> Result<(), Cow<'static, str>>,
@@ -430,8 +841,8 @@ impl Reader {
 
                             let idx = self.get_idx ();
                             return self.yield_error (
-                                callback,
-                                Id { level: level, parent: parent_idx, index: idx },
+                                reader, callback,
+                                Id { level: level, parent: parent_idx, index: idx, span: self.token },
                                 Cow::from ("The YAML directive must only be given at most once per document")
                             );
                         }
@@ -455,8 +866,8 @@ impl Reader {
                             let line = self.line;
 
                             self.yield_warning (
-                                callback,
-                                Id { level, parent: parent_idx, index: idx },
+                                reader, callback,
+                                Id { level, parent: parent_idx, index: idx, span: self.token },
                                 Cow::from (format! ("Unknown directive at the line {}", line))
                             )?;
 
@@ -475,13 +886,16 @@ impl Reader {
 
 
                         Token::DocumentStart if not (state, INDENT_PASSED) => {
-                            if indent > 0 { break 'top; }
+                            // the stream layer goes on with the next document, whatever the indentation of the last one
+                            if indent > 0 && propagated { break 'top; }
                             self.skip (reader, len, chars);
                             self.emit_doc_border (callback, level, parent_idx, Token::DocumentEnd)?;
                             document_issued = true;
 
                             self.index = 0; // reset the counter!
                             self.emit_doc_border (callback, level, parent_idx, Token::DocumentStart)?;
+                            off (&mut state, NODE_PASSED | INDENT_DEFINED);
+                            on (&mut state, INDENT_PASSED);
                             prev_indent = self.cursor;
                             *cur_idx = self.index;
                         }
@@ -587,7 +1001,7 @@ impl Reader {
                             let idx = self.get_idx ();
                             *cur_idx = idx;
 
-                            self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx }, BlockType::Node (Node {
+                            self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx, span: self.here () }, BlockType::Node (Node {
                                 anchor: anchor.take (),
                                 tag: tag.take (),
                                 content: NodeKind::Sequence
@@ -605,9 +1019,9 @@ impl Reader {
                             let idx = self.get_idx ();
 
                             self.yield_block (Block::new (
-                                Id { level: level, parent: parent_idx, index: idx },
+                                Id { level: level, parent: parent_idx, index: idx, span: self.token },
                                 BlockType::BlockMap (
-                                    Id { level: level, parent: parent_idx, index: *cur_idx },
+                                    Id { level: level, parent: parent_idx, index: *cur_idx, span: self.token },
                                     anchor.take (),
                                     tag.take ()
                                 )
@@ -626,7 +1040,7 @@ impl Reader {
                             let idx = self.get_idx ();
 
                             self.yield_block (Block::new (
-                                Id { level: level, parent: parent_idx, index: idx },
+                                Id { level: level, parent: parent_idx, index: idx, span: self.token },
                                 BlockType::Node (Node {
                                     anchor: anchor.take (),
                                     tag: tag.take (),
@@ -664,7 +1078,7 @@ impl Reader {
 
                         _ => {
                             let idx = self.get_idx ();
-                            return self.yield_error (callback, Id { level: level, parent: parent_idx, index: idx }, Cow::from ("Unexpected token / 0001"))
+                            return self.yield_error (reader, callback, Id { level: level, parent: parent_idx, index: idx, span: self.token }, Cow::from ("Unexpected token / 0001"))
                         }
                     }
                     break;
@@ -740,7 +1154,7 @@ impl Reader {
                                 let (chunk, _) = lazy_tail.take ().unwrap ();
                                 let idx = self.get_idx ();
                                 self.yield_block (Block::new (
-                                    Id { level: level, parent: parent_idx, index: idx },
+                                    Id { level: level, parent: parent_idx, index: idx, span: self.token },
                                     BlockType::Literal (chunk)
                                 ), callback)?;
                             }
@@ -798,7 +1212,7 @@ impl Reader {
 
                             let idx = self.get_idx ();
                             self.yield_block (Block::new (
-                                Id { level: level, parent: parent_idx, index: idx },
+                                Id { level: level, parent: parent_idx, index: idx, span: self.token },
                                 BlockType::Literal (chunk)
                             ), callback)?;
                             off (&mut state, HUNGRY | KEEPER);
@@ -812,7 +1226,7 @@ impl Reader {
 
                             let idx = self.get_idx ();
                             self.yield_block (Block::new (
-                                Id { level: level, parent: parent_idx, index: idx },
+                                Id { level: level, parent: parent_idx, index: idx, span: self.token },
                                 BlockType::Byte (b'\n', nls)
                             ), callback)?;
                             off (&mut state, HUNGRY | KEEPER);
@@ -833,7 +1247,7 @@ impl Reader {
 
                             let idx = self.get_idx ();
                             self.yield_block (Block::new (
-                                Id { level, parent: parent_idx, index: idx },
+                                Id { level, parent: parent_idx, index: idx, span: self.token },
                                 BlockType::Byte (byte, 1)
                             ), callback)?;
 
@@ -884,7 +1298,7 @@ impl Reader {
                                 let marker = self.consume (ctx, reader, callback, len, 0) ?;
                                 let idx = self.get_idx ();
                                 self.yield_block (Block::new (
-                                    Id { level: level, parent: parent_idx, index: idx },
+                                    Id { level: level, parent: parent_idx, index: idx, span: self.token },
                                     BlockType::Literal (marker)
                                 ), callback)?;
                             }
@@ -924,14 +1338,14 @@ impl Reader {
                 let idx = self.get_idx ();
 
                 self.yield_block (Block::new (
-                    Id { level: level, parent: parent_idx, index: idx },
+                    Id { level: level, parent: parent_idx, index: idx, span: self.token },
                     BlockType::Literal (lazy_nl.take ().unwrap ())
                 ), callback)?;
 
                 if is (state, CHOMP_KEEP) && lazy_tail.is_some () {
                     let idx = self.get_idx ();
                     self.yield_block (Block::new (
-                        Id { level: level, parent: parent_idx, index: idx },
+                        Id { level: level, parent: parent_idx, index: idx, span: self.token },
                         BlockType::Literal (lazy_tail.take ().unwrap ().0)
                     ), callback)?;
                 }
@@ -941,7 +1355,7 @@ impl Reader {
                 if is (state, CHOMP_KEEP) && chars > 0 {
                     let idx = self.get_idx ();
                     self.yield_block (Block::new (
-                        Id { level: level, parent: parent_idx, index: idx },
+                        Id { level: level, parent: parent_idx, index: idx, span: self.token },
                         BlockType::Byte (b'\n', chars)
                     ), callback)?;
                 }
@@ -967,6 +1381,7 @@ impl Reader {
         overtag: &mut Option<Marker>
     ) -> Result<(), ReadError> {
         let mut ctx = Context::new (ctx, ContextKind::ScalarBlock, self.cursor, level);
+        let block_indent = ctx.get_block_indent ().unwrap_or (0);
 
         const CHOMP_STRIP: u8 = 1;
         const CHOMP_KEEP: u8 = 2;
@@ -982,6 +1397,7 @@ impl Reader {
         let mut state: u8 = 0;
 
         let mut idx = 0;
+        let mut head = self.here (); // the block indicator
 
         loop {
             if let Some ( (token, len, chars) ) = if accel.is_none () { tokenizer::get_token (reader) } else { accel.take () } {
@@ -1001,6 +1417,7 @@ impl Reader {
                         Token::GT |
                         Token::Pipe if not (state, HEAD_PASSED) && not (state, TYPE_DEFINED) => {
                             self.skip (reader, len, chars);
+                            head = self.token;
                             on (&mut state, match token { Token::GT => FOLDED, _ => 0 } | TYPE_DEFINED);
                         }
 
@@ -1018,7 +1435,7 @@ impl Reader {
 
                             *cur_idx = idx;
 
-                            self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx }, BlockType::Node (Node {
+                            self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx, span: Span { start: head.start, end: self.token.end } }, BlockType::Node (Node {
                                 anchor: None,
                                 tag: None,
                                 content: NodeKind::LiteralBlockOpen
@@ -1087,7 +1504,7 @@ impl Reader {
 
                         _ if not (state, HEAD_PASSED) => {
                             let idx = self.get_idx ();
-                            return self.yield_error (callback, Id { level: level, parent: parent_idx, index: idx }, Cow::from ("Unexpected token / 0002"))
+                            return self.yield_error (reader, callback, Id { level: level, parent: parent_idx, index: idx, span: self.token }, Cow::from ("Unexpected token / 0002"))
                         }
 
                         _ if not (state, FOLDED) => return self.read_scalar_block_literal (
@@ -1103,7 +1520,7 @@ impl Reader {
                                 else if is (state, CHOMP_KEEP) { 8 }
                                 else { 0 }
                             } else { 0 } | 128,
-                            if is (state, INDENT_DEFINED) { indent } else { default_indent }
+                            if is (state, INDENT_DEFINED) { block_indent + indent } else { default_indent }
                         ).and_then (| () | {
                             let (anchor, tag) = if self.check_next_is_byte (b':', reader, indent, false) {
                                 (anchor, tag)
@@ -1114,7 +1531,7 @@ impl Reader {
                                 )
                             };
 
-                            self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx }, BlockType::Node (Node {
+                            self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx, span: self.token }, BlockType::Node (Node {
                                 anchor: anchor,
                                 tag: tag,
                                 content: NodeKind::LiteralBlockClose
@@ -1134,7 +1551,7 @@ impl Reader {
                                 else if is (state, CHOMP_KEEP) { 8 }
                                 else { 0 }
                             } else { 0 },
-                            if is (state, INDENT_DEFINED) { indent } else { default_indent }
+                            if is (state, INDENT_DEFINED) { block_indent + indent } else { default_indent }
                         ).and_then (| () | {
                             let (anchor, tag) = if self.check_next_is_byte (b':', reader, indent, false) {
                                 (anchor, tag)
@@ -1145,7 +1562,7 @@ impl Reader {
                                 )
                             };
 
-                            self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx }, BlockType::Node (Node {
+                            self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx, span: self.token }, BlockType::Node (Node {
                                 anchor: anchor,
                                 tag: tag,
                                 content: NodeKind::LiteralBlockClose
@@ -1249,8 +1666,8 @@ impl Reader {
                             let idx = self.get_idx ();
 
                             self.yield_block (Block::new (
-                                Id { level: level, parent: parent_idx, index: idx },
-                                BlockType::BlockMap (Id { level: level, parent: parent_idx, index: cur_idx }, None, None)
+                                Id { level: level, parent: parent_idx, index: idx, span: self.token },
+                                BlockType::BlockMap (Id { level: level, parent: parent_idx, index: cur_idx, span: self.token }, None, None)
                             ), callback)?;
 
                             self.read_map_block_implicit (reader, callback, &mut ctx, level + 1, idx, prev_indent, None)?;
@@ -1285,6 +1702,9 @@ impl Reader {
         overanchor: &mut Option<Marker>,
         overtag: &mut Option<Marker>
     ) -> Result<(), ReadError> {
+        let start = self.token.start; // the opening bracket
+        self.check_depth (Id { level: level, parent: parent_idx, index: idx, span: self.token }, callback)?;
+
         let mut ctx = Context::new (ctx, ContextKind::SequenceFlow, self.cursor, level);
 
         const NODE_PASSED: u8 = 1; // Indentation has been passed for the line
@@ -1322,7 +1742,7 @@ impl Reader {
                                 )
                             };
 
-                            self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx }, BlockType::Node (Node {
+                            self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx, span: Span { start: start, end: self.token.end } }, BlockType::Node (Node {
                                 anchor: anchor,
                                 tag: tag,
                                 content: NodeKind::Sequence
@@ -1361,7 +1781,7 @@ impl Reader {
                             let new_idx = self.get_idx ();
 
                             self.yield_block (Block::new (
-                                Id { level: level + 1, parent: idx, index: new_idx },
+                                Id { level: level + 1, parent: idx, index: new_idx, span: self.token },
                                 BlockType::Node (Node {
                                     anchor: None,
                                     tag: None,
@@ -1383,9 +1803,9 @@ impl Reader {
                             let new_idx = self.get_idx ();
 
                             self.yield_block (Block::new (
-                                Id { level: level + 1, parent: idx, index: new_idx },
+                                Id { level: level + 1, parent: idx, index: new_idx, span: self.token },
                                 BlockType::BlockMap (
-                                    Id { level: level + 1, parent: idx, index: cur_idx },
+                                    Id { level: level + 1, parent: idx, index: cur_idx, span: self.token },
                                     None,
                                     None
                                 )
@@ -1415,7 +1835,7 @@ impl Reader {
                             let new_idx = self.get_idx ();
 
                             self.yield_block (Block::new (
-                                Id { level: level + 1, parent: idx, index: new_idx },
+                                Id { level: level + 1, parent: idx, index: new_idx, span: self.token },
                                 BlockType::Node (Node {
                                     anchor: None,
                                     tag: None,
@@ -1553,9 +1973,9 @@ impl Reader {
                                             let idx = self.get_idx ();
 
                                             self.yield_block (Block::new (
-                                                Id { level: level, parent: parent_idx, index: idx },
+                                                Id { level: level, parent: parent_idx, index: idx, span: self.token },
                                                 BlockType::BlockMap (
-                                                    Id { level: level, parent: parent_idx, index: last_key_idx },
+                                                    Id { level: level, parent: parent_idx, index: last_key_idx, span: self.token },
                                                     None,
                                                     None
                                                 )
@@ -1651,9 +2071,9 @@ impl Reader {
                                             let idx = self.get_idx ();
 
                                             self.yield_block (Block::new (
-                                                Id { level: level, parent: parent_idx, index: idx },
+                                                Id { level: level, parent: parent_idx, index: idx, span: self.token },
                                                 BlockType::BlockMap (
-                                                    Id { level: level, parent: parent_idx, index: last_val_idx },
+                                                    Id { level: level, parent: parent_idx, index: last_val_idx, span: self.token },
                                                     None,
                                                     None
                                                 )
@@ -1673,7 +2093,7 @@ impl Reader {
 
                         _ => {
                             let idx = self.get_idx ();
-                            return self.yield_error (callback, Id { level: level, parent: parent_idx, index: idx }, Cow::from ("Unexpected token / 0003"))
+                            return self.yield_error (reader, callback, Id { level: level, parent: parent_idx, index: idx, span: self.token }, Cow::from ("Unexpected token / 0003"))
                         }
                     }
 
@@ -1700,6 +2120,9 @@ impl Reader {
         overanchor: &mut Option<Marker>,
         overtag: &mut Option<Marker>
     ) -> Result<(), ReadError> {
+        let start = self.token.start; // the opening brace
+        self.check_depth (Id { level: level, parent: parent_idx, index: idx, span: self.token }, callback)?;
+
         let mut ctx = Context::new (ctx, ContextKind::MappingFlow, self.cursor, level);
 
         const QST_PASSED: u8 = 1;
@@ -1742,7 +2165,7 @@ impl Reader {
                             )
                         };
 
-                        self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx }, BlockType::Node (Node {
+                        self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx, span: Span { start: start, end: self.token.end } }, BlockType::Node (Node {
                             anchor: anchor,
                             tag: tag,
                             content: NodeKind::Mapping
@@ -1816,7 +2239,7 @@ impl Reader {
         tokenizer::get_token (reader)
             .ok_or_else (|| {
                 let idx = self.get_idx ();
-                self.yield_error (callback, Id { level: level, parent: parent_idx, index: idx }, Cow::from ("Unexpected end of the document while parse %YAML directive")).unwrap_err ()
+                self.yield_error (reader, callback, Id { level: level, parent: parent_idx, index: idx, span: self.token }, Cow::from ("Unexpected end of the document while parse %YAML directive")).unwrap_err ()
             })
             .and_then (|(token, len, chars)| {
                 match token {
@@ -1825,22 +2248,22 @@ impl Reader {
                         tokenizer::get_token (reader)
                             .ok_or_else (|| {
                                 let idx = self.get_idx ();
-                                self.yield_error (callback, Id { level: level, parent: parent_idx, index: idx }, Cow::from ("Cannot read the version part of the %YAML directive")).unwrap_err ()
+                                self.yield_error (reader, callback, Id { level: level, parent: parent_idx, index: idx, span: self.token }, Cow::from ("Cannot read the version part of the %YAML directive")).unwrap_err ()
                             })
                     }
                     _ => {
                         let idx = self.get_idx ();
-                        Err (self.yield_error (callback, Id { level: level, parent: parent_idx, index: idx }, Cow::from ("Any %YAML directive should be followed by some space characters")).unwrap_err ())
+                        Err (self.yield_error (reader, callback, Id { level: level, parent: parent_idx, index: idx, span: self.token }, Cow::from ("Any %YAML directive should be followed by some space characters")).unwrap_err ())
                     }
                 }
             }).and_then (|(_, len, chars)| {
                 let marker = self.consume (ctx, reader, callback, len, chars) ?;
 
-                self.check_yaml_version (ctx, callback, level, parent_idx, &marker)
+                self.check_yaml_version (ctx, reader, callback, level, parent_idx, &marker)
                     .and_then (|ver| {
                         let idx = self.get_idx ();
                         self.yield_block (Block::new (
-                            Id { level: level, parent: parent_idx, index: idx },
+                            Id { level: level, parent: parent_idx, index: idx, span: self.token },
                             BlockType::DirectiveYaml (ver)
                         ), callback)
                     })
@@ -1848,7 +2271,7 @@ impl Reader {
     }
 
 
-    fn check_yaml_version<D: Datum + 'static> (&mut self, ctx: &mut Context<D>, callback: &mut dyn FnMut (Block<D>) -> Result<(), Cow<'static, str>>, level: usize, parent_idx: usize, marker: &Marker) -> Result<(u8, u8), ReadError> {
+    fn check_yaml_version<D: Datum + 'static, R: Read<Datum=D>> (&mut self, ctx: &mut Context<D>, reader: &mut R, callback: &mut dyn FnMut (Block<D>) -> Result<(), Cow<'static, str>>, level: usize, parent_idx: usize, marker: &Marker) -> Result<(u8, u8), ReadError> {
         enum R {
             Err (Cow<'static, str>),
             Warn (Cow<'static, str>, (u8, u8))
@@ -1890,11 +2313,11 @@ impl Reader {
         match result {
             R::Err (msg) => {
                 let idx = self.get_idx ();
-                Err (self.yield_error (callback, Id { level: level, parent: parent_idx, index: idx }, msg).unwrap_err ())
+                Err (self.yield_error (reader, callback, Id { level: level, parent: parent_idx, index: idx, span: self.token }, msg).unwrap_err ())
             },
             R::Warn (msg, res) => {
                 let idx = self.get_idx ();
-                self.yield_warning (callback, Id { level: level, parent: parent_idx, index: idx }, msg) ?;
+                self.yield_warning (reader, callback, Id { level: level, parent: parent_idx, index: idx, span: self.token }, msg) ?;
                 Ok (res)
             }
         }
@@ -1905,7 +2328,7 @@ impl Reader {
         tokenizer::get_token (reader)
             .ok_or_else (|| {
                 let idx = self.get_idx ();
-                self.yield_error (callback, Id { level: level, parent: parent_idx, index: idx }, Cow::from ("Unexpected end of the document while parse %TAG directive")).unwrap_err ()
+                self.yield_error (reader, callback, Id { level: level, parent: parent_idx, index: idx, span: self.token }, Cow::from ("Unexpected end of the document while parse %TAG directive")).unwrap_err ()
             })
             .and_then (|(token, len, chars)| {
                 match token {
@@ -1915,8 +2338,8 @@ impl Reader {
                             .ok_or_else (|| {
                                 let idx = self.get_idx ();
                                 self.yield_error (
-                                    callback,
-                                    Id { level: level, parent: parent_idx, index: idx },
+                                    reader, callback,
+                                    Id { level: level, parent: parent_idx, index: idx, span: self.token },
                                     Cow::from ("Cannot read the handle part of a %TAG directive")
                                 ).unwrap_err ()
                             })
@@ -1924,8 +2347,8 @@ impl Reader {
                     _ => {
                         let idx = self.get_idx ();
                         Err (self.yield_error (
-                            callback,
-                            Id { level: level, parent: parent_idx, index: idx },
+                            reader, callback,
+                            Id { level: level, parent: parent_idx, index: idx, span: self.token },
                             Cow::from ("%TAG directive should be followed by some space characters")
                         ).unwrap_err ())
                     }
@@ -1937,8 +2360,8 @@ impl Reader {
                     _ => {
                         let idx = self.get_idx ();
                         return Err (self.yield_error (
-                            callback,
-                            Id { level: level, parent: parent_idx, index: idx },
+                            reader, callback,
+                            Id { level: level, parent: parent_idx, index: idx, span: self.token },
                             Cow::from ("Handle part of a tag must have the format of a tag handle")
                         ).unwrap_err ())
                     }
@@ -1952,8 +2375,8 @@ impl Reader {
                     .ok_or_else (|| {
                         let idx = self.get_idx ();
                         self.yield_error (
-                            callback,
-                            Id { level: level, parent: parent_idx, index: idx },
+                            reader, callback,
+                            Id { level: level, parent: parent_idx, index: idx, span: self.token },
                             Cow::from ("Cannot read the prefix part of a %TAG directive")
                         ).unwrap_err ()
                     }).and_then (|(token, len, chars)| {
@@ -1962,8 +2385,8 @@ impl Reader {
                             _ => {
                                 let idx = self.get_idx ();
                                 return Err (self.yield_error (
-                                    callback,
-                                    Id { level: level, parent: parent_idx, index: idx },
+                                    reader, callback,
+                                    Id { level: level, parent: parent_idx, index: idx, span: self.token },
                                     Cow::from ("%TAG handle should be followed by some space characters")
                                 ).unwrap_err ());
                             }
@@ -1973,8 +2396,8 @@ impl Reader {
                             .ok_or_else (|| {
                                 let idx = self.get_idx ();
                                 self.yield_error (
-                                    callback,
-                                    Id { level: level, parent: parent_idx, index: idx },
+                                    reader, callback,
+                                    Id { level: level, parent: parent_idx, index: idx, span: self.token },
                                     Cow::from ("Cannot read the prefix part of a %TAG directive")
                                 ).unwrap_err ()
                             })
@@ -1990,8 +2413,8 @@ impl Reader {
                                     _ => {
                                         let idx = self.get_idx ();
                                         return Err (self.yield_error (
-                                            callback,
-                                            Id { level: level, parent: parent_idx, index: idx },
+                                            reader, callback,
+                                            Id { level: level, parent: parent_idx, index: idx, span: self.token },
                                             Cow::from ("Prefix part of a tag must have the format of a tag handle or uri")
                                         ).unwrap_err ())
                                     }
@@ -2002,7 +2425,7 @@ impl Reader {
 
                                 let idx = self.get_idx ();
                                 self.yield_block (Block::new (
-                                    Id { level: level, parent: parent_idx, index: idx },
+                                    Id { level: level, parent: parent_idx, index: idx, span: self.token },
                                     BlockType::DirectiveTag ( (handle, prefix) )
                                 ), callback)
                             })
@@ -2015,7 +2438,7 @@ impl Reader {
     fn emit_doc_border<D: Datum + 'static> (&mut self, callback: &mut dyn FnMut (Block<D>) -> Result<(), Cow<'static, str>>, level: usize, parent_idx: usize, token: Token) -> Result<(), ReadError> {
         let idx = self.get_idx ();
         self.yield_block (Block::new (
-            Id { level: level, parent: parent_idx, index: idx },
+            Id { level: level, parent: parent_idx, index: idx, span: self.token },
             match token { Token::DocumentStart => BlockType::DocStart, _ => BlockType::DocEnd }
         ), callback)
     }
@@ -2031,15 +2454,15 @@ impl Reader {
                 _ => {
                     let idx = self.get_idx ();
                     return self.yield_error (
-                        callback,
-                        Id { level: level, parent: parent_idx, index: idx },
+                        reader, callback,
+                        Id { level: level, parent: parent_idx, index: idx, span: self.token },
                         Cow::from ("Unexpected token (expected was '-')")
                     )
                 }
             }
         } else {
             let idx = self.get_idx ();
-            return self.yield_error (callback, Id { level: level, parent: parent_idx, index: idx }, Cow::from (format! ("Unexpected end of the document ({}:{})", file! (), line! ())))
+            return self.yield_error (reader, callback, Id { level: level, parent: parent_idx, index: idx, span: self.token }, Cow::from (format! ("Unexpected end of the document ({}:{})", file! (), line! ())))
         }
 
         'top: loop {
@@ -2151,7 +2574,28 @@ impl Reader {
     }
 
 
+    /// The flow and the block sequences nest through here (see `nested`)
     fn read_node_<D: Datum + 'static, R: Read<Datum=D>> (
+        &mut self,
+        reader: &mut R,
+        callback: &mut dyn FnMut (Block<D>) -> Result<(), Cow<'static, str>>,
+        ctx: &mut Context<D>,
+        indent: usize,
+        level: usize,
+        parent_idx: usize,
+        cur_idx: &mut usize,
+        accel: Option<(Token, usize, usize)>,
+        overanchor: &mut Option<Marker>,
+        overtag: &mut Option<Marker>,
+        flow: bool,
+        map_block_val: bool,
+    ) -> Result<(), ReadError> {
+        nested (|| self.read_node_body (reader, callback, ctx, indent, level, parent_idx, cur_idx, accel, overanchor, overtag, flow, map_block_val))
+    }
+
+
+    #[allow (clippy::too_many_arguments)]
+    fn read_node_body<D: Datum + 'static, R: Read<Datum=D>> (
         &mut self,
         reader: &mut R,
         callback: &mut dyn FnMut (Block<D>) -> Result<(), Cow<'static, str>>,
@@ -2264,7 +2708,7 @@ impl Reader {
 
                                         let idx = self.get_idx ();
                                         self.yield_block (Block::new (
-                                            Id { level: level + 1, parent: flow_idx, index: idx },
+                                            Id { level: level + 1, parent: flow_idx, index: idx, span: self.token },
                                             BlockType::Literal (chunk)
                                         ), callback)?;
                                     },
@@ -2274,7 +2718,7 @@ impl Reader {
                                 let marker = self.consume (ctx, reader, callback, len, chars) ?;
                                 let idx = self.get_idx ();
                                 self.yield_block (Block::new (
-                                    Id { level: level + 1, parent: flow_idx, index: idx },
+                                    Id { level: level + 1, parent: flow_idx, index: idx, span: self.token },
                                     BlockType::Literal (marker)
                                 ), callback)?;
                                 *cur_idx = idx;
@@ -2346,7 +2790,7 @@ impl Reader {
 
                                         let idx = self.get_idx ();
                                         self.yield_block (Block::new (
-                                            Id { level: level + 1, parent: flow_idx, index: idx },
+                                            Id { level: level + 1, parent: flow_idx, index: idx, span: self.token },
                                             BlockType::Literal (chunk)
                                         ), callback)?;
                                     },
@@ -2355,7 +2799,7 @@ impl Reader {
 
                                 let idx = self.get_idx ();
                                 self.yield_block (Block::new (
-                                    Id { level: level + 1, parent: flow_idx, index: idx },
+                                    Id { level: level + 1, parent: flow_idx, index: idx, span: self.token },
                                     BlockType::Byte (b' ', 1)
                                 ), callback)?;
                                 *cur_idx = idx;
@@ -2455,7 +2899,7 @@ impl Reader {
 
                                     let idx = self.get_idx ();
                                     self.yield_block (Block::new (
-                                        Id { level: level + 1, parent: flow_idx, index: idx },
+                                        Id { level: level + 1, parent: flow_idx, index: idx, span: self.token },
                                         BlockType::Literal (chunk)
                                     ), callback)?;
                                 },
@@ -2468,7 +2912,7 @@ impl Reader {
                                     let marker = self.consume (ctx, reader, callback, len, chars) ?;
                                     let idx = self.get_idx ();
                                     self.yield_block (Block::new (
-                                        Id { level: level + 1, parent: flow_idx, index: idx },
+                                        Id { level: level + 1, parent: flow_idx, index: idx, span: self.token },
                                         BlockType::Literal (marker)
                                     ), callback)?;
                                     *cur_idx = idx;
@@ -2480,7 +2924,7 @@ impl Reader {
                                     let marker = self.consume (ctx, reader, callback, len, chars) ?;
                                     let idx = self.get_idx ();
                                     self.yield_block (Block::new (
-                                        Id { level: level + 1, parent: flow_idx, index: idx },
+                                        Id { level: level + 1, parent: flow_idx, index: idx, span: self.token },
                                         BlockType::Literal (marker)
                                     ), callback)?;
                                     *cur_idx = idx;
@@ -2494,7 +2938,7 @@ impl Reader {
 
                                     let idx = self.get_idx ();
                                     self.yield_block (Block::new (
-                                        Id { level: level + 1, parent: flow_idx, index: idx },
+                                        Id { level: level + 1, parent: flow_idx, index: idx, span: self.token },
                                         BlockType::Literal (marker)
                                     ), callback)?;
                                     *cur_idx = idx;
@@ -2507,7 +2951,7 @@ impl Reader {
                                     if self.cursor == 0 { // 9.03 - we couldn't spare a space
                                         let idx = self.get_idx ();
                                         self.yield_block (Block::new (
-                                            Id { level: level + 1, parent: flow_idx, index: idx },
+                                            Id { level: level + 1, parent: flow_idx, index: idx, span: self.token },
                                             BlockType::Byte (b' ', 1)
                                         ), callback)?;
                                         *cur_idx = idx;
@@ -2520,17 +2964,22 @@ impl Reader {
                                         }
                                         _ => self.consume (ctx, reader, callback, len, chars) ?
                                     };
-                                    
+
+                                    let blen = ctx.get_data ().marker_len (&chunk);
                                     self.rtrim (ctx, &mut chunk);
+                                    let alen = ctx.get_data ().marker_len (&chunk);
 
                                     let idx = self.get_idx ();
                                     self.yield_block (Block::new (
-                                        Id { level: level + 1, parent: flow_idx, index: idx },
+                                        Id { level: level + 1, parent: flow_idx, index: idx, span: self.token },
                                         BlockType::Literal (chunk)
                                     ), callback)?;
                                     *cur_idx = idx;
 
                                     off (&mut state, NEWLINE_PASSED | AFTER_SPACE /*| INDENT_PASSED*/);
+
+                                    // the trailing spaces let a comment follow
+                                    if blen != alen { on (&mut state, AFTER_SPACE); }
                                 }
                             }
                         }
@@ -2542,8 +2991,8 @@ impl Reader {
                         Token::ReservedCommercialAt => {
                             let idx = self.get_idx ();
                             self.yield_error (
-                                callback,
-                                Id { level: level, parent: parent_idx, index: idx },
+                                reader, callback,
+                                Id { level: level, parent: parent_idx, index: idx, span: self.token },
                                 Cow::from ("@ character is reserved and may not be used to start a plain scalar")
                             )?;
                         }
@@ -2552,8 +3001,8 @@ impl Reader {
                         Token::ReservedGraveAccent => {
                             let idx = self.get_idx ();
                             self.yield_error (
-                                callback,
-                                Id { level: level, parent: parent_idx, index: idx },
+                                reader, callback,
+                                Id { level: level, parent: parent_idx, index: idx, span: self.token },
                                 Cow::from ("` character is reserved and may not be used to start a plain scalar")
                             )?;
                         }
@@ -2609,7 +3058,7 @@ impl Reader {
                                     let idx = self.get_idx ();
                                     *cur_idx = idx;
 
-                                    self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx }, BlockType::Node (Node {
+                                    self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx, span: self.token }, BlockType::Node (Node {
                                         anchor: if anchor.is_none () { overanchor.take () } else { anchor.take () },
                                         tag: if tag.is_none () { overtag.take () } else { tag.take () },
                                         content: NodeKind::Sequence
@@ -2643,7 +3092,7 @@ impl Reader {
                                         )
                                     };
 
-                                    return self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx }, BlockType::Node (Node {
+                                    return self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx, span: self.token }, BlockType::Node (Node {
                                         anchor: anchor,
                                         tag: tag,
                                         content: NodeKind::Null
@@ -2666,7 +3115,7 @@ impl Reader {
                                     )
                                 };
 
-                                return self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx }, BlockType::Node (Node {
+                                return self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx, span: self.token }, BlockType::Node (Node {
                                     anchor: anchor,
                                     tag: tag,
                                     content: NodeKind::Null
@@ -2723,7 +3172,7 @@ impl Reader {
                                         let idx = self.get_idx ();
                                         *cur_idx = idx;
 
-                                        self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx }, BlockType::Node (Node {
+                                        self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx, span: self.here () }, BlockType::Node (Node {
                                             anchor: if anchor.is_none () { overanchor.take () } else { anchor.take () },
                                             tag: if tag.is_none () { overtag.take () } else { tag.take () },
                                             content: NodeKind::Sequence
@@ -2757,7 +3206,7 @@ impl Reader {
                                         )
                                     };
 
-                                    return self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx }, BlockType::Node (Node {
+                                    return self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx, span: self.token }, BlockType::Node (Node {
                                         anchor: anchor,
                                         tag: tag,
                                         content: NodeKind::Null
@@ -2780,7 +3229,7 @@ impl Reader {
                                         )
                                 };
 
-                                return self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx }, BlockType::Node (Node {
+                                return self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx, span: self.token }, BlockType::Node (Node {
                                     anchor: anchor,
                                     tag: tag,
                                     content: NodeKind::Null
@@ -2791,11 +3240,12 @@ impl Reader {
 
                         Token::Alias if anchor.is_none () && tag.is_none () => {
                             self.skip (reader, 1, 0);
+                            let start = self.token.start; // the asterisk
                             let marker = self.consume (ctx, reader, callback, len - 1, chars) ?;
 
                             let idx = self.get_idx ();
                             self.yield_block (Block::new (
-                                Id { level: level, parent: parent_idx, index: idx },
+                                Id { level: level, parent: parent_idx, index: idx, span: Span { start: start, end: self.token.end } },
                                 BlockType::Alias (marker)
                             ), callback)?;
 
@@ -2819,7 +3269,7 @@ impl Reader {
                         Token::Dash => {
                             let idx = self.get_idx ();
 
-                            self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx }, BlockType::Node (Node {
+                            self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx, span: self.here () }, BlockType::Node (Node {
                                 anchor: if anchor.is_none () { overanchor.take () } else { anchor.take () },
                                 tag: if tag.is_none () { overtag.take () } else { tag.take () },
                                 content: NodeKind::Sequence
@@ -2836,7 +3286,7 @@ impl Reader {
                             *cur_idx = self.index;
 
                             self.yield_block (Block::new (
-                                Id { level: level, parent: parent_idx, index: idx },
+                                Id { level: level, parent: parent_idx, index: idx, span: self.token },
                                 BlockType::Node (Node {
                                     anchor: if anchor.is_none () { overanchor.take () } else { anchor.take () },
                                     tag: if tag.is_none () { overtag.take () } else { tag.take () },
@@ -2879,7 +3329,7 @@ impl Reader {
                                 )
                             };
 
-                            return self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx }, BlockType::Node (Node {
+                            return self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx, span: self.token }, BlockType::Node (Node {
                                 anchor: anchor,
                                 tag: tag,
                                 content: NodeKind::Scalar (marker)
@@ -2943,7 +3393,7 @@ impl Reader {
 
                             if blen != alen { on (&mut state, AFTER_SPACE); }
 
-                            flow_opt = Some (Block::new (Id { level: level, parent: parent_idx, index: flow_idx }, BlockType::Node (Node {
+                            flow_opt = Some (Block::new (Id { level: level, parent: parent_idx, index: flow_idx, span: self.token }, BlockType::Node (Node {
                                 anchor: None,
                                 tag: None,
                                 content: NodeKind::Scalar (marker)
@@ -2966,7 +3416,7 @@ impl Reader {
 
                             if blen != alen { on (&mut state, AFTER_SPACE); }
 
-                            flow_opt = Some (Block::new (Id { level: level, parent: parent_idx, index: flow_idx }, BlockType::Node (Node {
+                            flow_opt = Some (Block::new (Id { level: level, parent: parent_idx, index: flow_idx, span: self.token }, BlockType::Node (Node {
                                 anchor: None,
                                 tag: None,
                                 content: NodeKind::Scalar (marker)
@@ -2991,7 +3441,7 @@ impl Reader {
                                 )
                             };
 
-                            return self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx }, BlockType::Node (Node {
+                            return self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx, span: self.token }, BlockType::Node (Node {
                                 anchor: anchor,
                                 tag: tag,
                                 content: NodeKind::Null
@@ -3002,8 +3452,8 @@ impl Reader {
                         _ => {
                             let idx = self.get_idx ();
                             return Err (self.yield_error (
-                                callback,
-                                Id { level: level, parent: parent_idx, index: idx },
+                                reader, callback,
+                                Id { level: level, parent: parent_idx, index: idx, span: self.token },
                                 Cow::from (format! (r"Unexpected token ({}:{})", file! (), line! ()))
                             ).unwrap_err ())
                         }
@@ -3025,7 +3475,7 @@ impl Reader {
                         )
                     };
 
-                    return self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx }, BlockType::Node (Node {
+                    return self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx, span: self.token }, BlockType::Node (Node {
                         anchor: anchor,
                         tag: tag,
                         content: NodeKind::Null
@@ -3102,7 +3552,7 @@ impl Reader {
                         )
                     };
 
-                    return self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx }, BlockType::Node (Node {
+                    return self.yield_block (Block::new (Id { level: level, parent: parent_idx, index: idx, span: self.token }, BlockType::Node (Node {
                         anchor: anchor,
                         tag: tag,
                         content: NodeKind::Null
@@ -3286,13 +3736,57 @@ mod tests {
 
             if let BlockType::Error (msg, pos) = block.cargo {
                 assert_eq! (expected_message, msg);
-                assert_eq! (5, pos);
+                assert_eq! (5, pos.offset);
             } else { assert! (false, "Unexpected result") }
         } else { assert! (false, "Unexpected result") }
     }
 
 
 
+    #[test]
+    fn test_error_location () {
+        let src = "# \u{e9}\r\n%YAML 1.2\r\n%YAML 1.2\r\n---\r\n";
+        let (sender, receiver) = channel ();
+
+        let mut reader = Reader::new ();
+        let err = reader.read (
+            SliceReader::new (src.as_bytes ()),
+            &mut |block| { if let Err (_) = sender.send (block) { Err (Cow::from ("Cannot yield a block")) } else { Ok ( () ) } }
+        ).err ().unwrap ();
+
+        let expected = Location { offset: 17, line: 3, column: 1, text: String::from ("%YAML 1.2") };
+
+        assert_eq! (expected, err.location);
+        assert_eq! ("line 3, column 1", format! ("{}", err.location));
+
+        let mut found = false;
+        while let Ok (block) = receiver.try_recv () {
+            if let BlockType::Error (_, location) = block.cargo {
+                assert_eq! (expected, location);
+                found = true;
+            }
+        }
+        assert! (found, "No error block yielded");
+
+
+        let src = "# \u{e9}\r%YAML 1.9";
+
+        let mut reader = Reader::new ();
+        let err = reader.read (SliceReader::new (src.as_bytes ()), &mut |_| Ok ( () )).err ().unwrap ();
+
+        assert_eq! (Location { offset: 14, line: 2, column: 10, text: String::from ("%YAML 1.9") }, err.location);
+
+
+        let src = "top:\n  key: { a: 1, b: 2 ] }\n";
+
+        let mut reader = Reader::new ();
+        let err = reader.read (SliceReader::new (src.as_bytes ()), &mut |_| Ok ( () )).err ().unwrap ();
+
+        assert_eq! (Location { offset: 25, line: 2, column: 21, text: String::from ("  key: { a: 1, b: 2 ] }") }, err.location);
+    }
+
+
+
     #[test]
     fn test_directive_yaml_error_2 () {
         let expected_message = r"Any %YAML directive should be followed by some space characters";
@@ -3317,7 +3811,7 @@ mod tests {
 
             if let BlockType::Error (msg, pos) = block.cargo {
                 assert_eq! (expected_message, msg);
-                assert_eq! (5, pos);
+                assert_eq! (5, pos.offset);
             } else { assert! (false, "Unexpected result") }
         } else { assert! (false, "Unexpected result") }
     }
@@ -3348,7 +3842,7 @@ mod tests {
 
             if let BlockType::Error (msg, pos) = block.cargo {
                 assert_eq! (expected_message, msg);
-                assert_eq! (6, pos);
+                assert_eq! (6, pos.offset);
             } else { assert! (false, "Unexpected result") }
         } else { assert! (false, "Unexpected result") }
     }
@@ -3387,7 +3881,7 @@ mod tests {
 
             if let BlockType::Error (msg, pos) = block.cargo {
                 assert_eq! (expected_message, msg);
-                assert_eq! (7, pos);
+                assert_eq! (7, pos.offset);
             } else { assert! (false, "Unexpected result") }
         } else { assert! (false, "Unexpected result") }
     }
@@ -3426,7 +3920,7 @@ mod tests {
 
             if let BlockType::Error (msg, pos) = block.cargo {
                 assert_eq! (expected_message, msg);
-                assert_eq! (7, pos);
+                assert_eq! (7, pos.offset);
             } else { assert! (false, "Unexpected result") }
         } else { assert! (false, "Unexpected result") }
     }
@@ -3466,7 +3960,7 @@ mod tests {
 
             if let BlockType::Error (msg, pos) = block.cargo {
                 assert_eq! (expected_message, msg);
-                assert_eq! (10, pos);
+                assert_eq! (10, pos.offset);
             } else { assert! (false, "Unexpected result") }
         } else { assert! (false, "Unexpected result") }
     }
@@ -3506,7 +4000,7 @@ mod tests {
 
             if let BlockType::Error (msg, pos) = block.cargo {
                 assert_eq! (expected_message, msg);
-                assert_eq! (9, pos);
+                assert_eq! (9, pos.offset);
             } else { assert! (false, "Unexpected result") }
         } else { assert! (false, "Unexpected result") }
     }
@@ -3546,7 +4040,7 @@ mod tests {
 
             if let BlockType::Error (msg, pos) = block.cargo {
                 assert_eq! (expected_message, msg);
-                assert_eq! (9, pos);
+                assert_eq! (9, pos.offset);
             } else { assert! (false, "Unexpected result") }
         } else { assert! (false, "Unexpected result") }
     }
@@ -3584,7 +4078,7 @@ mod tests {
             
             if let BlockType::Warning (msg, pos) = block.cargo {
                 assert_eq! (expected_message, msg);
-                assert_eq! (10, pos);
+                assert_eq! (10, pos.offset);
             } else { assert! (false, "Unexpected result") }
         } else { assert! (false, "Unexpected result") }
 
@@ -3635,7 +4129,7 @@ mod tests {
 
             if let BlockType::Warning (msg, pos) = block.cargo {
                 assert_eq! (expected_message, msg);
-                assert_eq! (9, pos);
+                assert_eq! (9, pos.offset);
             } else { assert! (false, "Unexpected result") }
         } else { assert! (false, "Unexpected result") }
 
@@ -3677,7 +4171,7 @@ mod tests {
 
             if let BlockType::Error (msg, pos) = block.cargo {
                 assert_eq! (expected_message, msg);
-                assert_eq! (4, pos);
+                assert_eq! (4, pos.offset);
             } else { assert! (false, "Unexpected result") }
         } else { assert! (false, "Unexpected result") }
     }
@@ -3709,7 +4203,7 @@ mod tests {
 
             if let BlockType::Error (msg, pos) = block.cargo {
                 assert_eq! (expected_message, msg);
-                assert_eq! (4, pos);
+                assert_eq! (4, pos.offset);
             } else { assert! (false, "Unexpected result") }
         } else { assert! (false, "Unexpected result") }
     }
@@ -3741,7 +4235,7 @@ mod tests {
 
             if let BlockType::Error (msg, pos) = block.cargo {
                 assert_eq! (expected_message, msg);
-                assert_eq! (5, pos);
+                assert_eq! (5, pos.offset);
             } else { assert! (false, "Unexpected result") }
         } else { assert! (false, "Unexpected result") }
     }
@@ -3773,7 +4267,7 @@ mod tests {
 
             if let BlockType::Error (msg, pos) = block.cargo {
                 assert_eq! (expected_message, msg);
-                assert_eq! (5, pos);
+                assert_eq! (5, pos.offset);
             } else { assert! (false, "Unexpected result") }
         } else { assert! (false, "Unexpected result") }
     }
@@ -3814,7 +4308,7 @@ mod tests {
 
             if let BlockType::Error (msg, pos) = block.cargo {
                 assert_eq! (expected_message, msg);
-                assert_eq! (10, pos);
+                assert_eq! (10, pos.offset);
             } else { assert! (false, "Unexpected result") }
         } else { assert! (false, "Unexpected result") }
     }
@@ -3855,7 +4349,7 @@ mod tests {
 
             if let BlockType::Error (msg, pos) = block.cargo {
                 assert_eq! (expected_message, msg);
-                assert_eq! (11, pos);
+                assert_eq! (11, pos.offset);
             } else { assert! (false, "Unexpected result") }
         } else { assert! (false, "Unexpected result") }
     }
@@ -3896,7 +4390,7 @@ mod tests {
 
             if let BlockType::Error (msg, pos) = block.cargo {
                 assert_eq! (expected_message, msg);
-                assert_eq! (11, pos);
+                assert_eq! (11, pos.offset);
             } else { assert! (false, "Unexpected result") }
         } else { assert! (false, "Unexpected result") }
     }
@@ -3952,4 +4446,57 @@ mod tests {
             if let BlockType::DocStart = block.cargo { } else { assert! (false, "Unexpected result") }
         } else { assert! (false, "Unexpected result") }
     }
+
+
+
+    fn documents (src: &'static str) -> Vec<String> {
+        let mut data = Data::with_capacity (16);
+        let mut cargos = Vec::new ();
+
+        let result = Reader::new ().read (
+            SliceReader::new (src.as_bytes ()),
+            &mut |block| {
+                match block.cargo {
+                    BlockType::Datum (datum) => data.push (datum),
+                    BlockType::DocStart => cargos.push (String::from ("---")),
+                    BlockType::DocEnd => cargos.push (String::from ("...")),
+                    BlockType::Node (Node { content: NodeKind::Scalar (marker), .. }) => cargos.push (String::from_utf8_lossy (data.chunk (&marker).as_slice ()).into_owned ()),
+                    BlockType::Node (Node { content: NodeKind::Mapping, .. }) => cargos.push (String::from ("{}")),
+                    BlockType::Node (Node { content: NodeKind::Sequence, .. }) => cargos.push (String::from ("[]")),
+                    BlockType::Node (Node { content: NodeKind::Null, .. }) => cargos.push (String::from ("~")),
+                    BlockType::Error (message, _) => cargos.push (message.into_owned ()),
+                    _ => ()
+                };
+                Ok ( () )
+            }
+        );
+
+        if let Err (error) = result { cargos.push (format! ("{}", error)); }
+
+        cargos
+    }
+
+
+
+    #[test]
+    fn test_document_start_after_scalar_root () {
+        assert_eq! (documents ("--- first\n--- second\n"), vec! ["---", "first", "...", "---", "second", "..."]);
+    }
+
+
+
+    #[test]
+    fn test_document_start_after_flow_root () {
+        assert_eq! (documents ("--- [1]\n--- 2\n"), vec! ["---", "1", "[]", "...", "---", "2", "..."]);
+        assert_eq! (documents ("--- { c: 3 }\n---\nlast\n"), vec! ["---", "c", "3", "{}", "...", "---", "last", "..."]);
+    }
+
+
+
+    #[test]
+    fn test_document_start_after_nested_block () {
+        // the indented layers give way to the stream one
+        assert_eq! (documents ("a:\n  - 1\n--- b\n"), vec! ["---", "a", "[]", "1", "...", "---", "b", "..."]);
+        assert_eq! (documents ("- a:\n    b\n---\nc\n"), vec! ["---", "[]", "a", "b", "...", "---", "c", "..."]);
+    }
 }
@@ -3,7 +3,7 @@ extern crate skimmer;
 use self::skimmer::{Chunk, Data, Datum, Marker};
 
 use crate::model::yamlette::literal::{self, Literal};
-use crate::model::{Model, Schema, TaggedValue};
+use crate::model::{DecodeError, Model, Schema, TaggedValue};
 use crate::reader::{Block, BlockType, Id, NodeKind};
 use crate::sage::conveyor::Clue;
 use crate::sage::YamlVersion;
@@ -163,7 +163,16 @@ where
         }
     }
 
-    fn decode(&self, model: &dyn Model, explicit: bool, value: &[u8]) -> Result<TaggedValue, ()> {
+    fn decode(
+        &self,
+        model: &dyn Model,
+        explicit: bool,
+        value: &[u8],
+    ) -> Result<TaggedValue, DecodeError> {
+        if !model.is_decodable() {
+            return Err(DecodeError::new(model, value, "the model is not decodable"));
+        }
+
         match self.yaml_version {
             YamlVersion::V1x1 => model.decode11(explicit, value),
             YamlVersion::V1x2 => model.decode(explicit, value),
@@ -488,8 +497,10 @@ where
         let model =
             self.read_model(tag, &block_id, |m, _| !m.is_collection() && m.has_default())?;
 
-        let node = Node::Scalar(model.get_default());
-        let response = Response::Node(block_id, anchor, node);
+        let response = match model.get_default() {
+            Ok(value) => Response::Node(block_id, anchor, Node::Scalar(value)),
+            Err(error) => Response::Error(block_id, Cow::from(error.to_string())),
+        };
 
         self.out.send((self.idx, Clue::Response(response))).unwrap();
 
@@ -507,7 +518,7 @@ where
         let anchor: Option<String> = self.read_anchor(&block_id, model_literal, anchor)?;
         let tag: Option<String> = self.read_tag(&block_id, model_literal, tag)?;
 
-        let mut decoded: Result<TaggedValue, ()> = Err(());
+        let mut decoded: Option<TaggedValue> = None;
 
         let chunk = match marker {
             Ok(marker) => self.data.chunk(&marker),
@@ -541,38 +552,57 @@ where
         };
 
         let model: Option<(&dyn Model, bool)> = {
-            let empty = String::with_capacity(0);
-            let tag: &String = if let Some(ref tag) = tag { tag } else { &empty };
+            if let Some(ref tag) = tag {
+                self.lookup_model(tag, |m, e| {
+                    if !m.is_decodable() {
+                        return false;
+                    }
+                    decoded = self.decode(m, e, chunk.as_slice()).ok();
+                    decoded.is_some()
+                })
+            } else {
+                decoded = match self.yaml_version {
+                    YamlVersion::V1x1 => self.schema.try_decodable_models_11(chunk.as_slice()),
+                    YamlVersion::V1x2 => self.schema.try_decodable_models(chunk.as_slice()),
+                };
 
-            self.lookup_model(&tag, |m, e| {
-                if !m.is_decodable() {
-                    return false;
-                }
-                decoded = self.decode(m, e, chunk.as_slice());
-                decoded.is_ok()
-            })
+                None
+            }
         };
 
-        let node = if decoded.is_ok() {
-            Node::Scalar(decoded.unwrap())
+        let node = if let Some(decoded) = decoded {
+            Node::Scalar(decoded)
         } else {
             match model {
-                Some((model, explicit)) => {
-                    Node::Scalar(self.decode(model, explicit, chunk.as_slice())?)
-                }
+                Some((model, explicit)) => match self.decode(model, explicit, chunk.as_slice()) {
+                    Ok(value) => Node::Scalar(value),
+                    Err(error) => {
+                        self.out
+                            .send((
+                                self.idx,
+                                Clue::Response(Response::Error(
+                                    block_id,
+                                    Cow::from(error.to_string()),
+                                )),
+                            ))
+                            .unwrap();
+                        return Err(());
+                    }
+                },
                 None => {
-                    let mut meta: Result<TaggedValue, ()> = Err(());
+                    let mut meta: Option<TaggedValue> = None;
 
                     if let Some(m) = self.schema.get_metamodel() {
                         let empty = String::with_capacity(0);
                         let tag: &String = if let Some(ref tag) = tag { tag } else { &empty };
 
-                        meta =
-                            m.meta_init(anchor.clone(), self.resolve_tag(&tag), chunk.as_slice());
+                        meta = m
+                            .meta_init(anchor.clone(), self.resolve_tag(&tag), chunk.as_slice())
+                            .ok();
                     }
 
                     match meta {
-                        Err(_) => {
+                        None => {
                             self.out
                                 .send((
                                     self.idx,
@@ -591,7 +621,7 @@ where
                             return Err(());
                         }
 
-                        Ok(tagged_value) => Node::Scalar(tagged_value),
+                        Some(tagged_value) => Node::Scalar(tagged_value),
                     }
                 }
             }
@@ -3,7 +3,7 @@ extern crate skimmer;
 use self::skimmer::{Datum, Marker};
 
 use crate::model::Schema;
-use crate::reader::{Block, BlockType, Node, NodeKind};
+use crate::reader::{Block, BlockType, Id, Node, NodeKind};
 use crate::sage::ant::{self, Ant, Message, Request, Response, Signal};
 use crate::sage::{Idea, SageError, YamlVersion};
 
@@ -89,13 +89,14 @@ where
                     }
                 }
 
+                let version = YamlVersion::from(schema.get_yaml_version());
                 let schema = Arc::new(schema);
 
                 Ant::run(
                     0,
                     to_me.clone(),
                     schema.clone(),
-                    YamlVersion::V1x2,
+                    version,
                     atag_handles.clone(),
                 )
                 .or_else(|err| Err(SageError::IoError(err)))
@@ -104,7 +105,7 @@ where
                         1,
                         to_me.clone(),
                         schema.clone(),
-                        YamlVersion::V1x2,
+                        version,
                         atag_handles.clone(),
                     )
                     .or_else(|err| {
@@ -116,7 +117,7 @@ where
                             2,
                             to_me.clone(),
                             schema.clone(),
-                            YamlVersion::V1x2,
+                            version,
                             atag_handles.clone(),
                         )
                         .or_else(|err| {
@@ -137,7 +138,7 @@ where
 
                                 buff: None,
 
-                                yaml_version: YamlVersion::V1x2,
+                                yaml_version: version,
 
                                 tag_handles: atag_handles,
                                 // _schema: PhantomData,
@@ -163,13 +164,16 @@ where
 
         let mut datum: Option<D> = None;
 
-        let mut buf_literal_block: Option<(usize, Vec<Result<Marker, (u8, usize)>>)> = None;
+        let mut buf_literal_block: Option<(Id, Vec<Result<Marker, (u8, usize)>>)> = None;
 
         'top: loop {
             if let Some(msg) = if self.buff.is_some() {
                 Some(self.buff.take().unwrap())
             } else if flush_ants && self.msgs > 0 {
-                Some(self.cin.recv().unwrap().1) // TODO: do not panic, do SageError
+                match self.cin.recv() {
+                    Ok((_, msg)) => Some(msg),
+                    Err(_) => return Err(SageError::Error(Cow::from("abandoned sage"))),
+                }
             } else {
                 match self.ex_cin.try_recv() {
                     Err(TryRecvError::Empty) => match self.cin.try_recv() {
@@ -188,10 +192,10 @@ where
                 match msg {
                     Clue::Terminate => break 'top,
 
-                    Clue::Version(ver) => match ver {
-                        YamlVersion::V1x1 => self.set_version((1, 1))?,
-                        YamlVersion::V1x2 => self.set_version((1, 2))?,
-                    },
+                    Clue::Version(ver) => {
+                        self.yaml_version = ver;
+                        _conveyor_signal!(self, Signal::Version(ver))?;
+                    }
 
                     Clue::Response(result) => {
                         self.msgs -= 1;
@@ -251,13 +255,7 @@ where
 
                 if dusk {
                     dusk = false;
-                    self.think(Idea::Dusk)?;
-                    _conveyor_signal!(self, Signal::Reset)?;
-                    _conveyor_signal!(self, Signal::Version(self.yaml_version))?;
-
-                    for i in 0..self.tag_handles.len() {
-                        _conveyor_signal!(self, Signal::TagHandle(self.tag_handles[i].clone()))?;
-                    }
+                    self.dusk()?;
                 }
 
                 if job_is_done {
@@ -303,7 +301,7 @@ where
                         dusk = true;
                     }
 
-                    BlockType::DocEnd => self.think(Idea::Dusk)?,
+                    BlockType::DocEnd => self.dusk()?,
 
                     BlockType::Error(message, position) => {
                         self.think(Idea::ReadError(block.id, position, message))?
@@ -318,14 +316,17 @@ where
                         tag: _,
                         content: NodeKind::LiteralBlockOpen,
                     }) => {
-                        buf_literal_block = Some((block.id.index, Vec::with_capacity(32)));
+                        buf_literal_block = Some((block.id, Vec::with_capacity(32)));
                     }
 
                     BlockType::Literal(..) if buf_literal_block.is_some() => {
                         if let BlockType::Literal(chunk) = block.cargo {
-                            if let Some((idx, ref mut vec)) = buf_literal_block {
-                                if idx != block.id.parent {
-                                    panic!("Unexpected literal!")
+                            if let Some((ref mut open, ref mut vec)) = buf_literal_block {
+                                if open.index != block.id.parent {
+                                    return Err(SageError::Error(Cow::from("Unexpected literal")));
+                                }
+                                if block.id.span.end.offset > open.span.end.offset {
+                                    open.span.end = block.id.span.end;
                                 }
                                 vec.push(Ok(chunk));
                             }
@@ -334,9 +335,9 @@ where
 
                     BlockType::Byte(..) if buf_literal_block.is_some() => {
                         if let BlockType::Byte(byte, amount) = block.cargo {
-                            if let Some((idx, ref mut vec)) = buf_literal_block {
-                                if idx != block.id.parent {
-                                    panic!("Unexpected literal!")
+                            if let Some((ref open, ref mut vec)) = buf_literal_block {
+                                if open.index != block.id.parent {
+                                    return Err(SageError::Error(Cow::from("Unexpected literal")));
                                 }
                                 vec.push(Err((byte, amount)));
                             }
@@ -348,11 +349,18 @@ where
                         tag,
                         content: NodeKind::LiteralBlockClose,
                     }) => {
-                        let (idx, vec) = buf_literal_block.take().unwrap();
-                        if idx != block.id.index {
-                            panic!("Unexpected literal block!")
+                        let (open, vec) = match buf_literal_block.take() {
+                            Some((open, vec)) if open.index == block.id.index => (open, vec),
+                            _ => {
+                                return Err(SageError::Error(Cow::from("Unexpected literal block")))
+                            }
+                        };
+                        let mut id = block.id;
+                        id.span.start = open.span.start;
+                        if open.span.end.offset > id.span.end.offset {
+                            id.span.end = open.span.end;
                         }
-                        self.convey_request(Request::ReadLiteralBlock(block.id, anchor, tag, vec))?;
+                        self.convey_request(Request::ReadLiteralBlock(id, anchor, tag, vec))?;
                     }
 
                     BlockType::Alias(..)
@@ -378,6 +386,20 @@ where
         Ok(())
     }
 
+    /// Ends the document and resets the ants, so that the `%YAML` directive of the document
+    /// does not stay in force for the next ones
+    fn dusk(&mut self) -> Result<(), SageError> {
+        self.think(Idea::Dusk)?;
+        _conveyor_signal!(self, Signal::Reset)?;
+        _conveyor_signal!(self, Signal::Version(self.yaml_version))?;
+
+        for i in 0..self.tag_handles.len() {
+            _conveyor_signal!(self, Signal::TagHandle(self.tag_handles[i].clone()))?;
+        }
+
+        Ok(())
+    }
+
     fn set_version(&mut self, version: (u8, u8)) -> Result<(), SageError> {
         if version.0 != 1 {
             return Err(SageError::Error(Cow::from(format!(
@@ -386,13 +408,7 @@ where
             ))));
         }
 
-        let ver = if version.1 == 1 {
-            YamlVersion::V1x1
-        } else {
-            YamlVersion::V1x2
-        };
-
-        _conveyor_signal!(self, Signal::Version(ver))?;
+        _conveyor_signal!(self, Signal::Version(YamlVersion::from(version)))?;
 
         Ok(())
     }
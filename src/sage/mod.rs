@@ -8,10 +8,12 @@ use self::conveyor::{Clue, Conveyor};
 use self::skimmer::data::Datum;
 
 use crate::model::schema::Schema;
-use crate::model::TaggedValue;
-use crate::reader::{Block, Id};
+use crate::model::{DecodeError, TaggedValue};
+use crate::reader::{Block, Id, Location};
 
 use std::borrow::Cow;
+use std::error;
+use std::fmt;
 use std::io;
 use std::marker::PhantomData;
 use std::ops::Deref;
@@ -42,6 +44,7 @@ where
         })
     }
 
+    /// Sets the version of the documents with no `%YAML` directive (`Schema::get_yaml_version` by default)
     pub fn set_yaml_version(&self, version: YamlVersion) -> Result<(), SageError> {
         self.conv
             .1
@@ -73,9 +76,43 @@ where
     }
 }
 
+#[derive(Debug)]
 pub enum SageError {
     Error(Cow<'static, str>),
     IoError(io::Error),
+    DecodeError(DecodeError),
+}
+
+impl fmt::Display for SageError {
+    fn fmt(&self, fmtter: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SageError::Error(ref message) => write!(fmtter, "{}", message),
+            SageError::IoError(ref error) => write!(fmtter, "{}", error),
+            SageError::DecodeError(ref error) => write!(fmtter, "{}", error),
+        }
+    }
+}
+
+impl error::Error for SageError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            SageError::Error(_) => None,
+            SageError::IoError(ref error) => Some(error),
+            SageError::DecodeError(ref error) => Some(error),
+        }
+    }
+}
+
+impl From<io::Error> for SageError {
+    fn from(error: io::Error) -> SageError {
+        SageError::IoError(error)
+    }
+}
+
+impl From<DecodeError> for SageError {
+    fn from(error: DecodeError) -> SageError {
+        SageError::DecodeError(error)
+    }
 }
 
 #[derive(Debug)]
@@ -96,8 +133,8 @@ pub enum Idea {
     NodeScalar(Id, Option<String>, TaggedValue),
     NodeLiteral(Id, Option<String>, String),
 
-    ReadError(Id, usize, Cow<'static, str>),
-    ReadWarning(Id, usize, Cow<'static, str>),
+    ReadError(Id, Location, Cow<'static, str>),
+    ReadWarning(Id, Location, Cow<'static, str>),
 }
 
 #[derive(Eq, PartialEq, Clone, Copy, Hash, Debug)]
@@ -105,3 +142,15 @@ pub enum YamlVersion {
     V1x1,
     V1x2,
 }
+
+impl From<(u8, u8)> for YamlVersion {
+    /// The version of the `%YAML` directive or `Schema::get_yaml_version`,
+    /// where anything but 1.1 is read by the rules of 1.2
+    fn from(version: (u8, u8)) -> YamlVersion {
+        if version == (1, 1) {
+            YamlVersion::V1x1
+        } else {
+            YamlVersion::V1x2
+        }
+    }
+}
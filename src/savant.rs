@@ -1,23 +1,24 @@
 extern crate skimmer;
 
 use crate::model::schema::Schema;
-use crate::model::{Model, TaggedValue};
+use crate::model::{DecodeError, Model, TaggedValue};
 
 use crate::sage::{Idea, SageError, YamlVersion};
 
 use self::skimmer::{Chunk, Data, Datum, Marker};
 
-use crate::reader::{Block, BlockType, Node, NodeKind};
+use crate::reader::{Block, BlockType, Id, Node, NodeKind};
 
 use std::borrow::Cow;
 use std::marker::PhantomData;
 
 pub struct Savant<S, D> {
+    default_version: YamlVersion,
     yaml_version: YamlVersion,
     data: Data<D>,
     schema: S,
     tag_handles: Vec<(Cow<'static, str>, Cow<'static, str>)>,
-    buf_literal_block: Option<(usize, Vec<Result<Marker, (u8, usize)>>)>,
+    buf_literal_block: Option<(Id, Vec<Result<Marker, (u8, usize)>>)>,
     _datum: PhantomData<D>,
 }
 
@@ -37,8 +38,11 @@ where
             }
         }
 
+        let version = YamlVersion::from(schema.get_yaml_version());
+
         Savant {
-            yaml_version: YamlVersion::V1x2,
+            default_version: version,
+            yaml_version: version,
             data: Data::with_capacity(32),
             schema: schema,
             tag_handles: tag_handles,
@@ -47,6 +51,12 @@ where
         }
     }
 
+    /// Sets the version of the documents with no `%YAML` directive (`Schema::get_yaml_version` by default)
+    pub fn set_yaml_version(&mut self, version: YamlVersion) {
+        self.default_version = version;
+        self.yaml_version = version;
+    }
+
     pub fn think(&mut self, block: Block<D>) -> Result<Option<Idea>, SageError> {
         match block.cargo {
             BlockType::StreamEnd => {
@@ -69,7 +79,10 @@ where
 
             BlockType::DocStart => Ok(Some(Idea::Dawn)),
 
-            BlockType::DocEnd => Ok(Some(Idea::Dusk)),
+            BlockType::DocEnd => {
+                self.yaml_version = self.default_version;
+                Ok(Some(Idea::Dusk))
+            }
 
             BlockType::Error(message, position) => {
                 Ok(Some(Idea::ReadError(block.id, position, message)))
@@ -84,15 +97,18 @@ where
                 tag: _,
                 content: NodeKind::LiteralBlockOpen,
             }) => {
-                self.buf_literal_block = Some((block.id.index, Vec::with_capacity(32)));
+                self.buf_literal_block = Some((block.id, Vec::with_capacity(32)));
                 Ok(None)
             }
 
             BlockType::Literal(..) if self.buf_literal_block.is_some() => {
                 if let BlockType::Literal(chunk) = block.cargo {
-                    if let Some((idx, ref mut vec)) = self.buf_literal_block {
-                        if idx != block.id.parent {
-                            panic!("Unexpected literal!")
+                    if let Some((ref mut open, ref mut vec)) = self.buf_literal_block {
+                        if open.index != block.id.parent {
+                            return Err(SageError::Error(Cow::from("Unexpected literal")));
+                        }
+                        if block.id.span.end.offset > open.span.end.offset {
+                            open.span.end = block.id.span.end;
                         }
                         vec.push(Ok(chunk));
                     }
@@ -103,9 +119,9 @@ where
 
             BlockType::Byte(..) if self.buf_literal_block.is_some() => {
                 if let BlockType::Byte(byte, amount) = block.cargo {
-                    if let Some((idx, ref mut vec)) = self.buf_literal_block {
-                        if idx != block.id.parent {
-                            panic!("Unexpected literal!")
+                    if let Some((ref open, ref mut vec)) = self.buf_literal_block {
+                        if open.index != block.id.parent {
+                            return Err(SageError::Error(Cow::from("Unexpected literal")));
                         }
                         vec.push(Err((byte, amount)));
                     }
@@ -119,12 +135,17 @@ where
                 tag,
                 content: NodeKind::LiteralBlockClose,
             }) => {
-                let (idx, vec) = self.buf_literal_block.take().unwrap();
-                if idx != block.id.index {
-                    panic!("Unexpected literal block!")
+                let (open, vec) = match self.buf_literal_block.take() {
+                    Some((open, vec)) if open.index == block.id.index => (open, vec),
+                    _ => return Err(SageError::Error(Cow::from("Unexpected literal block"))),
+                };
+                let mut id = block.id;
+                id.span.start = open.span.start;
+                if open.span.end.offset > id.span.end.offset {
+                    id.span.end = open.span.end;
                 }
                 let (anchor, value) = self.read_literal_block(anchor, tag, Err(vec))?;
-                Ok(Some(Idea::NodeScalar(block.id, anchor, value)))
+                Ok(Some(Idea::NodeScalar(id, anchor, value)))
             }
 
             BlockType::Alias(..)
@@ -142,12 +163,7 @@ where
                 version.0, version.1
             ))));
         }
-        let ver = if version.1 == 1 {
-            YamlVersion::V1x1
-        } else {
-            YamlVersion::V1x2
-        };
-        self.yaml_version = ver;
+        self.yaml_version = YamlVersion::from(version);
         Ok(None)
     }
 
@@ -259,7 +275,7 @@ where
         let anchor: Option<String> = self.read_anchor(anchor)?;
         let tag: Option<String> = self.read_tag(tag)?;
 
-        let mut decoded: Result<TaggedValue, ()> = Err(());
+        let mut decoded: Option<TaggedValue> = None;
 
         let chunk = match marker {
             Ok(marker) => self.data.chunk(&marker),
@@ -299,51 +315,51 @@ where
                     if !m.is_decodable() {
                         return false;
                     }
-                    decoded = self.decode(m, e, chunk);
-                    decoded.is_ok()
+                    decoded = self.decode(m, e, chunk).ok();
+                    decoded.is_some()
                 })
             } else {
                 if let Some(v) = match self.yaml_version {
                     YamlVersion::V1x1 => self.schema.try_decodable_models_11(chunk),
                     YamlVersion::V1x2 => self.schema.try_decodable_models(chunk),
                 } {
-                    decoded = Ok(v)
+                    decoded = Some(v)
                 };
 
                 None
             }
         };
 
-        let node = if decoded.is_ok() {
-            decoded.unwrap()
+        let node = if let Some(decoded) = decoded {
+            decoded
         } else {
             match model {
-                Some((model, explicit)) => match self.decode(model, explicit, chunk) {
-                    Ok(v) => v,
-                    Err(()) => return Err(SageError::Error(Cow::from("Could not decode value"))),
-                },
+                Some((model, explicit)) => self.decode(model, explicit, chunk)?,
                 None => {
-                    let mut meta: Result<TaggedValue, ()> = Err(());
+                    let mut meta: Option<TaggedValue> = None;
 
                     if let Some(m) = self.schema.get_metamodel() {
                         let empty = String::with_capacity(0);
                         let tag: &String = if let Some(ref tag) = tag { tag } else { &empty };
 
-                        meta = m.meta_init(anchor.clone(), self.resolve_tag(&tag), chunk);
+                        meta = m
+                            .meta_init(anchor.clone(), self.resolve_tag(&tag), chunk)
+                            .ok();
                     }
 
                     match meta {
-                        Err(_) => {
-                            return Err(SageError::Error(Cow::from(format!(
-                                "Could not find appropriate model (tag {})",
-                                match tag {
-                                    Some(t) => t,
-                                    None => String::from(""),
-                                }
-                            ))));
+                        None => {
+                            return Err(SageError::Error(Cow::from(match tag {
+                                Some(t) => format!("Could not find appropriate model (tag {})", t),
+                                None => format!(
+                                    "Cannot resolve the scalar \"{}\": {}",
+                                    String::from_utf8_lossy(chunk),
+                                    self.schema.get_unresolved_reason()
+                                ),
+                            })));
                         }
 
-                        Ok(tagged_value) => tagged_value,
+                        Some(tagged_value) => tagged_value,
                     }
                 }
             }
@@ -422,12 +438,11 @@ where
         let anchor: Option<String> = self.read_anchor(anchor)?;
         let tag: Option<String> = self.read_tag(tag)?;
 
-        let tagged_value = if let Some(tag) = tag {
-            let model = self.read_model(tag, |m, _| !m.is_collection() && m.has_default())?;
-            model.get_default()
-        } else {
-            self.schema.get_model_null().get_default()
-        };
+        // the empty nodes with no tag are of the first scalar model with a default in the schema
+        let model = self.read_model(tag.unwrap_or_default(), |m, _| {
+            !m.is_collection() && m.has_default()
+        })?;
+        let tagged_value = model.get_default()?;
 
         Ok((anchor, tagged_value))
     }
@@ -650,7 +665,16 @@ where
         }
     }
 
-    fn decode(&self, model: &dyn Model, explicit: bool, value: &[u8]) -> Result<TaggedValue, ()> {
+    fn decode(
+        &self,
+        model: &dyn Model,
+        explicit: bool,
+        value: &[u8],
+    ) -> Result<TaggedValue, DecodeError> {
+        if !model.is_decodable() {
+            return Err(DecodeError::new(model, value, "the model is not decodable"));
+        }
+
         match self.yaml_version {
             YamlVersion::V1x1 => model.decode11(explicit, value),
             YamlVersion::V1x2 => model.decode(explicit, value),
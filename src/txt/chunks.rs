@@ -0,0 +1,306 @@
+extern crate skimmer;
+
+use self::skimmer::reader::{IntoReader, Read};
+use self::skimmer::{Datum, Marker};
+
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+/// A chunk of the source read by `Chunks`
+#[derive(Clone, Debug)]
+pub struct ChunkDatum {
+    content: Rc<Vec<u8>>,
+}
+
+impl Datum for ChunkDatum {
+    fn len(&self) -> usize {
+        self.content.len()
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        self.content.as_slice()
+    }
+}
+
+/// Source of the data coming in chunks, such as the reads of a socket or the messages of a channel.
+///
+/// Unlike the other sources, the stream is not read in full before the reader starts.
+/// Every chunk becomes a datum of its own and is taken from the iterator only when
+/// the reader looks past the previous ones, so that the limits of the reader may stop
+/// a stream before it has been received. The chunks may split the lines and even
+/// the characters; the nodes spanning several chunks are joined by `skimmer::Data`.
+pub struct Chunks<I> {
+    source: I,
+    exhausted: bool,
+
+    /// The chunks from the index `first` on, the ones before it have been passed and handed out
+    chunks: VecDeque<ChunkDatum>,
+    first: usize,
+
+    /// The index of the chunk at the cursor and the position of the cursor within it
+    current: usize,
+    pointer: usize,
+
+    /// The bytes from the cursor up to the end of the last chunk taken
+    buffered: usize,
+
+    /// The chunks with the lesser indexes have been consumed, so the reader holds them as its data.
+    /// Asking for a datum does not count, the reader may look at the passed ones again to report an error
+    given: usize,
+
+    /// The copy of the slices that cross the chunks
+    window: Vec<u8>,
+}
+
+impl<I> Chunks<I>
+where
+    I: Iterator<Item = Vec<u8>>,
+{
+    pub fn new(source: I) -> Chunks<I> {
+        Chunks {
+            source,
+            exhausted: false,
+            chunks: VecDeque::with_capacity(4),
+            first: 0,
+            current: 0,
+            pointer: 0,
+            buffered: 0,
+            given: 0,
+            window: Vec::new(),
+        }
+    }
+
+    /// Takes the chunks from the source until there are `len` bytes past the cursor
+    fn fill(&mut self, len: usize) -> bool {
+        while self.buffered < len && !self.exhausted {
+            match self.source.next() {
+                Some(chunk) => {
+                    if !chunk.is_empty() {
+                        self.buffered += chunk.len();
+                        self.chunks.push_back(ChunkDatum {
+                            content: Rc::new(chunk),
+                        });
+                    }
+                }
+                None => self.exhausted = true,
+            }
+        }
+
+        self.buffered >= len
+    }
+
+    /// Moves the cursor off the end of its chunk, so that a marker starts in the chunk it points at
+    fn settle(&mut self) {
+        while let Some(chunk) = self.chunks.get(self.current - self.first) {
+            if self.pointer < chunk.len() || !self.fill(1) {
+                break;
+            }
+
+            self.current += 1;
+            self.pointer = 0;
+        }
+    }
+
+    fn advance(&mut self, len: usize) -> usize {
+        self.fill(len);
+
+        let len = len.min(self.buffered);
+        let mut left = len;
+
+        while left > 0 {
+            let room = self.chunks[self.current - self.first].len() - self.pointer;
+
+            if left <= room {
+                self.pointer += left;
+                break;
+            }
+
+            left -= room;
+            self.current += 1;
+            self.pointer = 0;
+        }
+
+        self.buffered -= len;
+
+        while self.first < self.current && self.first < self.given {
+            self.chunks.pop_front();
+            self.first += 1;
+        }
+
+        len
+    }
+}
+
+impl<I> IntoReader for Chunks<I>
+where
+    I: Iterator<Item = Vec<u8>>,
+{
+    type Reader = Chunks<I>;
+
+    fn into_reader(self) -> Chunks<I> {
+        self
+    }
+}
+
+impl<I> Read for Chunks<I>
+where
+    I: Iterator<Item = Vec<u8>>,
+{
+    type Datum = ChunkDatum;
+
+    fn consume(&mut self, len: u8) -> Marker {
+        self.consume_long(len as usize)
+    }
+
+    fn consume_long(&mut self, len: usize) -> Marker {
+        self.settle();
+
+        let start = (self.current, self.pointer);
+        self.advance(len);
+        self.given = self.given.max(self.current + 1);
+
+        Marker::new(start, (self.current, self.pointer))
+    }
+
+    fn get_datum(&mut self, index: usize) -> Option<ChunkDatum> {
+        if index < self.first {
+            return None;
+        }
+
+        match self.chunks.get(index - self.first) {
+            Some(chunk) => Some(chunk.clone()),
+
+            // an empty stream still has its datum
+            None if index == 0 => Some(ChunkDatum {
+                content: Rc::new(Vec::new()),
+            }),
+
+            None => None,
+        }
+    }
+
+    fn skip(&mut self, len: u8) -> u8 {
+        self.advance(len as usize) as u8
+    }
+
+    fn skip_long(&mut self, len: usize) -> usize {
+        self.advance(len)
+    }
+
+    fn has(&mut self, len: u8) -> bool {
+        self.fill(len as usize)
+    }
+
+    fn has_long(&mut self, len: usize) -> bool {
+        self.fill(len)
+    }
+
+    fn byte_at_start(&mut self, byte: u8) -> bool {
+        self.get_byte_at(0) == Some(byte)
+    }
+
+    fn byte_at(&mut self, byte: u8, at: usize) -> bool {
+        self.get_byte_at(at) == Some(byte)
+    }
+
+    fn bytes_2_at_start(&mut self, bytes: [u8; 2]) -> bool {
+        self.slice_at(0, 2) == Some(&bytes)
+    }
+
+    fn bytes_2_at(&mut self, bytes: [u8; 2], at: usize) -> bool {
+        self.slice_at(at, 2) == Some(&bytes)
+    }
+
+    fn bytes_3_at_start(&mut self, bytes: [u8; 3]) -> bool {
+        self.slice_at(0, 3) == Some(&bytes)
+    }
+
+    fn bytes_3_at(&mut self, bytes: [u8; 3], at: usize) -> bool {
+        self.slice_at(at, 3) == Some(&bytes)
+    }
+
+    fn bytes_4_at_start(&mut self, bytes: [u8; 4]) -> bool {
+        self.slice_at(0, 4) == Some(&bytes)
+    }
+
+    fn bytes_4_at(&mut self, bytes: [u8; 4], at: usize) -> bool {
+        self.slice_at(at, 4) == Some(&bytes)
+    }
+
+    fn bytes_at_start(&mut self, bytes: &[u8]) -> bool {
+        self.slice_at(0, bytes.len()) == Some(bytes)
+    }
+
+    fn bytes_at(&mut self, bytes: &[u8], at: usize) -> bool {
+        self.slice_at(at, bytes.len()) == Some(bytes)
+    }
+
+    fn slice_at(&mut self, at: usize, len: usize) -> Option<&[u8]> {
+        if !self.fill(at + len) {
+            return None;
+        }
+
+        let mut index = self.current - self.first;
+        let mut offset = self.pointer + at;
+
+        while index < self.chunks.len() && offset >= self.chunks[index].len() {
+            offset -= self.chunks[index].len();
+            index += 1;
+        }
+
+        if len == 0 {
+            return Some(&[]);
+        }
+
+        if offset + len <= self.chunks[index].len() {
+            return Some(&self.chunks[index].as_slice()[offset..offset + len]);
+        }
+
+        self.window.clear();
+
+        for chunk in self.chunks.iter().skip(index) {
+            let end = chunk.len().min(offset + len - self.window.len());
+            self.window
+                .extend_from_slice(&chunk.as_slice()[offset..end]);
+            offset = 0;
+
+            if self.window.len() == len {
+                break;
+            }
+        }
+
+        Some(&self.window)
+    }
+
+    fn get_byte_at(&mut self, at: usize) -> Option<u8> {
+        self.slice_at(at, 1).map(|bytes| bytes[0])
+    }
+
+    fn get_byte_at_start(&mut self) -> Option<u8> {
+        self.get_byte_at(0)
+    }
+
+    fn get_bytes_2_at(&mut self, at: usize) -> Option<(u8, u8)> {
+        self.slice_at(at, 2).map(|bs| (bs[0], bs[1]))
+    }
+
+    fn get_bytes_2_at_start(&mut self) -> Option<(u8, u8)> {
+        self.get_bytes_2_at(0)
+    }
+
+    fn get_bytes_3_at(&mut self, at: usize) -> Option<(u8, u8, u8)> {
+        self.slice_at(at, 3).map(|bs| (bs[0], bs[1], bs[2]))
+    }
+
+    fn get_bytes_3_at_start(&mut self) -> Option<(u8, u8, u8)> {
+        self.get_bytes_3_at(0)
+    }
+
+    fn get_bytes_4_at(&mut self, at: usize) -> Option<(u8, u8, u8, u8)> {
+        self.slice_at(at, 4).map(|bs| (bs[0], bs[1], bs[2], bs[3]))
+    }
+
+    fn get_bytes_4_at_start(&mut self) -> Option<(u8, u8, u8, u8)> {
+        self.get_bytes_4_at(0)
+    }
+}
@@ -0,0 +1,73 @@
+use crate::txt::encoding::Encoding;
+
+use std::error;
+use std::fmt;
+use std::io;
+
+/// Source of any Unicode encoding the YAML 1.2 spec allows.
+///
+/// The reader works with UTF-8 only, so the source is read in full and transcoded
+/// into UTF-8 when the encoding is detected as UTF-16 or UTF-32 (see `Encoding::detect`).
+/// Byte offsets of the spans refer to the transcoded text in that case.
+///
+/// The source is decoded before reading, so that its errors are the `DecodeError`
+/// of `decode`, e.g. `yamlette! ( read ; Decoder::new (file).decode ()? ; ... )`.
+pub struct Decoder<R> {
+    source: R,
+}
+
+impl<R> Decoder<R>
+where
+    R: io::Read,
+{
+    pub fn new(source: R) -> Decoder<R> {
+        Decoder { source }
+    }
+
+    /// Reads the source in full and transcodes it into UTF-8
+    pub fn decode(mut self) -> Result<Vec<u8>, DecodeError> {
+        let mut buffer = Vec::with_capacity(32 * 1024);
+        self.source.read_to_end(&mut buffer)?;
+
+        Encoding::decode(buffer)
+    }
+}
+
+/// The source of a `Decoder` could not be read or transcoded
+#[derive(Debug)]
+pub enum DecodeError {
+    Io(io::Error),
+
+    /// The stream is not valid in the encoding it has been detected as
+    Encoding(&'static str),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, fmtter: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DecodeError::Io(ref error) => write!(fmtter, "The source cannot be read: {}", error),
+            DecodeError::Encoding(name) => {
+                write!(
+                    fmtter,
+                    "The stream cannot be transcoded from {} into UTF-8",
+                    name
+                )
+            }
+        }
+    }
+}
+
+impl error::Error for DecodeError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            DecodeError::Io(ref error) => Some(error),
+            DecodeError::Encoding(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for DecodeError {
+    fn from(error: io::Error) -> DecodeError {
+        DecodeError::Io(error)
+    }
+}
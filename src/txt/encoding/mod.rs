@@ -1,19 +1,113 @@
+pub mod decoder;
 pub mod unicode;
 pub mod utf8;
+pub mod utf16;
+pub mod utf32;
 
 
 
+pub use self::decoder::{ DecodeError, Decoder };
 pub use self::unicode::Unicode;
 pub use self::utf8::UTF8;
+pub use self::utf16::{ UTF16BE, UTF16LE };
+pub use self::utf32::{ UTF32BE, UTF32LE };
 
 
 use std::default::Default;
+use std::string::FromUtf8Error;
 
 
 
 #[derive (Copy, Clone)]
 pub enum Encoding {
-    UTF8 (self::utf8::UTF8)
+    UTF8 (self::utf8::UTF8),
+    UTF16LE (self::utf16::UTF16LE),
+    UTF16BE (self::utf16::UTF16BE),
+    UTF32LE (self::utf32::UTF32LE),
+    UTF32BE (self::utf32::UTF32BE)
+}
+
+
+
+impl Encoding {
+    /// Detects the encoding by the byte order mark or, if there is none,
+    /// by the null bytes among the first ASCII characters (YAML 1.2, 5.2)
+    pub fn detect (stream: &[u8]) -> Encoding {
+        match *stream {
+            [0x00, 0x00, 0xFE, 0xFF, ..] => Encoding::UTF32BE (UTF32BE),
+            [0x00, 0x00, 0x00, _, ..] => Encoding::UTF32BE (UTF32BE),
+            [0xFF, 0xFE, 0x00, 0x00, ..] => Encoding::UTF32LE (UTF32LE),
+            [_, 0x00, 0x00, 0x00, ..] => Encoding::UTF32LE (UTF32LE),
+            [0xFE, 0xFF, ..] => Encoding::UTF16BE (UTF16BE),
+            [0x00, _, ..] => Encoding::UTF16BE (UTF16BE),
+            [0xFF, 0xFE, ..] => Encoding::UTF16LE (UTF16LE),
+            [_, 0x00, ..] => Encoding::UTF16LE (UTF16LE),
+            _ => Encoding::UTF8 (UTF8)
+        }
+    }
+
+
+    pub fn name (self) -> &'static str {
+        match self {
+            Encoding::UTF8 (_) => "UTF-8",
+            Encoding::UTF16LE (_) => "UTF-16LE",
+            Encoding::UTF16BE (_) => "UTF-16BE",
+            Encoding::UTF32LE (_) => "UTF-32LE",
+            Encoding::UTF32BE (_) => "UTF-32BE"
+        }
+    }
+
+
+    /// The byte order mark of the encoding
+    pub fn bom (self) -> &'static [u8] {
+        match self {
+            Encoding::UTF8 (_) => &[0xEF, 0xBB, 0xBF],
+            Encoding::UTF16LE (_) => &[0xFF, 0xFE],
+            Encoding::UTF16BE (_) => &[0xFE, 0xFF],
+            Encoding::UTF32LE (_) => &[0xFF, 0xFE, 0x00, 0x00],
+            Encoding::UTF32BE (_) => &[0x00, 0x00, 0xFE, 0xFF]
+        }
+    }
+
+
+    /// Transcodes the UTF-8 stream into the encoding, prepending the byte order mark if asked.
+    /// Fails if the stream is not valid UTF-8.
+    pub fn encode (self, stream: Vec<u8>, bom: bool) -> Result<Vec<u8>, FromUtf8Error> {
+        let string = String::from_utf8 (stream)?;
+
+        let encoded = match self {
+            Encoding::UTF8 (_) => string.into_bytes (),
+            encoding => encoding.string_to_bytes (string)
+        };
+
+        if bom {
+            let mut result = self.bom ().to_vec ();
+            result.extend (encoded);
+            Ok (result)
+        } else {
+            Ok (encoded)
+        }
+    }
+
+
+    /// Transcodes the stream into UTF-8 dropping its byte order mark.
+    /// UTF-8 streams are passed through as they are.
+    pub fn decode (stream: Vec<u8>) -> Result<Vec<u8>, DecodeError> {
+        let encoding = Encoding::detect (&stream);
+
+        if let Encoding::UTF8 (_) = encoding { return Ok (stream) }
+
+        let content = if stream.starts_with (encoding.bom ()) {
+            &stream[encoding.bom ().len () ..]
+        } else {
+            &stream[..]
+        };
+
+        match encoding.bytes_to_string (content) {
+            Ok (string) => Ok (string.into_bytes ()),
+            Err (_) => Err (DecodeError::Encoding (encoding.name ()))
+        }
+    }
 }
 
 
@@ -27,105 +121,165 @@ impl Default for Encoding {
 impl Unicode for Encoding {
     fn char_max_bytes_len (self) -> u8 {
         match self {
-            Encoding::UTF8 (e) => e.char_max_bytes_len ()
+            Encoding::UTF8 (e) => e.char_max_bytes_len (),
+            Encoding::UTF16LE (e) => e.char_max_bytes_len (),
+            Encoding::UTF16BE (e) => e.char_max_bytes_len (),
+            Encoding::UTF32LE (e) => e.char_max_bytes_len (),
+            Encoding::UTF32BE (e) => e.char_max_bytes_len ()
         }
     }
 
 
     fn check_is_flo_num (self, stream: &[u8]) -> bool {
         match self {
-            Encoding::UTF8 (e) => e.check_is_flo_num (stream)
+            Encoding::UTF8 (e) => e.check_is_flo_num (stream),
+            Encoding::UTF16LE (e) => e.check_is_flo_num (stream),
+            Encoding::UTF16BE (e) => e.check_is_flo_num (stream),
+            Encoding::UTF32LE (e) => e.check_is_flo_num (stream),
+            Encoding::UTF32BE (e) => e.check_is_flo_num (stream)
         }
     }
 
 
     fn check_is_dec_num (self, stream: &[u8]) -> bool {
         match self {
-            Encoding::UTF8 (e) => e.check_is_dec_num (stream)
+            Encoding::UTF8 (e) => e.check_is_dec_num (stream),
+            Encoding::UTF16LE (e) => e.check_is_dec_num (stream),
+            Encoding::UTF16BE (e) => e.check_is_dec_num (stream),
+            Encoding::UTF32LE (e) => e.check_is_dec_num (stream),
+            Encoding::UTF32BE (e) => e.check_is_dec_num (stream)
         }
     }
 
 
     fn extract_bin_digit (self, stream: &[u8]) -> Option<(u8, u8)> {
         match self {
-            Encoding::UTF8 (e) => e.extract_bin_digit (stream)
+            Encoding::UTF8 (e) => e.extract_bin_digit (stream),
+            Encoding::UTF16LE (e) => e.extract_bin_digit (stream),
+            Encoding::UTF16BE (e) => e.extract_bin_digit (stream),
+            Encoding::UTF32LE (e) => e.extract_bin_digit (stream),
+            Encoding::UTF32BE (e) => e.extract_bin_digit (stream)
         }
     }
 
 
     fn extract_dec_digit (self, stream: &[u8]) -> Option<(u8, u8)> {
         match self {
-            Encoding::UTF8 (e) => e.extract_dec_digit (stream)
+            Encoding::UTF8 (e) => e.extract_dec_digit (stream),
+            Encoding::UTF16LE (e) => e.extract_dec_digit (stream),
+            Encoding::UTF16BE (e) => e.extract_dec_digit (stream),
+            Encoding::UTF32LE (e) => e.extract_dec_digit (stream),
+            Encoding::UTF32BE (e) => e.extract_dec_digit (stream)
         }
     }
 
 
     fn extract_oct_digit (self, stream: &[u8]) -> Option<(u8, u8)> {
         match self {
-            Encoding::UTF8 (e) => e.extract_oct_digit (stream)
+            Encoding::UTF8 (e) => e.extract_oct_digit (stream),
+            Encoding::UTF16LE (e) => e.extract_oct_digit (stream),
+            Encoding::UTF16BE (e) => e.extract_oct_digit (stream),
+            Encoding::UTF32LE (e) => e.extract_oct_digit (stream),
+            Encoding::UTF32BE (e) => e.extract_oct_digit (stream)
         }
     }
 
 
     fn extract_hex_digit (self, stream: &[u8]) -> Option<(u8, u8)> {
         match self {
-            Encoding::UTF8 (e) => e.extract_hex_digit (stream)
+            Encoding::UTF8 (e) => e.extract_hex_digit (stream),
+            Encoding::UTF16LE (e) => e.extract_hex_digit (stream),
+            Encoding::UTF16BE (e) => e.extract_hex_digit (stream),
+            Encoding::UTF32LE (e) => e.extract_hex_digit (stream),
+            Encoding::UTF32BE (e) => e.extract_hex_digit (stream)
         }
     }
 
 
     fn check_bom (self, bom: &[u8]) -> bool {
         match self {
-            Encoding::UTF8 (e) => e.check_bom (bom)
+            Encoding::UTF8 (e) => e.check_bom (bom),
+            Encoding::UTF16LE (e) => e.check_bom (bom),
+            Encoding::UTF16BE (e) => e.check_bom (bom),
+            Encoding::UTF32LE (e) => e.check_bom (bom),
+            Encoding::UTF32BE (e) => e.check_bom (bom)
         }
     }
 
 
     unsafe fn to_unicode_ptr (self, ptr: *const u8, len: usize) -> (u32, u8) {
         match self {
-            Encoding::UTF8 (e) => e.to_unicode_ptr (ptr, len)
+            Encoding::UTF8 (e) => e.to_unicode_ptr (ptr, len),
+            Encoding::UTF16LE (e) => e.to_unicode_ptr (ptr, len),
+            Encoding::UTF16BE (e) => e.to_unicode_ptr (ptr, len),
+            Encoding::UTF32LE (e) => e.to_unicode_ptr (ptr, len),
+            Encoding::UTF32BE (e) => e.to_unicode_ptr (ptr, len)
         }
     }
 
 
     fn to_unicode (self, stream: &[u8]) -> (u32, u8) {
         match self {
-            Encoding::UTF8 (e) => e.to_unicode (stream)
+            Encoding::UTF8 (e) => e.to_unicode (stream),
+            Encoding::UTF16LE (e) => e.to_unicode (stream),
+            Encoding::UTF16BE (e) => e.to_unicode (stream),
+            Encoding::UTF32LE (e) => e.to_unicode (stream),
+            Encoding::UTF32BE (e) => e.to_unicode (stream)
         }
     }
 
 
     fn from_unicode (self, point: u32) -> [u8; 5] {
         match self {
-            Encoding::UTF8 (e) => e.from_unicode (point)
+            Encoding::UTF8 (e) => e.from_unicode (point),
+            Encoding::UTF16LE (e) => e.from_unicode (point),
+            Encoding::UTF16BE (e) => e.from_unicode (point),
+            Encoding::UTF32LE (e) => e.from_unicode (point),
+            Encoding::UTF32BE (e) => e.from_unicode (point)
         }
     }
 
 
     fn str_to_bytes<'a> (self, string: &'a str) -> Result<&'a [u8], Vec<u8>> {
         match self {
-            Encoding::UTF8 (e) => e.str_to_bytes (string)
+            Encoding::UTF8 (e) => e.str_to_bytes (string),
+            Encoding::UTF16LE (e) => e.str_to_bytes (string),
+            Encoding::UTF16BE (e) => e.str_to_bytes (string),
+            Encoding::UTF32LE (e) => e.str_to_bytes (string),
+            Encoding::UTF32BE (e) => e.str_to_bytes (string)
         }
     }
 
 
     fn string_to_bytes (self, string: String) -> Vec<u8> {
         match self {
-            Encoding::UTF8 (e) => e.string_to_bytes (string)
+            Encoding::UTF8 (e) => e.string_to_bytes (string),
+            Encoding::UTF16LE (e) => e.string_to_bytes (string),
+            Encoding::UTF16BE (e) => e.string_to_bytes (string),
+            Encoding::UTF32LE (e) => e.string_to_bytes (string),
+            Encoding::UTF32BE (e) => e.string_to_bytes (string)
         }
     }
 
 
     fn bytes_to_string (self, bytes: &[u8]) -> Result<String, ()> {
         match self {
-            Encoding::UTF8 (e) => e.bytes_to_string (bytes)
+            Encoding::UTF8 (e) => e.bytes_to_string (bytes),
+            Encoding::UTF16LE (e) => e.bytes_to_string (bytes),
+            Encoding::UTF16BE (e) => e.bytes_to_string (bytes),
+            Encoding::UTF32LE (e) => e.bytes_to_string (bytes),
+            Encoding::UTF32BE (e) => e.bytes_to_string (bytes)
         }
     }
 
 
     fn bytes_to_string_times (self, bytes: &[u8], times: usize) -> Result<String, ()> {
         match self {
-            Encoding::UTF8 (e) => e.bytes_to_string_times (bytes, times)
+            Encoding::UTF8 (e) => e.bytes_to_string_times (bytes, times),
+            Encoding::UTF16LE (e) => e.bytes_to_string_times (bytes, times),
+            Encoding::UTF16BE (e) => e.bytes_to_string_times (bytes, times),
+            Encoding::UTF32LE (e) => e.bytes_to_string_times (bytes, times),
+            Encoding::UTF32BE (e) => e.bytes_to_string_times (bytes, times)
         }
     }
 }
@@ -0,0 +1,230 @@
+use crate::txt::encoding::Unicode;
+
+use std::slice;
+
+#[derive(Copy, Clone)]
+pub struct UTF16LE;
+
+#[derive(Copy, Clone)]
+pub struct UTF16BE;
+
+macro_rules! utf16 {
+    ( $encoding:ident ; $bom:expr ; $from_bytes:ident ; $to_bytes:ident ) => {
+        impl $encoding {
+            #[inline(always)]
+            fn unit(self, stream: &[u8], at: usize) -> Option<u16> {
+                if stream.len() < at + 2 {
+                    None
+                } else {
+                    Some(u16::$from_bytes([stream[at], stream[at + 1]]))
+                }
+            }
+
+            #[inline(always)]
+            fn ascii(self, stream: &[u8]) -> Option<u8> {
+                match self.to_unicode(stream) {
+                    (code, 2) if code < 0x80 => Some(code as u8),
+                    _ => None,
+                }
+            }
+
+            fn encode_into(self, string: &str, bytes: &mut Vec<u8>) {
+                for unit in string.encode_utf16() {
+                    bytes.extend_from_slice(&unit.$to_bytes());
+                }
+            }
+        }
+
+        impl Unicode for $encoding {
+            #[inline(always)]
+            fn char_max_bytes_len(self) -> u8 {
+                4
+            }
+
+            fn check_bom(self, bom: &[u8]) -> bool {
+                bom == $bom
+            }
+
+            fn check_is_flo_num(self, stream: &[u8]) -> bool {
+                match self.ascii(stream) {
+                    Some(b'0'..=b'9') => true,
+                    Some(b'+') | Some(b'-') | Some(b'.') => stream.len() > 2,
+                    _ => false,
+                }
+            }
+
+            fn check_is_dec_num(self, stream: &[u8]) -> bool {
+                match self.ascii(stream) {
+                    Some(b'0'..=b'9') => true,
+                    Some(b'+') | Some(b'-') => stream.len() > 2,
+                    _ => false,
+                }
+            }
+
+            fn extract_bin_digit(self, stream: &[u8]) -> Option<(u8, u8)> {
+                match self.ascii(stream) {
+                    Some(n @ b'0'..=b'1') => Some((n - b'0', 2)),
+                    _ => None,
+                }
+            }
+
+            fn extract_dec_digit(self, stream: &[u8]) -> Option<(u8, u8)> {
+                match self.ascii(stream) {
+                    Some(n @ b'0'..=b'9') => Some((n - b'0', 2)),
+                    _ => None,
+                }
+            }
+
+            fn extract_oct_digit(self, stream: &[u8]) -> Option<(u8, u8)> {
+                match self.ascii(stream) {
+                    Some(n @ b'0'..=b'7') => Some((n - b'0', 2)),
+                    _ => None,
+                }
+            }
+
+            fn extract_hex_digit(self, stream: &[u8]) -> Option<(u8, u8)> {
+                match self.ascii(stream) {
+                    Some(n @ b'0'..=b'9') => Some((n - b'0', 2)),
+                    Some(n @ b'A'..=b'F') => Some((n - b'A' + 10, 2)),
+                    Some(n @ b'a'..=b'f') => Some((n - b'a' + 10, 2)),
+                    _ => None,
+                }
+            }
+
+            unsafe fn to_unicode_ptr(self, ptr: *const u8, len: usize) -> (u32, u8) {
+                self.to_unicode(slice::from_raw_parts(ptr, len))
+            }
+
+            fn to_unicode(self, stream: &[u8]) -> (u32, u8) {
+                let first = match self.unit(stream, 0) {
+                    Some(unit) => unit as u32,
+                    None => return (0xFFFD, 1),
+                };
+
+                match first {
+                    0xD800..=0xDBFF => match self.unit(stream, 2) {
+                        Some(second @ 0xDC00..=0xDFFF) => (
+                            0x10000 + ((first - 0xD800) << 10) + (second as u32 - 0xDC00),
+                            4,
+                        ),
+                        _ => (0xFFFD, 2),
+                    },
+                    0xDC00..=0xDFFF => (0xFFFD, 2),
+                    code => (code, 2),
+                }
+            }
+
+            fn from_unicode(self, code: u32) -> [u8; 5] {
+                let mut result = [0; 5];
+
+                if let Some(chr) = std::char::from_u32(code) {
+                    let mut units = [0; 2];
+                    let units = chr.encode_utf16(&mut units);
+
+                    for (i, unit) in units.iter().enumerate() {
+                        let [a, b] = unit.$to_bytes();
+                        result[i * 2] = a;
+                        result[i * 2 + 1] = b;
+                    }
+
+                    result[4] = (units.len() * 2) as u8;
+                }
+
+                result
+            }
+
+            fn str_to_bytes(self, string: &str) -> Result<&[u8], Vec<u8>> {
+                Err(self.string_to_bytes(String::from(string)))
+            }
+
+            fn string_to_bytes(self, string: String) -> Vec<u8> {
+                let mut bytes = Vec::with_capacity(string.len() * 2);
+                self.encode_into(&string, &mut bytes);
+                bytes
+            }
+
+            fn bytes_to_string(self, bytes: &[u8]) -> Result<String, ()> {
+                let mut string = String::with_capacity(bytes.len() / 2);
+                let mut stream = bytes;
+
+                while !stream.is_empty() {
+                    let (code, len) = self.to_unicode(stream);
+                    string.push(std::char::from_u32(code).unwrap_or('\u{FFFD}'));
+                    stream = &stream[len as usize..];
+                }
+
+                Ok(string)
+            }
+
+            fn bytes_to_string_times(self, bytes: &[u8], times: usize) -> Result<String, ()> {
+                let string = self.bytes_to_string(bytes)?;
+                Ok(string.repeat(times))
+            }
+        }
+    };
+}
+
+utf16!(UTF16LE ; &[0xFF, 0xFE] ; from_le_bytes ; to_le_bytes);
+utf16!(UTF16BE ; &[0xFE, 0xFF] ; from_be_bytes ; to_be_bytes);
+
+#[cfg(all(test, not(feature = "dev")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_unicode() {
+        let src = "ключ: 😁"
+            .encode_utf16()
+            .flat_map(|u| u.to_le_bytes())
+            .collect::<Vec<u8>>();
+
+        assert_eq!((0x43a, 2), UTF16LE.to_unicode(&src));
+        assert_eq!((0x3a, 2), UTF16LE.to_unicode(&src[8..]));
+        assert_eq!((0x1f601, 4), UTF16LE.to_unicode(&src[12..]));
+        assert_eq!((0xFFFD, 2), UTF16LE.to_unicode(&src[14..]));
+
+        let src = "ключ: 😁"
+            .encode_utf16()
+            .flat_map(|u| u.to_be_bytes())
+            .collect::<Vec<u8>>();
+
+        assert_eq!((0x43a, 2), UTF16BE.to_unicode(&src));
+        assert_eq!((0x1f601, 4), UTF16BE.to_unicode(&src[12..]));
+    }
+
+    #[test]
+    fn from_unicode() {
+        assert_eq!([0x3D, 0xD8, 0x13, 0xDE, 4], UTF16LE.from_unicode(0x1f613));
+        assert_eq!([0xD8, 0x3D, 0xDE, 0x13, 4], UTF16BE.from_unicode(0x1f613));
+        assert_eq!([0x20, 0, 0, 0, 2], UTF16LE.from_unicode(0x20));
+        assert_eq!([0, 0x20, 0, 0, 2], UTF16BE.from_unicode(0x20));
+    }
+
+    #[test]
+    fn digits() {
+        assert_eq!(Some((7, 2)), UTF16LE.extract_dec_digit(&[b'7', 0]));
+        assert_eq!(Some((15, 2)), UTF16BE.extract_hex_digit(&[0, b'f']));
+        assert_eq!(None, UTF16BE.extract_oct_digit(&[0, b'8']));
+        assert!(UTF16LE.check_is_dec_num(&[b'-', 0, b'1', 0]));
+        assert!(!UTF16LE.check_is_dec_num(&[b'-', 0]));
+    }
+
+    #[test]
+    fn round_trip() {
+        let string = "Mark McGwire: ключ 😁";
+
+        assert_eq!(
+            Err(UTF16LE.string_to_bytes(String::from(string))),
+            UTF16LE.str_to_bytes(string)
+        );
+
+        assert_eq!(
+            Ok(String::from(string)),
+            UTF16LE.bytes_to_string(&UTF16LE.string_to_bytes(String::from(string)))
+        );
+        assert_eq!(
+            Ok(String::from(string)),
+            UTF16BE.bytes_to_string(&UTF16BE.string_to_bytes(String::from(string)))
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use crate::txt::encoding::Unicode;
+
+use std::slice;
+
+#[derive(Copy, Clone)]
+pub struct UTF32LE;
+
+#[derive(Copy, Clone)]
+pub struct UTF32BE;
+
+macro_rules! utf32 {
+    ( $encoding:ident ; $bom:expr ; $from_bytes:ident ; $to_bytes:ident ) => {
+        impl $encoding {
+            #[inline(always)]
+            fn ascii(self, stream: &[u8]) -> Option<u8> {
+                match self.to_unicode(stream) {
+                    (code, 4) if code < 0x80 => Some(code as u8),
+                    _ => None,
+                }
+            }
+        }
+
+        impl Unicode for $encoding {
+            #[inline(always)]
+            fn char_max_bytes_len(self) -> u8 {
+                4
+            }
+
+            fn check_bom(self, bom: &[u8]) -> bool {
+                bom == $bom
+            }
+
+            fn check_is_flo_num(self, stream: &[u8]) -> bool {
+                match self.ascii(stream) {
+                    Some(b'0'..=b'9') => true,
+                    Some(b'+') | Some(b'-') | Some(b'.') => stream.len() > 4,
+                    _ => false,
+                }
+            }
+
+            fn check_is_dec_num(self, stream: &[u8]) -> bool {
+                match self.ascii(stream) {
+                    Some(b'0'..=b'9') => true,
+                    Some(b'+') | Some(b'-') => stream.len() > 4,
+                    _ => false,
+                }
+            }
+
+            fn extract_bin_digit(self, stream: &[u8]) -> Option<(u8, u8)> {
+                match self.ascii(stream) {
+                    Some(n @ b'0'..=b'1') => Some((n - b'0', 4)),
+                    _ => None,
+                }
+            }
+
+            fn extract_dec_digit(self, stream: &[u8]) -> Option<(u8, u8)> {
+                match self.ascii(stream) {
+                    Some(n @ b'0'..=b'9') => Some((n - b'0', 4)),
+                    _ => None,
+                }
+            }
+
+            fn extract_oct_digit(self, stream: &[u8]) -> Option<(u8, u8)> {
+                match self.ascii(stream) {
+                    Some(n @ b'0'..=b'7') => Some((n - b'0', 4)),
+                    _ => None,
+                }
+            }
+
+            fn extract_hex_digit(self, stream: &[u8]) -> Option<(u8, u8)> {
+                match self.ascii(stream) {
+                    Some(n @ b'0'..=b'9') => Some((n - b'0', 4)),
+                    Some(n @ b'A'..=b'F') => Some((n - b'A' + 10, 4)),
+                    Some(n @ b'a'..=b'f') => Some((n - b'a' + 10, 4)),
+                    _ => None,
+                }
+            }
+
+            unsafe fn to_unicode_ptr(self, ptr: *const u8, len: usize) -> (u32, u8) {
+                self.to_unicode(slice::from_raw_parts(ptr, len))
+            }
+
+            fn to_unicode(self, stream: &[u8]) -> (u32, u8) {
+                if stream.len() < 4 {
+                    return (0xFFFD, 1);
+                }
+
+                let code = u32::$from_bytes([stream[0], stream[1], stream[2], stream[3]]);
+
+                if code > 0x10FFFF || (0xD800..=0xDFFF).contains(&code) {
+                    (0xFFFD, 4)
+                } else {
+                    (code, 4)
+                }
+            }
+
+            fn from_unicode(self, code: u32) -> [u8; 5] {
+                match std::char::from_u32(code) {
+                    Some(_) => {
+                        let [a, b, c, d] = code.$to_bytes();
+                        [a, b, c, d, 4]
+                    }
+                    None => [0, 0, 0, 0, 0],
+                }
+            }
+
+            fn str_to_bytes(self, string: &str) -> Result<&[u8], Vec<u8>> {
+                Err(self.string_to_bytes(String::from(string)))
+            }
+
+            fn string_to_bytes(self, string: String) -> Vec<u8> {
+                let mut bytes = Vec::with_capacity(string.len() * 4);
+
+                for chr in string.chars() {
+                    bytes.extend_from_slice(&(chr as u32).$to_bytes());
+                }
+
+                bytes
+            }
+
+            fn bytes_to_string(self, bytes: &[u8]) -> Result<String, ()> {
+                let mut string = String::with_capacity(bytes.len() / 4);
+                let mut stream = bytes;
+
+                while !stream.is_empty() {
+                    let (code, len) = self.to_unicode(stream);
+                    string.push(std::char::from_u32(code).unwrap_or('\u{FFFD}'));
+                    stream = &stream[len as usize..];
+                }
+
+                Ok(string)
+            }
+
+            fn bytes_to_string_times(self, bytes: &[u8], times: usize) -> Result<String, ()> {
+                let string = self.bytes_to_string(bytes)?;
+                Ok(string.repeat(times))
+            }
+        }
+    };
+}
+
+utf32!(UTF32LE ; &[0xFF, 0xFE, 0x00, 0x00] ; from_le_bytes ; to_le_bytes);
+utf32!(UTF32BE ; &[0x00, 0x00, 0xFE, 0xFF] ; from_be_bytes ; to_be_bytes);
+
+#[cfg(all(test, not(feature = "dev")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_unicode() {
+        assert_eq!((0x1f601, 4), UTF32LE.to_unicode(&[0x01, 0xF6, 0x01, 0x00]));
+        assert_eq!((0x1f601, 4), UTF32BE.to_unicode(&[0x00, 0x01, 0xF6, 0x01]));
+        assert_eq!((0xFFFD, 4), UTF32BE.to_unicode(&[0x00, 0x11, 0x00, 0x00]));
+        assert_eq!((0xFFFD, 1), UTF32LE.to_unicode(&[0x20, 0x00]));
+    }
+
+    #[test]
+    fn from_unicode() {
+        assert_eq!([0x13, 0xF6, 0x01, 0x00, 4], UTF32LE.from_unicode(0x1f613));
+        assert_eq!([0x00, 0x01, 0xF6, 0x13, 4], UTF32BE.from_unicode(0x1f613));
+        assert_eq!([0, 0, 0, 0, 0], UTF32BE.from_unicode(0xD800));
+    }
+
+    #[test]
+    fn round_trip() {
+        let string = "Mark McGwire: ключ 😁";
+
+        assert_eq!(
+            Ok(String::from(string)),
+            UTF32LE.bytes_to_string(&UTF32LE.string_to_bytes(String::from(string)))
+        );
+        assert_eq!(
+            Ok(String::from(string)),
+            UTF32BE.bytes_to_string(&UTF32BE.string_to_bytes(String::from(string)))
+        );
+        assert_eq!(Some((10, 4)), UTF32BE.extract_hex_digit(&[0, 0, 0, b'A']));
+    }
+}
@@ -1,3 +1,5 @@
+pub mod chunks;
 pub mod encoding;
 
-pub use self::encoding::{ Encoding, Unicode };
+pub use self::chunks::Chunks;
+pub use self::encoding::{ DecodeError, Decoder, Encoding, Unicode };
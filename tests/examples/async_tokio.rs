@@ -0,0 +1,212 @@
+#[cfg(all(test, feature = "tokio", not(feature = "dev")))]
+mod stable {
+    extern crate tokio;
+    extern crate yamlette;
+
+    use self::tokio::io::{AsyncRead, ReadBuf};
+    use self::yamlette::async_tokio::{self, Events};
+    use self::yamlette::error::ErrorKind;
+    use self::yamlette::events::{self, Event, ScalarStyle};
+    use self::yamlette::limits::{Limit, Limits};
+    use self::yamlette::model::schema::core::Core;
+
+    use std::io;
+    use std::pin::Pin;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::task::{Context, Poll};
+
+    const SRC: &str =
+        "servers:\n  - name: ☃ alpha\n    ports: [80, 443]\n  - name: beta\n---\nsecond\n";
+
+    /// A scalar of every style, with properties in front of some
+    const STYLES: &str = "plain: &a !!str 'single'\nflow: [ \"double\", { k: v } ]\n\
+                          ? !local >\n  folded\n: |\n  literal\n\n...\n--- &b \"next\\\n  line\"\n";
+
+    /// Gives out the source a few bytes at a time, failing in the end if it's told to
+    struct Trickle {
+        src: &'static [u8],
+        step: usize,
+        fail: bool,
+    }
+
+    impl Trickle {
+        fn new(src: &'static str, step: usize, fail: bool) -> Trickle {
+            Trickle {
+                src: src.as_bytes(),
+                step,
+                fail,
+            }
+        }
+    }
+
+    impl AsyncRead for Trickle {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<io::Result<()>> {
+            if self.src.is_empty() && self.fail {
+                return Poll::Ready(Err(io::Error::new(io::ErrorKind::ConnectionReset, "reset")));
+            }
+
+            let len = self.step.min(self.src.len()).min(buf.remaining());
+            buf.put_slice(&self.src[..len]);
+            self.src = &self.src[len..];
+
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    /// A sequence that never ends, counting the bytes given out
+    struct Endless {
+        given: Arc<AtomicUsize>,
+    }
+
+    impl AsyncRead for Endless {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<io::Result<()>> {
+            while buf.remaining() >= 7 {
+                buf.put_slice(b"- item\n");
+                self.given.fetch_add(7, Ordering::SeqCst);
+            }
+
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    async fn dump(mut events: Events) -> Vec<String> {
+        let mut dump = Vec::new();
+
+        while let Some(event) = events.next().await {
+            dump.push(match event {
+                Event::StreamStart => String::from("+STR"),
+                Event::StreamEnd => String::from("-STR"),
+                Event::DocumentStart => String::from("+DOC"),
+                Event::DocumentEnd => String::from("-DOC"),
+                Event::MappingStart { .. } => String::from("+MAP"),
+                Event::MappingEnd => String::from("-MAP"),
+                Event::SequenceStart { .. } => String::from("+SEQ"),
+                Event::SequenceEnd => String::from("-SEQ"),
+                Event::Scalar { .. } => String::from("=VAL"),
+                Event::Alias { .. } => String::from("=ALI"),
+                Event::Warning(..) => String::from("WRN"),
+                Event::Error(message, _) => format!("ERR {}", message),
+            });
+        }
+
+        dump
+    }
+
+    #[tokio::test]
+    async fn read() {
+        for step in vec![1, 3, 64] {
+            let book = async_tokio::read(Trickle::new(SRC, step, false), Core::new())
+                .await
+                .ok()
+                .unwrap();
+
+            assert_eq!(book.volumes.len(), 2);
+            assert!(book.query("/servers/0/name").unwrap() == "☃ alpha");
+            assert!(book.query("servers[0].ports[1]").unwrap() == 443);
+            assert!(book.query("servers[1].name").unwrap() == "beta");
+        }
+
+        let book = async_tokio::read(&b"[1, 2]"[..], Core::new())
+            .await
+            .ok()
+            .unwrap();
+
+        assert!(book.query("/1").unwrap() == 2);
+    }
+
+    #[tokio::test]
+    async fn read_errors() {
+        let error = async_tokio::read(Trickle::new(SRC, 5, true), Core::new())
+            .await
+            .err()
+            .unwrap();
+
+        assert_eq!(error.kind(), ErrorKind::Io);
+
+        let error = async_tokio::read(&b"%YAML"[..], Core::new())
+            .await
+            .err()
+            .unwrap();
+
+        assert_eq!(error.kind(), ErrorKind::Read);
+        assert_eq!(error.location().map(|location| location.column), Some(6));
+    }
+
+    #[tokio::test]
+    async fn read_with_limits() {
+        let given = Arc::new(AtomicUsize::new(0));
+        let source = Endless {
+            given: given.clone(),
+        };
+
+        let limits = Limits {
+            max_document_size: Some(1 << 16),
+            ..Limits::new()
+        };
+
+        let error = async_tokio::read_with_limits(source, Core::new(), limits)
+            .await
+            .err()
+            .unwrap();
+
+        assert_eq!(error.kind(), ErrorKind::Limit(Limit::DocumentSize));
+        assert!(given.load(Ordering::SeqCst) < 1 << 20);
+    }
+
+    #[tokio::test]
+    async fn styles_across_chunks() {
+        let styles = |src: &'static str| -> Vec<(usize, ScalarStyle)> {
+            events::Events::new(src, Core::new())
+                .ok()
+                .unwrap()
+                .filter_map(|event| match event {
+                    Event::Scalar { style, span, .. } => Some((span.start.offset, style)),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        let whole = styles(STYLES);
+
+        for step in 1..=17 {
+            let mut events = Events::new(Trickle::new(STYLES, step, false), Core::new());
+            let mut found = Vec::new();
+
+            while let Some(event) = events.next().await {
+                if let Event::Scalar { style, span, .. } = event {
+                    found.push((span.start.offset, style));
+                }
+            }
+
+            assert_eq!(found, whole, "{}", step);
+        }
+    }
+
+    #[tokio::test]
+    async fn events() {
+        let events = Events::new(Trickle::new(SRC, 2, false), Core::new());
+
+        assert_eq!(
+            dump(events).await,
+            [
+                "+STR", "+DOC", "+MAP", "=VAL", "+SEQ", "+MAP", "=VAL", "=VAL", "=VAL", "+SEQ",
+                "=VAL", "=VAL", "-SEQ", "-MAP", "+MAP", "=VAL", "=VAL", "-MAP", "-SEQ", "-MAP",
+                "-DOC", "+DOC", "=VAL", "-DOC", "-STR"
+            ]
+        );
+
+        let events = Events::new(Trickle::new("[1, 2]", 1, true), Core::new());
+        let dump = dump(events).await;
+
+        assert_eq!(dump[dump.len() - 2..], ["ERR reset", "-STR"]);
+    }
+}
@@ -16,7 +16,7 @@ macro_rules! sage {
                 }
             })
             .unwrap_or_else(|err| {
-                assert!(false, "Unexpected result: {}, :{}", err, err.position);
+                assert!(false, "Unexpected result: {}, :{}", err, err.location);
             });
 
         sage
@@ -42,7 +42,7 @@ macro_rules! sage11 {
                 }
             })
             .unwrap_or_else(|err| {
-                assert!(false, "Unexpected result: {}, :{}", err, err.position);
+                assert!(false, "Unexpected result: {}, :{}", err, err.location);
             });
 
         sage
@@ -69,6 +69,7 @@ mod stable {
     extern crate yamlette;
 
     use self::skimmer::reader::SliceReader;
+    use self::yamlette::book::extractor::Pointer;
     use self::yamlette::book::Book;
     use self::yamlette::model::schema::core::Core;
     use self::yamlette::reader::Reader;
@@ -808,4 +809,243 @@ iso: morph";
         assert_eq!(dua, Some(2u8));
         assert_eq!(tri, Some(3u8));
     }
+
+    #[test]
+    fn extra_13() {
+        let src = "host: localhost\nports:\n  - 80\n  - &tls 443 # secure\nnested: { a: [1, *tls] }\ntext: |\n  one\n  two\n";
+
+        let sage = sage!(src);
+        let book = book!(sage);
+
+        let text = |ptr: Pointer| {
+            let span = ptr.span();
+            &src[span.start.offset..span.end.offset]
+        };
+
+        let root = Pointer::new(&book.volumes[0]).unwrap();
+        assert_eq!(text(root), &src[..src.len() - 1]);
+
+        let host = root.into_map().unwrap();
+        assert_eq!(text(host), "host");
+        assert_eq!(text(host.next_sibling().unwrap()), "localhost");
+
+        let ports = host.next_sibling().unwrap().next_sibling().unwrap();
+        assert_eq!(text(ports), "ports");
+
+        let seq = ports.next_sibling().unwrap();
+        assert_eq!(text(seq), "- 80\n  - &tls 443");
+        assert_eq!((seq.span().start.line, seq.span().start.column), (3, 3));
+        assert_eq!((seq.span().end.line, seq.span().end.column), (4, 13));
+
+        let nested = seq.next_sibling().unwrap().next_sibling().unwrap();
+        assert_eq!(text(nested), "{ a: [1, *tls] }");
+
+        let list = nested.into_map().unwrap().next_sibling().unwrap();
+        assert_eq!(text(list), "[1, *tls]");
+
+        let alias = list.into_seq().unwrap().next_sibling().unwrap();
+        assert_eq!(text(alias), "*tls");
+        assert_eq!(text(alias.unalias()), "443");
+
+        let literal = nested.next_sibling().unwrap().next_sibling().unwrap();
+        assert_eq!(text(literal), "|\n  one\n  two");
+    }
+
+    #[test]
+    fn extra_13_multiline() {
+        let src = "folded: >-\n  one\n  two\nplain: one\n  two\nflow: [one\n  two]\n";
+
+        let sage = sage!(src);
+        let book = book!(sage);
+
+        let text = |ptr: Pointer| {
+            let span = ptr.span();
+            &src[span.start.offset..span.end.offset]
+        };
+
+        let folded = Pointer::new(&book.volumes[0])
+            .unwrap()
+            .into_map()
+            .unwrap()
+            .next_sibling()
+            .unwrap();
+        assert_eq!(text(folded), ">-\n  one\n  two");
+
+        let plain = folded.next_sibling().unwrap().next_sibling().unwrap();
+        assert_eq!(text(plain), "one\n  two");
+
+        let flow = plain.next_sibling().unwrap().next_sibling().unwrap();
+        assert_eq!(text(flow.into_seq().unwrap()), "one\n  two");
+    }
+
+    #[test]
+    fn extra_14() {
+        let src = r"
+- &CENTER { x: 1, y: 2 }
+- &LEFT { x: 0, y: 2 }
+- &BIG { r: 10 }
+- &SMALL { r: 1 }
+
+# All the following maps are equal:
+
+- # Explicit keys
+  x: 1
+  y: 2
+  r: 10
+  label: center/big
+
+- # Merge one map
+  << : *CENTER
+  r: 10
+  label: center/big
+
+- # Merge multiple maps
+  << : [ *CENTER, *BIG ]
+  label: center/big
+
+- # Override
+  << : [ *BIG, *LEFT, *SMALL ]
+  x: 1
+  label: center/big
+
+- # Inline
+  << : { x: 1, y: 2, r: 1 }
+  r: 10
+  label: center/big
+";
+
+        let sage = sage!(src).unwrap();
+        let mut book = Book::new();
+        book.set_merge_keys(true);
+
+        for idea in &*sage {
+            book.stamp(idea);
+        }
+
+        for ix in 4..9 {
+            let map = Pointer::new(&book.volumes[0])
+                .unwrap()
+                .into_seq()
+                .and_then(|ptr| (0..ix).try_fold(ptr, |ptr, _| ptr.next_sibling()))
+                .unwrap();
+
+            assert_eq!(map.unalias().into_map().unwrap().count_siblings(), 8);
+
+            yamlette_reckon! ( ptr ; Some (map) ; {
+                "x" => (x:u8),
+                "y" => (y:u8),
+                "r" => (r:u8),
+                "label" => (label:&str)
+            } );
+
+            assert_eq!(x, Some(1));
+            assert_eq!(y, Some(2));
+            assert_eq!(r, Some(10));
+            assert_eq!(label, Some("center/big"));
+        }
+    }
+
+    #[test]
+    fn extra_15() {
+        let src = r"
+base: &base { a: 1 }
+derived: { <<: *base, b: 2 }
+";
+
+        let sage = sage!(src);
+        let book = book!(sage);
+
+        yamlette_reckon! ( book ; book ; [[ { "derived" => { "<<" => (merge:&str), "a" => (a:u8), "b" => (b:u8) } } ]] );
+
+        assert_eq!(merge, None);
+        assert_eq!(a, None);
+        assert_eq!(b, Some(2));
+    }
+
+    #[test]
+    fn extra_16() {
+        // the scalar and flow roots do not hold the next document marker on their line
+        let src = "--- first\n--- [2]\n--- { c: 3 }\n---\nlast\n";
+
+        let sage = sage!(src);
+        let book = book!(sage);
+
+        assert_eq!(book.volumes.len(), 4);
+
+        yamlette_reckon! ( book ; book ; [ [ (first:&str) ], [ [ (two:u8) ] ], [ { "c" => (three:u8) } ], [ (last:&str) ] ] );
+
+        assert_eq!(first, Some("first"));
+        assert_eq!(two, Some(2));
+        assert_eq!(three, Some(3));
+        assert_eq!(last, Some("last"));
+    }
+
+    #[test]
+    fn extra_17() {
+        // only the plain or tagged `<<` merges, and the keys are compared by their values
+        let src = r"
+base: &base { a: 1, 100000000000000000000: big }
+plain: { <<: *base, b: 2 }
+quoted: { '<<': *base, b: 2 }
+tagged: { !!merge '<<': *base, b: 2 }
+hex: { <<: *base, 0x56BC75E2D63100000: own }
+";
+
+        let sage = sage!(src).unwrap();
+        let mut book = Book::new();
+        book.set_merge_keys(true);
+
+        for idea in &*sage {
+            book.stamp(idea);
+        }
+
+        let pairs = |path: &str| {
+            book.query(path)
+                .and_then(|ptr| ptr.into_map())
+                .map(|ptr| ptr.count_siblings() / 2)
+        };
+
+        assert_eq!(pairs("plain"), Some(3));
+        assert_eq!(pairs("quoted"), Some(2));
+        assert_eq!(pairs("tagged"), Some(3));
+        assert_eq!(pairs("hex"), Some(2));
+
+        assert_eq!(book.fetch::<&str>("hex.100000000000000000000"), Some("own"));
+        assert!(book.query("quoted.<<.a").is_some());
+
+        // a merge key not resolved is still the `<<` key
+        let sage = sage!(src);
+        let book = book!(sage);
+
+        assert!(book.query("plain.<<.a").is_some());
+        assert!(book.query("plain").and_then(|ptr| ptr.into_map()).unwrap() == "<<");
+    }
+
+    #[test]
+    fn extra_18() {
+        // a comment after the spaces ends the continuation line of a plain scalar
+        let src = "k: first\n  second  # tail\nz: x # end\n";
+
+        let sage = sage!(src);
+        let book = book!(sage);
+
+        yamlette_reckon! ( book ; book ; [[ { "k" => (k:&str), "z" => (z:&str) } ]] );
+
+        assert_eq!(k, Some("first second"));
+        assert_eq!(z, Some("x"));
+    }
+
+    #[test]
+    fn extra_19() {
+        // the indentation indicator counts from the indentation of the collection around the scalar
+        let src = "a:\n  b: |2\n      lead\n    z\n  c:\n    - |1\n       y\n";
+
+        let sage = sage!(src);
+        let book = book!(sage);
+
+        yamlette_reckon! ( book ; book ; [[ { "a" => { "b" => (b:&str), "c" => [ (c:&str) ] } } ]] );
+
+        assert_eq!(b, Some("  lead\nz\n"));
+        assert_eq!(c, Some("  y\n"));
+    }
 }
@@ -0,0 +1,160 @@
+#[cfg(all(test, not(feature = "dev")))]
+mod stable {
+    extern crate skimmer;
+    extern crate yamlette;
+
+    use self::skimmer::reader::IntoReader;
+    use self::yamlette::book::value::Value;
+    use self::yamlette::book::Book;
+    use self::yamlette::limits::{Limit, Limits};
+    use self::yamlette::model::schema::core::Core;
+    use self::yamlette::reader::{ReadError, Reader};
+    use self::yamlette::savant::Savant;
+    use self::yamlette::txt::Chunks;
+
+    const SRC: &str = r#"%YAML 1.2
+---
+plain: multi
+  line
+"double": "ünïcödé é ☃"
+'single': 'it''s'
+literal: |
+  first
+  second
+folded: >-
+  one
+  two
+flow: { a: [1, 2.5, ~], b: { c: true } }
+anchors:
+  - &x [anchored, value]
+  - *x
+  - !!str 42
+# a comment
+...
+---
+- ключ: значение
+- ? complex key
+  : 0x1F
+"#;
+
+    fn read<R>(source: R, limits: Limits) -> Result<Book, ReadError>
+    where
+        R: IntoReader,
+        R::Reader: skimmer::reader::Read,
+        <R::Reader as skimmer::reader::Read>::Datum: skimmer::Datum + 'static,
+    {
+        let mut book = Book::new();
+        let mut savant = Savant::new(Core::new());
+
+        Reader::with_limits(limits).read(source.into_reader(), &mut |block| match savant
+            .think(block)
+        {
+            Ok(Some(idea)) => {
+                book.stamp(idea);
+                Ok(())
+            }
+            Ok(None) => Ok(()),
+            Err(error) => Err(error.to_string().into()),
+        })?;
+
+        Ok(book)
+    }
+
+    fn split(src: &str, size: usize) -> Chunks<std::vec::IntoIter<Vec<u8>>> {
+        let chunks: Vec<Vec<u8>> = src.as_bytes().chunks(size).map(|c| c.to_vec()).collect();
+        Chunks::new(chunks.into_iter())
+    }
+
+    #[test]
+    fn same_as_whole() {
+        let whole = read(SRC, Limits::new()).ok().unwrap();
+
+        assert_eq!(whole.volumes.len(), 2);
+        assert!(whole.check().is_ok());
+
+        for size in (1..=17).chain(vec![64, 4096]) {
+            let book = read(split(SRC, size), Limits::new()).ok().unwrap();
+
+            assert!(book.check().is_ok(), "chunks of {}", size);
+            assert!(book.query("double").unwrap() == "ünïcödé é ☃");
+            assert!(book.query("literal").unwrap() == "first\nsecond\n");
+            assert!(book.query("/anchors/1/0").unwrap() == "anchored");
+            assert_eq!(
+                book.volumes.len(),
+                whole.volumes.len(),
+                "chunks of {}",
+                size
+            );
+
+            for (volume, expected) in book.volumes.iter().zip(whole.volumes.iter()) {
+                assert!(
+                    Value::from_volume(volume) == Value::from_volume(expected),
+                    "chunks of {}",
+                    size
+                );
+                assert_eq!(volume.spans, expected.spans, "chunks of {}", size);
+            }
+        }
+    }
+
+    #[test]
+    fn error_location() {
+        let src = "top:\n  key: { a: 1, b: 2 ] }\n";
+        let whole = read(src, Limits::new()).err().unwrap();
+
+        assert_eq!(whole.location.text, "  key: { a: 1, b: 2 ] }");
+
+        for size in 1..=8 {
+            let error = read(split(src, size), Limits::new()).err().unwrap();
+
+            assert_eq!(
+                (
+                    error.location.offset,
+                    error.location.line,
+                    error.location.column
+                ),
+                (25, 2, 21),
+                "chunks of {}",
+                size
+            );
+
+            // the chunks passed before the error may be gone, the rest of the line is there
+            assert!(
+                whole.location.text.ends_with(&error.location.text),
+                "chunks of {}",
+                size
+            );
+            assert!(error.location.text.ends_with("] }"), "chunks of {}", size);
+        }
+    }
+
+    #[test]
+    fn empty_chunks() {
+        let chunks = vec![vec![], b"a: ".to_vec(), vec![], b"1".to_vec(), vec![]];
+        let book = read(Chunks::new(chunks.into_iter()), Limits::new())
+            .ok()
+            .unwrap();
+
+        assert!(book.query("a").unwrap() == 1);
+
+        let book = read(Chunks::new(Vec::new().into_iter()), Limits::new())
+            .ok()
+            .unwrap();
+
+        assert!(book.volumes.iter().all(|volume| volume.gist.is_empty()));
+    }
+
+    #[test]
+    fn pulled_as_read() {
+        let limits = Limits {
+            max_document_size: Some(1024),
+            ..Limits::new()
+        };
+
+        // an endless stream is only read until the limit is exceeded
+        let endless = std::iter::repeat(b"- item\n".to_vec());
+        let error = read(Chunks::new(endless), limits).err().unwrap();
+
+        assert_eq!(error.limit, Some(Limit::DocumentSize));
+    }
+}
@@ -0,0 +1,267 @@
+#[cfg(all(test, not(feature = "dev")))]
+mod stable {
+    use std::env;
+    use std::fs;
+    use std::io::Write;
+    use std::path::PathBuf;
+    use std::process::{Command, Stdio};
+
+    const SRC: &str = r#"name: app
+ports: [80, 443]
+servers:
+  - host: alpha
+    note: "two\nlines"
+---
+second
+"#;
+
+    /// Runs the binary with the input on its stdin, giving back the exit code, stdout and stderr
+    fn run(args: &[&str], input: &str) -> (i32, String, String) {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_yamlette"))
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(input.as_bytes())
+            .unwrap();
+
+        let output = child.wait_with_output().unwrap();
+
+        (
+            output.status.code().unwrap(),
+            String::from_utf8(output.stdout).unwrap(),
+            String::from_utf8(output.stderr).unwrap(),
+        )
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("yamlette-cli-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn check() {
+        assert_eq!(run(&["check"], SRC), (0, String::new(), String::new()));
+
+        let (code, out, err) = run(&["check", "-"], "%YAML");
+        assert_eq!(code, 1);
+        assert_eq!(out, "");
+        assert!(err.starts_with("<stdin>:1:6: "), "{}", err);
+
+        let dir = temp_dir("check");
+        let good = dir.join("good.yaml");
+        let missing = dir.join("missing.yaml");
+        fs::write(&good, SRC).unwrap();
+
+        let (code, _, err) = run(
+            &["check", good.to_str().unwrap(), missing.to_str().unwrap()],
+            "",
+        );
+        assert_eq!(code, 1);
+        assert_eq!(err.lines().count(), 1);
+        assert!(err.starts_with(missing.to_str().unwrap()), "{}", err);
+        assert!(err.contains(": I/O error: "), "{}", err);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn fmt() {
+        let (code, out, _) = run(&["fmt"], "a:   {b: [1,   2]}\n");
+        assert_eq!(code, 0);
+        assert_eq!(out, "a:\n  b:\n    - 1\n    - 2\n");
+
+        let (_, out, _) = run(&["fmt", "--indent", "4"], "a: {b: [1, 2]}");
+        assert_eq!(out, "a:\n    b:\n        - 1\n        - 2\n");
+
+        let (_, out, _) = run(&["fmt", "--flow"], SRC);
+        assert_eq!(
+            out,
+            r#"---
+{ name: app, ports: [ 80, 443 ], servers: [ { host: alpha, note: "two\nlines" } ] }
+---
+second
+"#
+        );
+
+        // the output reads back into the same documents
+        let (_, json, _) = run(&["to-json"], &out);
+        assert_eq!(json, run(&["to-json"], SRC).1);
+
+        // the strings read back as something else from their plain form are quoted
+        let src = "a: \"true\"\nb: \"1\"\nc: \"x: y\"\nd: \"#c\"\ne: !foo \"x: y\"\n";
+        let (_, out, _) = run(&["fmt"], src);
+        assert_eq!(
+            out,
+            "a: 'true'\nb: '1'\nc: 'x: y'\nd: '#c'\ne: !<!foo> \"x: y\"\n"
+        );
+        assert_eq!(
+            run(&["to-json"], &out).1,
+            "{\"a\":\"true\",\"b\":\"1\",\"c\":\"x: y\",\"d\":\"#c\",\"e\":\"x: y\"}\n"
+        );
+
+        // the documents that cannot be written back as they are fail instead
+        for src in ["s: !!set {a, b}\n", "f: !foo [1]\n", "a: &x 1\nb: *x\n"] {
+            let (code, out, err) = run(&["fmt"], src);
+            assert_eq!(code, 1, "{}", src);
+            assert_eq!(out, "");
+            assert!(
+                err.starts_with("yamlette fmt: <stdin>: document 0: "),
+                "{}",
+                err
+            );
+        }
+
+        assert_eq!(
+            run(&["fmt"], "--- 1\n--- !foo [1]\n").2,
+            "yamlette fmt: <stdin>: document 1: The tag <!foo> of a sequence cannot be written yet\n"
+        );
+    }
+
+    #[test]
+    fn to_json() {
+        let (code, out, _) = run(&["to-json"], SRC);
+        assert_eq!(code, 0);
+        assert_eq!(
+            out,
+            r#"{"name":"app","ports":[80,443],"servers":[{"host":"alpha","note":"two\nlines"}]}
+"second"
+"#
+        );
+
+        let (_, out, _) = run(&["to-json", "--pretty"], "a: [1.5, ~]\nb: {}\n");
+        assert_eq!(
+            out,
+            "{\n  \"a\": [\n    1.5,\n    null\n  ],\n  \"b\": {}\n}\n"
+        );
+
+        let (_, out, _) = run(&["to-json"], "? 1\n: one\nbin: !!binary aGVsbG8=\n");
+        assert_eq!(out, "{\"1\":\"one\",\"bin\":\"aGVsbG8=\"}\n");
+
+        let (code, _, err) = run(&["to-json"], "? [1]\n: list\n");
+        assert_eq!(code, 1);
+        assert!(err.contains("collection as a key"), "{}", err);
+
+        let (code, _, _) = run(&["to-json"], "inf: .inf\n");
+        assert_eq!(code, 1);
+
+        let (code, out, err) = run(&["to-json"], "--- 1\n--- &a [ *a ]\n");
+        assert_eq!(code, 1);
+        assert_eq!(out, "");
+        assert_eq!(
+            err,
+            "yamlette to-json: <stdin>: document 1: An alias refers to a collection containing it\n"
+        );
+
+        // the scalars of the types with no value of their own are their text
+        let (code, out, _) = run(&["to-json"], "%YAML 1.1\n---\na: =\nb: !!yaml \"!\"\n");
+        assert_eq!(code, 0);
+        assert_eq!(out, "{\"a\":\"=\",\"b\":\"!\"}\n");
+    }
+
+    #[test]
+    fn from_json() {
+        let (code, out, _) = run(&["from-json"], r#"{"a": [1, "b", null], "c": {"d": true}}"#);
+        assert_eq!(code, 0);
+        assert_eq!(out, "a:\n  - 1\n  - b\n  - ~\nc:\n  d: true\n");
+
+        let (_, out, _) = run(&["from-json", "--flow"], r#"{"a": [1, 2]}"#);
+        assert_eq!(out, "{ a: [ 1, 2 ] }\n");
+
+        let (_, out, _) = run(&["from-json"], r#"{"a":"1","b":"null"}"#);
+        assert_eq!(out, "a: '1'\nb: 'null'\n");
+        assert_eq!(run(&["to-json"], &out).1, "{\"a\":\"1\",\"b\":\"null\"}\n");
+
+        // plain strings are not JSON
+        let (code, out, err) = run(&["from-json"], "a: b");
+        assert_eq!(code, 1);
+        assert_eq!(out, "");
+        assert_eq!(
+            err,
+            "yamlette from-json: <stdin>:1:1: Cannot resolve the scalar \"a\": not a JSON-compatible plain scalar\n"
+        );
+    }
+
+    #[test]
+    fn get() {
+        assert_eq!(
+            run(&["get", "name"], SRC),
+            (0, String::from("app\n"), String::new())
+        );
+        assert_eq!(run(&["get", "/ports/1"], SRC).1, "443\n");
+        assert_eq!(run(&["get", "servers[0].note"], SRC).1, "two\nlines\n");
+        assert_eq!(run(&["get", "ports"], SRC).1, "- 80\n- 443\n");
+        assert_eq!(run(&["get", "ports", "--flow"], SRC).1, "[ 80, 443 ]\n");
+        assert_eq!(
+            run(&["get", "servers", "--json"], SRC).1,
+            "[{\"host\":\"alpha\",\"note\":\"two\\nlines\"}]\n"
+        );
+        assert_eq!(run(&["get", "", "--doc", "1"], SRC).1, "second\n");
+
+        let (code, out, err) = run(&["get", "servers[1]"], SRC);
+        assert_eq!(code, 1);
+        assert_eq!(out, "");
+        assert_eq!(err, "<stdin>: `servers[1]` is not found\n");
+    }
+
+    #[test]
+    fn docs() {
+        assert_eq!(run(&["docs"], SRC).1, "2\n");
+        assert_eq!(run(&["docs"], "").1, "0\n");
+        assert_eq!(run(&["docs"], "--- 1\n--- 2\n--- 3\n").1, "3\n");
+        assert_eq!(run(&["docs"], "--- 1\n---\n--- 3\n").1, "3\n");
+        assert_eq!(run(&["docs"], "---\n").1, "1\n");
+        assert_eq!(run(&["docs"], "# c\n...\n").1, "0\n");
+
+        let dir = temp_dir("docs");
+        let prefix = format!("{}/part-", dir.to_str().unwrap());
+
+        let (code, out, _) = run(&["docs", "--split", &prefix], SRC);
+        assert_eq!(code, 0);
+        assert_eq!(out, format!("{0}0.yaml\n{0}1.yaml\n", prefix));
+        assert_eq!(
+            fs::read_to_string(format!("{}0.yaml", prefix)).unwrap(),
+            "name: app\nports:\n  - 80\n  - 443\nservers:\n  - host: alpha\n    note: \"two\\nlines\"\n"
+        );
+        assert_eq!(
+            fs::read_to_string(format!("{}1.yaml", prefix)).unwrap(),
+            "second\n"
+        );
+
+        let (code, _, err) = run(&["docs", "--split", &prefix], "--- 1\n--- &a 2\n");
+        assert_eq!(code, 1);
+        assert_eq!(
+            err,
+            "yamlette docs: <stdin>: document 1: The anchor &a cannot be written yet\n"
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn usage() {
+        assert_eq!(run(&["help"], "").0, 0);
+
+        for args in vec![
+            vec![],
+            vec!["frob"],
+            vec!["get"],
+            vec!["to-json", "--flow"],
+            vec!["fmt", "--indent", "wide"],
+            vec!["fmt", "a.yaml", "b.yaml"],
+        ] {
+            let (code, out, err) = run(&args, "");
+            assert_eq!(code, 2, "{:?}", args);
+            assert_eq!(out, "");
+            assert!(err.starts_with("yamlette: "), "{}", err);
+        }
+    }
+}
@@ -11,7 +11,7 @@ macro_rules! composer {
         );
 
         reader.read().unwrap_or_else(|err| {
-            assert!(false, "Unexpected result: {}, :{}", err, err.position);
+            assert!(false, "Unexpected result: {}, :{}", err, err.location);
         });
 
         let composer = composer.unwrap();
@@ -0,0 +1,240 @@
+#[cfg(all(test, feature = "serde", not(feature = "dev")))]
+mod stable {
+    extern crate serde;
+    extern crate skimmer;
+    extern crate yamlette;
+
+    use self::serde::Deserialize;
+    use self::skimmer::reader::SliceReader;
+
+    use self::yamlette::book::de::{from_book, from_pointer, from_str, from_str_with_limits};
+    use self::yamlette::book::extractor::Pointer;
+    use self::yamlette::book::Book;
+    use self::yamlette::error::{Error, ErrorKind};
+    use self::yamlette::limits::{Limit, Limits};
+    use self::yamlette::model::schema::core::Core;
+    use self::yamlette::reader::Reader;
+    use self::yamlette::savant::Savant;
+
+    use std::borrow::Cow;
+    use std::collections::BTreeMap;
+
+    fn book(src: &'static str) -> Book {
+        let mut book = Book::new();
+        let mut reader = Reader::new();
+        let mut savant = Savant::new(Core::new());
+
+        reader
+            .read(
+                SliceReader::new(src.as_bytes()),
+                &mut |block| match savant.think(block) {
+                    Ok(Some(idea)) => {
+                        book.stamp(idea);
+                        Ok(())
+                    }
+                    Ok(None) => Ok(()),
+                    Err(_) => Err(Cow::from("Cannot think of a block")),
+                },
+            )
+            .ok()
+            .unwrap();
+
+        book
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Server<'a> {
+        host: &'a str,
+        port: u16,
+        secure: bool,
+        weight: f64,
+        tags: Vec<String>,
+        backup: Option<String>,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    enum Action {
+        Stop,
+        Restart(u8),
+        Scale { min: u8, max: u8 },
+        Route(String, u16),
+    }
+
+    #[test]
+    fn struct_borrowed() {
+        let book = book(
+            r#"
+host: localhost
+port: 8080
+secure: true
+weight: 0.5
+tags: [a, b]
+backup: ~
+"#,
+        );
+
+        let server: Server = from_book(&book).map_err(|e| e.to_string()).unwrap();
+
+        assert_eq!(
+            server,
+            Server {
+                host: "localhost",
+                port: 8080,
+                secure: true,
+                weight: 0.5,
+                tags: vec!["a".to_string(), "b".to_string()],
+                backup: None,
+            }
+        );
+    }
+
+    #[test]
+    fn aliases() {
+        let book = book(
+            r#"
+base: &base
+  min: 1
+  max: 3
+actions:
+  - Stop
+  - Restart: 5
+  - Scale: *base
+  - Route: [gateway, 443]
+"#,
+        );
+
+        let ptr = Pointer::new(&book.volumes[0]).unwrap();
+
+        let mut ptr = ptr.into_map();
+        let mut actions: Option<Vec<Action>> = None;
+
+        while let Some(key) = ptr {
+            let val = key.next_sibling().unwrap();
+            if key == "actions" {
+                actions = from_pointer(val).ok();
+            }
+            ptr = val.next_sibling();
+        }
+
+        assert_eq!(
+            actions,
+            Some(vec![
+                Action::Stop,
+                Action::Restart(5),
+                Action::Scale { min: 1, max: 3 },
+                Action::Route("gateway".to_string(), 443),
+            ])
+        );
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Nested(Vec<Nested>);
+
+    #[derive(Debug, Deserialize)]
+    enum Chain {
+        Next(Box<Chain>),
+    }
+
+    #[test]
+    fn recursive_aliases() {
+        let result: Result<Nested, _> = from_str("&a [ *a ]");
+        assert_eq!(
+            result.err().map(|err| err.to_string()),
+            Some("The alias refers to a collection containing it".to_string())
+        );
+
+        let result: Result<Nested, _> = from_str("&a [ [ [ *a ] ] ]");
+        assert_eq!(
+            result.err().map(|err| err.to_string()),
+            Some("The alias refers to a collection containing it".to_string())
+        );
+
+        let result: Result<Chain, _> = from_str("&a { Next: *a }");
+        assert_eq!(
+            result.err().map(|err| err.to_string()),
+            Some("The alias refers to a collection containing it".to_string())
+        );
+
+        // the same anchor may be expanded any number of times side by side
+        let result: Result<Vec<Vec<u8>>, _> = from_str("[ &a [1, 2], *a, *a ]");
+        assert_eq!(result.ok(), Some(vec![vec![1, 2], vec![1, 2], vec![1, 2]]));
+
+        // and within other collections, as long as it is not within itself
+        let result: Result<Vec<Vec<Vec<u8>>>, _> = from_str("[ [ &a [1, 2] ], [ *a, *a ] ]");
+        assert_eq!(
+            result.ok(),
+            Some(vec![vec![vec![1, 2]], vec![vec![1, 2], vec![1, 2]]])
+        );
+    }
+
+    #[test]
+    fn limits() {
+        // two levels in the source, five with the aliases expanded
+        let chain = "- &a []\n- &b [*a]\n- &c [*b]\n- [*c]\n";
+
+        let limits = Limits {
+            max_depth: Some(4),
+            ..Limits::new()
+        };
+
+        let error = from_str_with_limits::<Nested>(chain, limits).err().unwrap();
+
+        assert_eq!(error.limit().map(|e| e.limit), Some(Limit::Depth));
+        assert_eq!(Error::from(error).kind(), ErrorKind::Limit(Limit::Depth));
+
+        let limits = Limits {
+            max_depth: Some(5),
+            ..Limits::new()
+        };
+
+        assert!(from_str_with_limits::<Nested>(chain, limits).is_ok());
+
+        let limits = Limits {
+            max_nodes: Some(7),
+            ..Limits::new()
+        };
+
+        let error = from_str_with_limits::<Nested>(chain, limits).err().unwrap();
+        assert_eq!(error.limit().map(|e| e.limit), Some(Limit::Nodes));
+
+        let limits = Limits {
+            max_aliases: Some(2),
+            ..Limits::new()
+        };
+
+        let error = from_str_with_limits::<Nested>(chain, limits).err().unwrap();
+        assert_eq!(
+            error.limit().map(|e| (e.limit, e.max)),
+            Some((Limit::Aliases, 2))
+        );
+
+        assert!(from_str::<Nested>(chain).is_ok());
+    }
+
+    #[test]
+    fn owned_from_str() {
+        let map: BTreeMap<String, Vec<i64>> = from_str("{ a: [1, 2], b: [] }").ok().unwrap();
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["a"], vec![1, 2]);
+        assert!(map["b"].is_empty());
+
+        let map: BTreeMap<String, u8> = from_str("{ <<: 1 }").ok().unwrap();
+        assert_eq!(map["<<"], 1);
+    }
+
+    #[test]
+    fn type_mismatch() {
+        let result: Result<Vec<u8>, _> = from_str("{ a: 1 }");
+        assert!(result.is_err());
+
+        let result: Result<u8, _> = from_str("300");
+        assert!(result.is_err());
+
+        let result: Result<u8, _> = from_str("# yaml\n%YAML 1.2\n%YAML 1.2\n---\n1\n");
+        assert_eq!(
+            result.err().map(|err| err.to_string()),
+            Some("The YAML directive must only be given at most once per document at line 3, column 1".to_string())
+        );
+    }
+}
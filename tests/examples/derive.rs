@@ -0,0 +1,291 @@
+#[cfg(all(test, feature = "derive", not(feature = "dev")))]
+mod stable {
+    extern crate yamlette;
+
+    use self::yamlette::book::extractor::{FromPointer, Pointer};
+    use self::yamlette::model::style::FLOW;
+    use self::yamlette::model::yaml::str::FORCE_QUOTES;
+    use self::yamlette::orchestra::chord::Chord;
+
+    #[derive(Debug, PartialEq, FromPointer)]
+    struct Server<'a> {
+        name: &'a str,
+        #[yamlette(rename = "at")]
+        position: Point,
+        #[yamlette(default)]
+        weight: u32,
+        #[yamlette(default = "default_proto")]
+        proto: String,
+        note: Option<String>,
+        #[yamlette(flatten)]
+        limits: Limits,
+        #[yamlette(skip)]
+        hits: u64,
+    }
+
+    #[derive(Debug, PartialEq, FromPointer)]
+    #[yamlette(rename_all = "kebab-case")]
+    struct Limits {
+        max_conn: Option<u32>,
+    }
+
+    #[derive(Debug, PartialEq, FromPointer, Chord)]
+    struct Point(i32, i32);
+
+    #[derive(Debug, PartialEq, FromPointer, Chord)]
+    struct Meters(f64);
+
+    #[derive(Debug, PartialEq, FromPointer, Chord)]
+    #[yamlette(rename_all = "lowercase")]
+    enum Action {
+        Stop,
+        Move(Point),
+        Jump { height: Meters },
+    }
+
+    #[derive(Debug, PartialEq, FromPointer)]
+    #[yamlette(tag = "kind")]
+    enum Shape {
+        #[yamlette(yaml_tag = "!circle")]
+        Circle {
+            r: u32,
+        },
+        #[yamlette(rename = "square")]
+        Square {
+            side: u32,
+        },
+        Empty,
+    }
+
+    #[derive(Debug, PartialEq, FromPointer, Chord)]
+    #[yamlette(tag = "kind", rename_all = "snake_case")]
+    enum Event {
+        Started { at: String },
+        Stopped,
+    }
+
+    #[derive(Debug, PartialEq, FromPointer, Chord)]
+    #[yamlette(rename_all = "camelCase")]
+    struct Service {
+        #[yamlette(style(FORCE_QUOTES))]
+        display_name: String,
+        #[yamlette(rename = "at", style(FLOW))]
+        position: Point,
+        max_conn: Option<u32>,
+        #[yamlette(skip)]
+        hits: u64,
+        #[yamlette(style(FLOW))]
+        events: Events,
+        #[yamlette(style(FLOW))]
+        actions: Actions,
+    }
+
+    #[derive(Debug, PartialEq, FromPointer, Chord)]
+    struct Actions(Action, Action);
+
+    #[derive(Debug, PartialEq, FromPointer, Chord)]
+    struct Events(Event, Event);
+
+    #[derive(Debug, PartialEq, Chord)]
+    #[yamlette(style(FLOW))]
+    struct Pair<T> {
+        left: T,
+        right: T,
+    }
+
+    fn default_proto() -> String {
+        String::from("tcp")
+    }
+
+    #[test]
+    fn structs() {
+        let src = r#"
+alpha:
+  name: alpha
+  at: [80, 443]
+  weight: 5
+  proto: udp
+  note: primary
+  max-conn: 100
+beta:
+  name: beta
+  at: [8080, 0]
+  note: ~
+  hits: 12
+broken:
+  at: [22, 0]
+"#;
+
+        yamlette! ( read ; src ; [[ ]] ; { book: book } );
+
+        let root = Pointer::new(&book.volumes[0]).unwrap();
+
+        assert_eq!(
+            root.fetch::<Server>("alpha"),
+            Some(Server {
+                name: "alpha",
+                position: Point(80, 443),
+                weight: 5,
+                proto: String::from("udp"),
+                note: Some(String::from("primary")),
+                limits: Limits {
+                    max_conn: Some(100)
+                },
+                hits: 0,
+            })
+        );
+
+        assert_eq!(
+            root.fetch::<Server>("beta"),
+            Some(Server {
+                name: "beta",
+                position: Point(8080, 0),
+                weight: 0,
+                proto: String::from("tcp"),
+                note: None,
+                limits: Limits { max_conn: None },
+                hits: 0,
+            })
+        );
+
+        assert_eq!(root.fetch::<Server>("broken"), None);
+    }
+
+    #[test]
+    fn tuples() {
+        yamlette! ( read ; "[[1, 2], [1, 2, 3], 2.5, ~]" ; [[ ]] ; { book: book } );
+
+        let root = Pointer::new(&book.volumes[0]).unwrap();
+
+        assert_eq!(root.fetch::<Point>("0"), Some(Point(1, 2)));
+        assert_eq!(root.fetch::<Point>("1"), None);
+        assert_eq!(root.fetch::<Meters>("2"), Some(Meters(2.5)));
+        assert_eq!(root.fetch::<Meters>("3"), None);
+    }
+
+    #[test]
+    fn external_enums() {
+        yamlette! ( read ; "[stop, {move: [3, 4]}, {jump: {height: 1.5}}, walk, {stop: ~, move: [0, 0]}]" ; [[ ]] ; { book: book } );
+
+        let root = Pointer::new(&book.volumes[0]).unwrap();
+
+        assert_eq!(root.fetch::<Action>("0"), Some(Action::Stop));
+        assert_eq!(root.fetch::<Action>("1"), Some(Action::Move(Point(3, 4))));
+        assert_eq!(
+            root.fetch::<Action>("2"),
+            Some(Action::Jump {
+                height: Meters(1.5)
+            })
+        );
+        assert_eq!(root.fetch::<Action>("3"), None);
+        assert_eq!(root.fetch::<Action>("4"), None);
+    }
+
+    #[test]
+    fn internal_enums() {
+        let src = r#"
+- !circle { r: 2 }
+- { kind: square, side: 3 }
+- { kind: Empty }
+- { kind: hexagon }
+- { side: 3 }
+"#;
+
+        yamlette! ( read ; src ; [[ ]] ; { book: book } );
+
+        let root = Pointer::new(&book.volumes[0]).unwrap();
+
+        assert_eq!(root.fetch::<Shape>("0"), Some(Shape::Circle { r: 2 }));
+        assert_eq!(root.fetch::<Shape>("1"), Some(Shape::Square { side: 3 }));
+        assert_eq!(root.fetch::<Shape>("2"), Some(Shape::Empty));
+        assert_eq!(root.fetch::<Shape>("3"), None);
+        assert_eq!(root.fetch::<Shape>("4"), None);
+    }
+
+    #[test]
+    fn reckon() {
+        yamlette! ( read ; "{name: gamma, at: [22, 0]}" ; [[ (server:Server) ]] );
+
+        let server = server.unwrap();
+
+        assert_eq!(server.name, "gamma");
+        assert_eq!(server.position, Point(22, 0));
+    }
+
+    fn write<T: Chord>(value: T) -> String {
+        yamlette! ( write ; [[ (value) ]] ).ok().unwrap()
+    }
+
+    #[test]
+    fn chord_structs() {
+        let service = Service {
+            display_name: String::from("api"),
+            position: Point(1, -2),
+            max_conn: None,
+            hits: 7,
+            actions: Actions(
+                Action::Stop,
+                Action::Jump {
+                    height: Meters(0.5),
+                },
+            ),
+            events: Events(
+                Event::Started {
+                    at: String::from("noon"),
+                },
+                Event::Stopped,
+            ),
+        };
+
+        let size = service.chord_size();
+        let result = write(service);
+
+        assert_eq!(
+            result,
+            r#"displayName: 'api'
+at: [ 1, -2 ]
+events: [ { kind: started, at: noon }, { kind: stopped } ]
+actions: [ stop, { jump: { height: 0.5 } } ]
+"#
+        );
+        assert_eq!(size, 25);
+
+        yamlette! ( read ; result ; [[ (restored:Service) ]] );
+
+        assert_eq!(
+            restored,
+            Some(Service {
+                display_name: String::from("api"),
+                position: Point(1, -2),
+                max_conn: None,
+                hits: 0,
+                actions: Actions(
+                    Action::Stop,
+                    Action::Jump {
+                        height: Meters(0.5)
+                    }
+                ),
+                events: Events(
+                    Event::Started {
+                        at: String::from("noon")
+                    },
+                    Event::Stopped
+                ),
+            })
+        );
+    }
+
+    #[test]
+    fn chord_enums() {
+        assert_eq!(write(Action::Move(Point(3, 4))), "move:\n  - 3\n  - 4\n");
+        assert_eq!(write(Pair { left: 1, right: 2 }), "{ left: 1, right: 2 }");
+        assert_eq!(
+            Pair {
+                left: Meters(1.0),
+                right: Meters(2.0)
+            }
+            .chord_size(),
+            5
+        );
+    }
+}
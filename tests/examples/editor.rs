@@ -0,0 +1,303 @@
+#[cfg(all(test, not(feature = "dev")))]
+mod stable {
+    extern crate yamlette;
+
+    use self::yamlette::book::value::Value;
+    use self::yamlette::editor::{EditError, Editor};
+
+    const CONFIG: &str = r#"# Deployment settings
+version: 1.4.2   # bumped by the release bot
+
+image:
+  name: 'registry/app'
+  tag: "v1.4.2"
+
+replicas: 3
+ports: [80, 443]
+
+env:
+  - RUST_LOG=info
+  - MODE=production   # keep in sync with the chart
+
+empty:
+notes: |
+  First line
+  Second line
+"#;
+
+    fn editor() -> Editor {
+        Editor::new(CONFIG).ok().unwrap()
+    }
+
+    #[test]
+    fn untouched() {
+        let editor = editor();
+
+        assert_eq!(editor.source(), CONFIG);
+        assert_eq!(
+            editor.text(editor.find(&["image", "tag"]).unwrap().span()),
+            "\"v1.4.2\""
+        );
+        assert_eq!(
+            editor.text(editor.find(&["env", "1"]).unwrap().span()),
+            "MODE=production"
+        );
+        assert!(editor.find(&["env", "2"]).is_none());
+        assert!(editor.find(&["image", "digest"]).is_none());
+    }
+
+    #[test]
+    fn set_scalars() {
+        let mut editor = editor();
+
+        editor.set(&["version"], Value::from("1.5.0")).ok().unwrap();
+        editor
+            .set(&["image", "name"], Value::from("mirror/app's"))
+            .ok()
+            .unwrap();
+        editor
+            .set(&["image", "tag"], Value::from("v1.5.0"))
+            .ok()
+            .unwrap();
+        editor.set(&["replicas"], Value::from(5)).ok().unwrap();
+        editor.set(&["ports", "1"], Value::from(8443)).ok().unwrap();
+        editor
+            .set(&["env", "1"], Value::from("MODE=staging"))
+            .ok()
+            .unwrap();
+        editor.set(&["empty"], Value::from(true)).ok().unwrap();
+
+        assert_eq!(
+            editor.source(),
+            r#"# Deployment settings
+version: 1.5.0   # bumped by the release bot
+
+image:
+  name: 'mirror/app''s'
+  tag: "v1.5.0"
+
+replicas: 5
+ports: [80, 8443]
+
+env:
+  - RUST_LOG=info
+  - MODE=staging   # keep in sync with the chart
+
+empty: true
+notes: |
+  First line
+  Second line
+"#
+        );
+
+        assert!(editor.find(&["image", "name"]).unwrap() == "mirror/app's");
+    }
+
+    #[test]
+    fn set_block_scalar() {
+        let mut editor = editor();
+
+        editor
+            .set(&["notes"], Value::from("Only line\n"))
+            .ok()
+            .unwrap();
+
+        assert!(editor.source().ends_with("empty:\nnotes: |\n  Only line\n"));
+        assert!(editor.find(&["notes"]).unwrap() == "Only line\n");
+
+        // the blank lines kept at the end belong to the scalar
+        let mut editor = Editor::new("a: |+\n  one\n\nb: 2\n").ok().unwrap();
+
+        editor.set(&["a"], Value::from("x\n\n")).ok().unwrap();
+        assert_eq!(editor.source(), "a: |+\n  x\n\nb: 2\n");
+        assert!(editor.find(&["a"]).unwrap() == "x\n\n");
+
+        editor.set(&["a"], Value::from("y\n")).ok().unwrap();
+        assert_eq!(editor.source(), "a: |\n  y\nb: 2\n");
+        assert!(editor.find(&["a"]).unwrap() == "y\n");
+    }
+
+    #[test]
+    fn set_ambiguous_strings() {
+        // the plain form of these would be read back as something else
+        for string in ["a: b", "x # y", "- z", "true", "1", "", "[1]", "#c"] {
+            let mut editor = Editor::new("k: v   # note\nn: 1\n").ok().unwrap();
+
+            editor.set(&["k"], Value::from(string)).ok().unwrap();
+            assert!(editor.find(&["k"]).unwrap() == string, "{:?}", string);
+            assert!(editor.find(&["n"]).unwrap() == 1);
+            assert!(editor.source().ends_with("   # note\nn: 1\n"));
+        }
+
+        let mut editor = Editor::new("k: v   # note\nn: 1\n").ok().unwrap();
+
+        editor.set(&["k"], Value::from("plain text")).ok().unwrap();
+        assert_eq!(editor.source(), "k: plain text   # note\nn: 1\n");
+
+        editor.set(&["k"], Value::from("a: b")).ok().unwrap();
+        assert_eq!(editor.source(), "k: 'a: b'   # note\nn: 1\n");
+    }
+
+    #[test]
+    fn set_block_scalar_indentation() {
+        let mut editor = Editor::new("a:\n  b: |\n    text\n  c: 1\n").ok().unwrap();
+
+        editor
+            .set(&["a", "b"], Value::from("  lead\nz\n"))
+            .ok()
+            .unwrap();
+
+        assert_eq!(editor.source(), "a:\n  b: |2\n      lead\n    z\n  c: 1\n");
+        assert!(editor.find(&["a", "b"]).unwrap() == "  lead\nz\n");
+        assert!(editor.find(&["a", "c"]).unwrap() == 1);
+
+        let mut editor = Editor::new("b: >-\n  text\nc: 1\n").ok().unwrap();
+
+        editor.set(&["b"], Value::from(" lead\nz")).ok().unwrap();
+
+        assert_eq!(editor.source(), "b: |2-\n   lead\n  z\nc: 1\n");
+        assert!(editor.find(&["b"]).unwrap() == " lead\nz");
+    }
+
+    #[test]
+    fn set_collections() {
+        let mut editor = editor();
+
+        let mut image = Value::Null;
+        image.insert("name", "app");
+        image.insert("tag", "latest");
+
+        editor.set(&["image"], image).ok().unwrap();
+        editor
+            .set(&["ports"], Value::from(vec![8080]))
+            .ok()
+            .unwrap();
+
+        assert!(editor
+            .source()
+            .starts_with("# Deployment settings\nversion: 1.4.2   # bumped by the release bot\n\nimage:\n  { name: app, tag: latest }\n\nreplicas: 3\nports: [ 8080 ]\n"));
+
+        assert!(editor.find(&["image", "tag"]).unwrap() == "latest");
+    }
+
+    #[test]
+    fn insert_and_push() {
+        let mut editor = editor();
+
+        editor
+            .insert(&["image"], "pull", Value::from("always"))
+            .ok()
+            .unwrap();
+        editor
+            .insert(&[], "debug", Value::from(false))
+            .ok()
+            .unwrap();
+        editor.insert(&[], "replicas", Value::from(4)).ok().unwrap();
+        editor
+            .push(&["env"], Value::from("REGION=eu"))
+            .ok()
+            .unwrap();
+        editor.push(&["ports"], Value::from(8080)).ok().unwrap();
+
+        assert_eq!(
+            editor.source(),
+            r#"# Deployment settings
+version: 1.4.2   # bumped by the release bot
+
+image:
+  name: 'registry/app'
+  tag: "v1.4.2"
+  pull: always
+
+replicas: 4
+ports: [80, 443, 8080]
+
+env:
+  - RUST_LOG=info
+  - MODE=production   # keep in sync with the chart
+  - REGION=eu
+
+empty:
+notes: |
+  First line
+  Second line
+debug: false
+"#
+        );
+    }
+
+    #[test]
+    fn remove() {
+        let mut editor = editor();
+
+        editor.remove(&["image", "tag"]).ok().unwrap();
+        editor.remove(&["env", "0"]).ok().unwrap();
+        editor.remove(&["ports", "0"]).ok().unwrap();
+        editor.remove(&["notes"]).ok().unwrap();
+
+        assert_eq!(
+            editor.source(),
+            r#"# Deployment settings
+version: 1.4.2   # bumped by the release bot
+
+image:
+  name: 'registry/app'
+
+replicas: 3
+ports: [443]
+
+env:
+  - MODE=production   # keep in sync with the chart
+
+empty:
+"#
+        );
+
+        match editor.remove(&["image", "tag"]) {
+            Err(EditError::NotFound) => (),
+            _ => assert!(false, "Unexpected result"),
+        };
+    }
+
+    #[test]
+    fn flow_collections() {
+        let mut editor = Editor::new("point: {x: 1, y: 2}\nlist: []\n").ok().unwrap();
+
+        editor.remove(&["point", "y"]).ok().unwrap();
+        editor.insert(&["point"], "z", Value::from(3)).ok().unwrap();
+        editor.push(&["list"], Value::from("a")).ok().unwrap();
+        editor.push(&["list"], Value::from("b")).ok().unwrap();
+
+        assert_eq!(editor.source(), "point: {x: 1, z: 3}\nlist: [a, b]\n");
+    }
+
+    #[test]
+    fn multiline_plain_comment() {
+        let mut editor = Editor::new("k: first\n  second  # tail\nz: 1\n")
+            .ok()
+            .unwrap();
+
+        assert_eq!(
+            editor.text(editor.find(&["k"]).unwrap().span()),
+            "first\n  second"
+        );
+
+        editor.set(&["k"], Value::from("new")).ok().unwrap();
+
+        assert_eq!(editor.source(), "k: new  # tail\nz: 1\n");
+    }
+
+    #[test]
+    fn invalid_edit() {
+        let mut editor = editor();
+        let span = editor.find(&["replicas"]).unwrap().span();
+
+        assert!(editor.replace(span, "*nowhere").is_err());
+        assert_eq!(editor.source(), CONFIG);
+
+        match editor.insert(&["replicas"], "key", Value::Null) {
+            Err(EditError::Unsupported(_)) => (),
+            _ => assert!(false, "Unexpected result"),
+        };
+    }
+}
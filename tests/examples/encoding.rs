@@ -0,0 +1,188 @@
+#[cfg(all(test, not(feature = "dev")))]
+mod stable {
+    extern crate yamlette;
+
+    use self::yamlette::model::schema::core::Core;
+    use self::yamlette::orchestra::Orchestra;
+    use self::yamlette::txt::encoding::{UTF16LE, UTF32BE, UTF8};
+    use self::yamlette::txt::{DecodeError, Decoder, Encoding};
+
+    use std::io::{self, Cursor, Read};
+
+    const SRC: &str = "%YAML 1.2\n---\nname: Mark McGwire\nhr: 65\nnames: [ключ, 😁]\n";
+
+    fn to_utf16(bom: &[u8], be: bool) -> Vec<u8> {
+        let mut bytes = bom.to_vec();
+        for unit in SRC.encode_utf16() {
+            bytes.extend_from_slice(&if be {
+                unit.to_be_bytes()
+            } else {
+                unit.to_le_bytes()
+            });
+        }
+        bytes
+    }
+
+    fn to_utf32(bom: &[u8], be: bool) -> Vec<u8> {
+        let mut bytes = bom.to_vec();
+        for chr in SRC.chars() {
+            bytes.extend_from_slice(&if be {
+                (chr as u32).to_be_bytes()
+            } else {
+                (chr as u32).to_le_bytes()
+            });
+        }
+        bytes
+    }
+
+    fn check(bytes: Vec<u8>, encoding: &str) {
+        assert_eq!(encoding, Encoding::detect(&bytes).name());
+
+        let source = Decoder::new(Cursor::new(bytes)).decode().ok().unwrap();
+
+        yamlette! ( read ; source ; [[ { "name" => (name:&str), "hr" => (hr:u8), "names" => [ (key:&str), (smile:&str) ] } ]] ; { result: result } );
+
+        assert!(result.is_ok());
+        assert_eq!(name, Some("Mark McGwire"));
+        assert_eq!(hr, Some(65));
+        assert_eq!(key, Some("ключ"));
+        assert_eq!(smile, Some("😁"));
+    }
+
+    #[test]
+    fn utf8() {
+        check(SRC.as_bytes().to_vec(), "UTF-8");
+        check([&[0xEF, 0xBB, 0xBF], SRC.as_bytes()].concat(), "UTF-8");
+    }
+
+    #[test]
+    fn utf16_with_bom() {
+        check(to_utf16(&[0xFF, 0xFE], false), "UTF-16LE");
+        check(to_utf16(&[0xFE, 0xFF], true), "UTF-16BE");
+    }
+
+    #[test]
+    fn utf16_without_bom() {
+        check(to_utf16(&[], false), "UTF-16LE");
+        check(to_utf16(&[], true), "UTF-16BE");
+    }
+
+    #[test]
+    fn utf32() {
+        check(to_utf32(&[0xFF, 0xFE, 0x00, 0x00], false), "UTF-32LE");
+        check(to_utf32(&[0x00, 0x00, 0xFE, 0xFF], true), "UTF-32BE");
+        check(to_utf32(&[], false), "UTF-32LE");
+        check(to_utf32(&[], true), "UTF-32BE");
+    }
+
+    #[test]
+    fn undecoded() {
+        let source: String = "name: Mark McGwire\n"
+            .chars()
+            .flat_map(|chr| vec![chr, '\0'])
+            .collect();
+
+        yamlette! ( read ; source ; [[ { "name" => (name:&str) } ]] ; { result: result } );
+
+        assert_eq!(name, None);
+
+        match result {
+            Err(Err(error)) => assert!(error.description.contains("UTF-16LE")),
+            _ => assert!(false, "the stream must not be read"),
+        }
+    }
+
+    const OUT: &str = "- Mark McGwire\n- ключ\n- 😁\n";
+
+    #[test]
+    fn write_utf16_with_bom() {
+        let music = yamlette! ( write bytes ; [[ [ "Mark McGwire", "ключ", "😁" ] ]] ; { encoding: Encoding::UTF16LE(UTF16LE), bom: true } ).ok().unwrap();
+
+        let mut expected = vec![0xFF, 0xFE];
+        for unit in OUT.encode_utf16() {
+            expected.extend_from_slice(&unit.to_le_bytes());
+        }
+
+        assert_eq!(expected, music);
+
+        let source = Decoder::new(Cursor::new(music)).decode().ok().unwrap();
+
+        yamlette! ( read ; source ; [[ [ (mark:&str), (key:&str), (smile:&str) ] ]] );
+
+        assert_eq!(mark, Some("Mark McGwire"));
+        assert_eq!(key, Some("ключ"));
+        assert_eq!(smile, Some("😁"));
+    }
+
+    #[test]
+    fn write_utf32() {
+        let mut orchestra = Orchestra::solo(Core::new());
+        orchestra.set_encoding(Encoding::UTF32BE(UTF32BE));
+
+        let music = yamlette! ( write bytes ; warm ; &mut orchestra ; [[ [ "Mark McGwire", "ключ", "😁" ] ]] ).ok().unwrap();
+
+        let expected: Vec<u8> = OUT
+            .chars()
+            .flat_map(|chr| (chr as u32).to_be_bytes())
+            .collect();
+
+        assert_eq!(expected, music);
+        assert_eq!("UTF-32BE", Encoding::detect(&music).name());
+    }
+
+    #[test]
+    fn write_utf8_with_bom() {
+        let music = yamlette! ( write ; [[ [ "Mark McGwire", "ключ", "😁" ] ]] ; { encoding: Encoding::UTF8(UTF8), bom: true } ).ok().unwrap();
+
+        assert_eq!(format!("\u{FEFF}{}", OUT), music);
+
+        let music = yamlette! ( write ; [[ [ "Mark McGwire" ] ]] ; { encoding: Encoding::UTF16LE(UTF16LE) } );
+
+        assert!(music.is_err());
+    }
+
+    #[test]
+    fn write_into_utf16_with_bom() {
+        let music = yamlette! ( write bytes ; [[ [ "Mark McGwire", "ключ", "😁" ], [ "Mark McGwire" ] ]] ; { encoding: Encoding::UTF16LE(UTF16LE), bom: true } ).ok().unwrap();
+
+        let sink = yamlette! ( write into ; Vec::new() ; [[ [ "Mark McGwire", "ключ", "😁" ], [ "Mark McGwire" ] ]] ; { encoding: Encoding::UTF16LE(UTF16LE), bom: true } ).ok().unwrap();
+
+        assert_eq!(music, sink);
+        assert_eq!(&[0xFF, 0xFE], &sink[..2]);
+    }
+
+    #[test]
+    fn encode_invalid() {
+        let encoding = Encoding::UTF16LE(UTF16LE);
+
+        assert_eq!(
+            encoding.encode(b"a".to_vec(), true).ok(),
+            Some(vec![0xFF, 0xFE, b'a', 0])
+        );
+        assert!(encoding.encode(vec![b'a', 0xFF], false).is_err());
+        assert!(Encoding::UTF8(UTF8).encode(vec![0xC3], false).is_err());
+    }
+
+    /// Fails after the first bytes of the source
+    struct Failing(bool);
+
+    impl Read for Failing {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.0 {
+                return Err(io::Error::new(io::ErrorKind::Other, "the disk is gone"));
+            }
+
+            self.0 = true;
+            buf[..8].copy_from_slice(b"name: Ma");
+            Ok(8)
+        }
+    }
+
+    #[test]
+    fn failing_decode() {
+        match Decoder::new(Failing(false)).decode() {
+            Err(DecodeError::Io(error)) => assert_eq!(error.to_string(), "the disk is gone"),
+            _ => assert!(false, "the error must be returned"),
+        }
+    }
+}
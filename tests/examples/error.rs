@@ -0,0 +1,175 @@
+#[cfg(all(test, not(feature = "dev")))]
+mod stable {
+    extern crate skimmer;
+    extern crate yamlette;
+
+    use self::yamlette::book::Book;
+    use self::yamlette::error::{Error, ErrorKind};
+    use self::yamlette::model::schema::core::Core;
+    use self::yamlette::model::yaml::int::Int;
+    use self::yamlette::model::renderer::Renderer;
+    use self::yamlette::model::{DecodeError, Model, Tagged, TaggedValue};
+    use self::yamlette::orchestra::{OrchError, Orchestra};
+    use self::yamlette::reader::Reader;
+    use self::yamlette::savant::Savant;
+
+    use std::any::Any;
+    use std::borrow::Cow;
+    use std::error::Error as StdError;
+    use std::iter;
+
+    fn read(src: &'static str) -> Result<Book, Error> {
+        let mut book = Book::new();
+        let mut savant = Savant::new(Core::new());
+
+        Reader::new().read(
+            skimmer::reader::IntoReader::into_reader(src),
+            &mut |block| match savant.think(block) {
+                Ok(Some(idea)) => {
+                    book.stamp(idea);
+                    Ok(())
+                }
+                Ok(None) => Ok(()),
+                Err(error) => Err(error.to_string().into()),
+            },
+        )?;
+
+        book.check()?;
+
+        Ok(book)
+    }
+
+    #[test]
+    fn decode_error_reason() {
+        let error = Int.decode(true, b"12 apples").unwrap_err();
+
+        assert_eq!("tag:yaml.org,2002:int", error.tag);
+        assert_eq!("12 apples", error.value);
+        assert_eq!(
+            "cannot decode \"12 apples\" as tag:yaml.org,2002:int: unexpected characters after the number",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn read_decode_error() {
+        let error = read("numbers:\n  - 1\n  - !<tag:yaml.org,2002:int> 2.5\n")
+            .err()
+            .unwrap();
+
+        assert_eq!(ErrorKind::Read, error.kind());
+        assert_eq!(3, error.location().unwrap().line);
+        assert_eq!(
+            "cannot decode \"2.5\" as tag:yaml.org,2002:int: unexpected characters after the number at line 3, column 30",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn book_check() {
+        yamlette! ( sage ; "- 1\n- !<tag:yaml.org,2002:int> 2.5\n" ; [[ [ (one:u8) ] ]] ; { book: book } );
+
+        assert_eq!(Some(1), one);
+
+        let error = book.check().err().unwrap();
+
+        assert_eq!(ErrorKind::Sage, error.kind());
+        assert_eq!(2, error.location().unwrap().line);
+        assert!(error
+            .message()
+            .ends_with("unexpected characters after the number"));
+    }
+
+    #[test]
+    fn read_error() {
+        let error = read("%YAML").err().unwrap();
+
+        assert_eq!(ErrorKind::Read, error.kind());
+        assert_eq!(
+            "Unexpected end of the document while parse %YAML directive at line 1, column 6",
+            error.to_string()
+        );
+        assert!(read("%YAML 1.2\n---\nkey: value\n").is_ok());
+    }
+
+    #[test]
+    fn boxed() {
+        fn write() -> Result<String, Box<dyn StdError>> {
+            let orchestra = Orchestra::solo(Core::new());
+
+            yamlette_compose! ( orchestra ; orchestra ; [[ [ "Mark McGwire" ] ]] );
+
+            orchestra.listen()?;
+            orchestra.listen()?;
+
+            Ok(String::new())
+        }
+
+        let error = write().err().unwrap();
+
+        assert!(error.downcast_ref::<OrchError>().is_some());
+        assert_eq!("no music has been played", error.to_string());
+    }
+
+    #[test]
+    fn source_chain() {
+        let decode = DecodeError::new(&Int, b"x", "an integer starts with a sign or a digit");
+        let error = Error::from(decode.clone());
+
+        assert_eq!(ErrorKind::Decode, error.kind());
+        assert_eq!(
+            Some(&decode),
+            error.source().unwrap().downcast_ref::<DecodeError>()
+        );
+    }
+
+    struct Bare;
+
+    impl Model for Bare {
+        fn get_tag(&self) -> Cow<'static, str> {
+            Cow::from("tag:example.com,2000:bare")
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_mut_any(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    #[test]
+    fn model_defaults() {
+        let error = Bare.decode(true, b"value").unwrap_err();
+
+        assert_eq!("tag:example.com,2000:bare", error.tag);
+        assert_eq!("the model is not decodable", error.reason);
+
+        let error = Bare.meta_init(None, None, b"value").unwrap_err();
+        assert_eq!("the model is not a metamodel", error.reason);
+
+        let error = Bare.get_default().unwrap_err();
+        assert_eq!("the model has no default value", error.reason);
+
+        let value = TaggedValue::new(Cow::from("tag:example.com,2000:bare"), Box::new(()));
+        let value = Bare.encode(&Renderer, value, &mut iter::empty()).unwrap_err();
+
+        let value = Bare
+            .compose(&Renderer, value, &mut iter::empty(), &mut [])
+            .unwrap_err();
+        assert_eq!("tag:example.com,2000:bare", value.get_tag());
+    }
+
+    #[test]
+    fn read_not_decodable() {
+        let error = read("key: !<tag:yaml.org,2002:map> value\n")
+            .err()
+            .unwrap();
+
+        assert_eq!(ErrorKind::Read, error.kind());
+        assert!(error
+            .message()
+            .ends_with("as tag:yaml.org,2002:map: the model is not decodable"));
+    }
+}
@@ -0,0 +1,294 @@
+#[cfg(all(test, not(feature = "dev")))]
+mod stable {
+    extern crate yamlette;
+
+    use self::yamlette::book::word::Word;
+    use self::yamlette::events::{Event, Events, ScalarStyle};
+    use self::yamlette::model::schema::core::Core;
+
+    fn dump(src: &'static str) -> Vec<String> {
+        Events::new(src, Core::new())
+            .ok()
+            .unwrap()
+            .map(|event| match event {
+                Event::StreamStart => String::from("+STR"),
+                Event::StreamEnd => String::from("-STR"),
+                Event::DocumentStart => String::from("+DOC"),
+                Event::DocumentEnd => String::from("-DOC"),
+                Event::MappingStart { anchor, tag, .. } => props("+MAP", anchor, &tag),
+                Event::MappingEnd => String::from("-MAP"),
+                Event::SequenceStart { anchor, tag, .. } => props("+SEQ", anchor, &tag),
+                Event::SequenceEnd => String::from("-SEQ"),
+                Event::Scalar {
+                    anchor,
+                    tag,
+                    style,
+                    value,
+                    ..
+                } => {
+                    let style = match style {
+                        ScalarStyle::Plain => ":",
+                        ScalarStyle::SingleQuoted => "'",
+                        ScalarStyle::DoubleQuoted => "\"",
+                        ScalarStyle::Literal => "|",
+                        ScalarStyle::Folded => ">",
+                    };
+                    format!("{} {}{}", props("=VAL", anchor, &tag), style, text(value))
+                }
+                Event::Alias { name, .. } => format!("=ALI *{}", name),
+                Event::Warning(message, location) => {
+                    format!("WRN {} at {}:{}", message, location.line, location.column)
+                }
+                Event::Error(message, _) => format!("ERR {}", message),
+            })
+            .collect()
+    }
+
+    fn props(head: &str, anchor: Option<String>, tag: &str) -> String {
+        let tag = tag.replace("tag:yaml.org,2002:", "!!");
+        match anchor {
+            Some(anchor) => format!("{} &{} <{}>", head, anchor, tag),
+            None => format!("{} <{}>", head, tag),
+        }
+    }
+
+    fn text(value: yamlette::model::TaggedValue) -> String {
+        let word = Word::extract_scalar(value);
+
+        let int: Result<i64, &Word> = (&word).into();
+        if let Ok(int) = int {
+            return int.to_string();
+        }
+
+        match word {
+            Word::Str(string) => string.replace('\n', "\\n"),
+            Word::Bool(value) => value.to_string(),
+            Word::Null => String::from("~"),
+            Word::Scalar(_) => String::from("<scalar>"),
+            _ => String::from("?"),
+        }
+    }
+
+    #[test]
+    fn stream() {
+        let src = r#"%YAML 1.2
+---
+plain: 1
+nested:
+  - &x "two"
+  - *x
+  - !local three
+block: |
+  literal
+folded: >-
+  folded
+flow: { 'key': [], empty: }
+...
+---
+- next
+"#;
+
+        assert_eq!(
+            dump(src),
+            vec![
+                "+STR",
+                "+DOC",
+                "+MAP <!!map>",
+                "=VAL <!!str> :plain",
+                "=VAL <!!int> :1",
+                "=VAL <!!str> :nested",
+                "+SEQ <!!seq>",
+                "=VAL &x <!!str> \"two",
+                "=ALI *x",
+                "=VAL <tag:yamlette.org,1:incognitum> :<scalar>",
+                "-SEQ",
+                "=VAL <!!str> :block",
+                "=VAL <!!str> |literal\\n",
+                "=VAL <!!str> :folded",
+                "=VAL <!!str> >folded",
+                "=VAL <!!str> :flow",
+                "+MAP <!!map>",
+                "=VAL <!!str> 'key",
+                "+SEQ <!!seq>",
+                "-SEQ",
+                "=VAL <!!str> :empty",
+                "=VAL <!!null> :~",
+                "-MAP",
+                "-MAP",
+                "-DOC",
+                "+DOC",
+                "+SEQ <!!seq>",
+                "=VAL <!!str> :next",
+                "-SEQ",
+                "-DOC",
+                "-STR",
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_flow() {
+        assert_eq!(
+            dump("{ a: { b: [ c, { d: e } ] }, f: g }\n"),
+            vec![
+                "+STR",
+                "+DOC",
+                "+MAP <!!map>",
+                "=VAL <!!str> :a",
+                "+MAP <!!map>",
+                "=VAL <!!str> :b",
+                "+SEQ <!!seq>",
+                "=VAL <!!str> :c",
+                "+MAP <!!map>",
+                "=VAL <!!str> :d",
+                "=VAL <!!str> :e",
+                "-MAP",
+                "-SEQ",
+                "-MAP",
+                "=VAL <!!str> :f",
+                "=VAL <!!str> :g",
+                "-MAP",
+                "-DOC",
+                "-STR",
+            ]
+        );
+    }
+
+    #[test]
+    fn same_as_volume() {
+        let sources: [&'static str; 4] = [
+            "a:\n  b:\n    - 1\n    - c: 2\n      d: [3, {e: 4}]\n  f: 5\ng: 6\n",
+            "- - - 1\n    - 2\n  - 3\n- 4\n",
+            "? [complex, key]\n: value\nplain: text\n",
+            "x: &a { y: 1 }\nz: *a\n",
+        ];
+
+        for src in sources.iter() {
+            yamlette! ( read ; *src ; [[ ]] ; { book: book } );
+
+            let expected: Vec<(usize, &'static str)> = book.volumes[0]
+                .gist
+                .iter()
+                .map(|&(_, level, ref word)| {
+                    let kind = match *word {
+                        Word::Map(_) => "map",
+                        Word::Seq(_) => "seq",
+                        Word::Alias(_) => "alias",
+                        _ => "scalar",
+                    };
+                    (level, kind)
+                })
+                .collect();
+
+            let mut level = 0;
+            let mut found: Vec<(usize, &'static str)> = Vec::new();
+
+            for event in Events::new(*src, Core::new()).ok().unwrap() {
+                match event {
+                    Event::MappingStart { .. } => {
+                        found.push((level, "map"));
+                        level += 1;
+                    }
+                    Event::SequenceStart { .. } => {
+                        found.push((level, "seq"));
+                        level += 1;
+                    }
+                    Event::MappingEnd | Event::SequenceEnd => level -= 1,
+                    Event::Alias { .. } => found.push((level, "alias")),
+                    Event::Scalar { .. } => found.push((level, "scalar")),
+                    _ => (),
+                }
+            }
+
+            assert_eq!(level, 0);
+            assert_eq!(found, expected, "{}", src);
+        }
+    }
+
+    #[test]
+    fn styles() {
+        let src = "plain: &a !!str 'single'\nflow: [ \"double\", { k: v } ]\n\
+                   ? !local >\n  folded\n: |\n  literal\n\n...\n--- &b \"next\\\n  line\"\n";
+
+        let styles: Vec<ScalarStyle> = Events::new(src, Core::new())
+            .ok()
+            .unwrap()
+            .filter_map(|event| match event {
+                Event::Scalar { style, .. } => Some(style),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(
+            styles,
+            vec![
+                ScalarStyle::Plain,
+                ScalarStyle::SingleQuoted,
+                ScalarStyle::Plain,
+                ScalarStyle::DoubleQuoted,
+                ScalarStyle::Plain,
+                ScalarStyle::Plain,
+                ScalarStyle::Folded,
+                ScalarStyle::Literal,
+                ScalarStyle::DoubleQuoted,
+            ]
+        );
+    }
+
+    #[test]
+    fn spans() {
+        let mut spans = Events::new("key: 'value'\nlist: [1, 22]\n", Core::new())
+            .ok()
+            .unwrap()
+            .filter_map(|event| match event {
+                Event::Scalar { span, .. } | Event::SequenceStart { span, .. } => {
+                    Some((span.start.line, span.start.column, span.end.column))
+                }
+                _ => None,
+            });
+
+        assert_eq!(spans.next(), Some((1, 1, 4)));
+        assert_eq!(spans.next(), Some((1, 6, 13)));
+        assert_eq!(spans.next(), Some((2, 1, 5)));
+        assert_eq!(spans.next(), Some((2, 7, 14)));
+        assert_eq!(spans.next(), Some((2, 8, 9)));
+        assert_eq!(spans.next(), Some((2, 11, 13)));
+        assert_eq!(spans.next(), None);
+    }
+
+    #[test]
+    fn stop_early() {
+        let mut events = Events::with_capacity("[1, 2, 3, 4, 5, 6, 7, 8]", Core::new(), 1)
+            .ok()
+            .unwrap();
+
+        let event = events.next();
+        assert!(matches!(event, Some(Event::StreamStart)), "{:?}", event);
+
+        drop(events);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            dump("%YAML"),
+            vec![
+                "+STR",
+                "ERR Unexpected end of the document while parse %YAML directive",
+                "-STR",
+            ]
+        );
+
+        assert_eq!(
+            dump("%YAML 1.21\n---\nvalue\n"),
+            vec![
+                "+STR",
+                "WRN %YAML minor version is not fully supported at 1:11",
+                "+DOC",
+                "=VAL <!!str> :value",
+                "-DOC",
+                "-STR",
+            ]
+        );
+    }
+}
@@ -160,6 +160,25 @@ bar:
         assert_eq!(should_be.map(|b| b.to_string()), result);
     }
 
+    #[test]
+    fn example_02_01_block_solo() {
+        let should_be = Some(
+            r#"- Mark McGwire
+- Sammy Sosa
+- Ken Griffey
+"#,
+        );
+
+        let result = yamlette! ( write ; [[ [ "Mark McGwire", "Sammy Sosa", "Ken Griffey" ] ]] ; { solo: true } ).ok ();
+
+        assert_eq!(should_be.map(|b| b.to_string()), result);
+
+        let schema = yamlette::model::schema::core::Core::new();
+        let result = yamlette! ( write ; [[ [ "Mark McGwire", "Sammy Sosa", "Ken Griffey" ] ]] ; { schema: schema, solo: true } ).ok ();
+
+        assert_eq!(should_be.map(|b| b.to_string()), result);
+    }
+
     #[test]
     fn custom_00_tagged_incognitum() {
         let should_be = Some("- !<env> Value\n");
@@ -173,4 +192,30 @@ bar:
 
         assert_eq!(should_be.map(|b| b.to_string()), result);
     }
+
+    #[test]
+    fn extra_merge_keys() {
+        let src = r#"
+defaults: &defaults
+  image: rust
+  retries: 2
+test:
+  <<: *defaults
+  retries: 3
+"#;
+
+        yamlette! ( read ; src ; [[ { "test" => { "image" => (image:&str), "retries" => (retries:u8) } } ]] ; { merge_keys: true } );
+
+        assert_eq!(image, Some("rust"));
+        assert_eq!(retries, Some(3));
+
+        yamlette! ( sage ; src ; [[ { "test" => { "image" => (image:&str), "retries" => (retries:u8) } } ]] ; { merge_keys: true } );
+
+        assert_eq!(image, Some("rust"));
+        assert_eq!(retries, Some(3));
+
+        yamlette! ( read ; src ; [[ { "test" => { "image" => (image:&str) } } ]] );
+
+        assert_eq!(image, None);
+    }
 }
@@ -0,0 +1,233 @@
+#[cfg(all(test, not(feature = "dev")))]
+mod stable {
+    extern crate skimmer;
+    extern crate yamlette;
+
+    use self::yamlette::book::value::{Value, ValueError};
+    use self::yamlette::book::Book;
+    use self::yamlette::error::{Error, ErrorKind};
+    use self::yamlette::limits::{Limit, Limits};
+    use self::yamlette::model::schema::core::Core;
+    use self::yamlette::reader::Reader;
+    use self::yamlette::savant::Savant;
+
+    const LAUGHS: &str = r#"
+a: &a [lol, lol, lol, lol, lol, lol, lol, lol, lol]
+b: &b [*a, *a, *a, *a, *a, *a, *a, *a, *a]
+c: &c [*b, *b, *b, *b, *b, *b, *b, *b, *b]
+d: &d [*c, *c, *c, *c, *c, *c, *c, *c, *c]
+e: &e [*d, *d, *d, *d, *d, *d, *d, *d, *d]
+"#;
+
+    fn read(src: &str, limits: Limits) -> Result<Book, Error> {
+        let mut book = Book::new();
+        let mut savant = Savant::new(Core::new());
+
+        book.set_limits(limits);
+
+        Reader::with_limits(limits).read(
+            skimmer::reader::IntoReader::into_reader(src.to_string()),
+            &mut |block| match savant.think(block) {
+                Ok(Some(idea)) => {
+                    book.stamp(idea);
+                    Ok(())
+                }
+                Ok(None) => Ok(()),
+                Err(error) => Err(error.to_string().into()),
+            },
+        )?;
+
+        book.check()?;
+
+        Ok(book)
+    }
+
+    fn exceeded(src: &str, limits: Limits) -> ErrorKind {
+        read(src, limits).err().unwrap().kind()
+    }
+
+    #[test]
+    fn unlimited_by_default() {
+        let book = read(LAUGHS, Limits::default()).ok().unwrap();
+
+        assert!(book.query("/e/8/8/8/8/8").unwrap() == "lol");
+    }
+
+    #[test]
+    fn expanded_nodes() {
+        let limits = Limits {
+            max_nodes: Some(10_000),
+            ..Limits::new()
+        };
+
+        assert_eq!(exceeded(LAUGHS, limits), ErrorKind::Limit(Limit::Nodes));
+
+        yamlette! ( read ; LAUGHS ; [[ ]] ; { book: book, limits: limits } );
+
+        let volume = &book.volumes[0];
+
+        assert_eq!(volume.exceeded().map(|e| e.limit), Some(Limit::Nodes));
+        assert!(Value::from_volume(volume).is_none());
+
+        let book = read("a: &a [1, 2]\nb: [*a, *a]", limits).ok().unwrap();
+        assert!(book.query("/b/1/0").unwrap() == 1);
+    }
+
+    #[test]
+    fn recursive_alias() {
+        let limits = Limits {
+            max_nodes: Some(1_000_000),
+            ..Limits::new()
+        };
+
+        assert_eq!(
+            exceeded("&a [1, *a]", limits),
+            ErrorKind::Limit(Limit::Nodes)
+        );
+    }
+
+    #[test]
+    fn extraction() {
+        // two levels in the source, five with the aliases expanded
+        let chain = "- &a []\n- &b [*a]\n- &c [*b]\n- [*c]\n";
+
+        let limits = Limits {
+            max_depth: Some(4),
+            ..Limits::new()
+        };
+
+        let book = read(chain, limits).ok().unwrap();
+        let volume = &book.volumes[0];
+
+        match Value::try_from_volume(volume) {
+            Err(ValueError::Limit(error)) => {
+                assert_eq!((error.limit, error.max), (Limit::Depth, 4))
+            }
+            _ => assert!(false, "Unexpected result"),
+        };
+        assert!(Value::from_volume(volume).is_none());
+
+        // the nodes within the limit are still there
+        let value = Value::try_from_pointer(book.query("/2").unwrap());
+        assert_eq!(value.ok().unwrap().as_seq().unwrap().len(), 1);
+
+        let limits = Limits {
+            max_depth: Some(5),
+            ..Limits::new()
+        };
+
+        let book = read(chain, limits).ok().unwrap();
+        assert!(Value::from_volume(&book.volumes[0]).is_some());
+
+        let limits = Limits {
+            max_nodes: Some(10_000),
+            ..Limits::new()
+        };
+
+        yamlette! ( read ; LAUGHS ; [[ ]] ; { book: book, limits: limits } );
+
+        match Value::try_from_volume(&book.volumes[0]) {
+            Err(ValueError::Limit(error)) => assert_eq!(error.limit, Limit::Nodes),
+            _ => assert!(false, "Unexpected result"),
+        };
+    }
+
+    #[test]
+    fn aliases() {
+        let limits = Limits {
+            max_aliases: Some(20),
+            ..Limits::new()
+        };
+
+        assert_eq!(exceeded(LAUGHS, limits), ErrorKind::Limit(Limit::Aliases));
+        assert!(read("a: &a 1\nb: *a\nc: *a", limits).is_ok());
+
+        yamlette! ( read ; LAUGHS ; [[ ]] ; { result: result, limits: limits } );
+
+        let error = result.err().unwrap().err().unwrap();
+
+        assert_eq!(error.limit, Some(Limit::Aliases));
+        assert_eq!(error.location.line, 5);
+    }
+
+    #[test]
+    fn depth() {
+        let limits = Limits {
+            max_depth: Some(3),
+            ..Limits::new()
+        };
+
+        assert!(read("a: [b, {c: d}]", limits).is_ok());
+        assert_eq!(
+            exceeded("a: [b, {c: [d]}]", limits),
+            ErrorKind::Limit(Limit::Depth)
+        );
+
+        let deep = format!("{}{}", "[".repeat(100_000), "]".repeat(100_000));
+
+        assert_eq!(exceeded(&deep, limits), ErrorKind::Limit(Limit::Depth));
+    }
+
+    #[test]
+    fn untrusted_depth() {
+        // read in the stack of a test thread, with the frames of a debug build
+        let limits = Limits::untrusted();
+        let max = limits.max_depth.unwrap();
+
+        for (open, close) in [("[", "]"), ("{a: ", "}"), ("- ", "")] {
+            let nested = |depth: usize| format!("{}1{}", open.repeat(depth), close.repeat(depth));
+
+            assert!(read(&nested(max), limits).is_ok(), "{}", open);
+            assert_eq!(
+                exceeded(&nested(max + 1), limits),
+                ErrorKind::Limit(Limit::Depth),
+                "{}",
+                open
+            );
+        }
+
+        let mapping = |depth: usize| {
+            (0..depth)
+                .map(|level| format!("{}a:\n", "  ".repeat(level)))
+                .collect::<String>()
+        };
+
+        assert!(read(&mapping(max), limits).is_ok());
+        assert_eq!(
+            exceeded(&mapping(2 * max), limits),
+            ErrorKind::Limit(Limit::Depth)
+        );
+    }
+
+    #[test]
+    fn scalar_length() {
+        let limits = Limits {
+            max_scalar_len: Some(8),
+            ..Limits::new()
+        };
+
+        assert!(read("key: short", limits).is_ok());
+        assert_eq!(
+            exceeded("key: a bit longer", limits),
+            ErrorKind::Limit(Limit::ScalarLength)
+        );
+        assert_eq!(
+            exceeded("key: |\n  line\n  line\n  line\n", limits),
+            ErrorKind::Limit(Limit::ScalarLength)
+        );
+    }
+
+    #[test]
+    fn document_size() {
+        let limits = Limits {
+            max_document_size: Some(32),
+            ..Limits::new()
+        };
+
+        assert!(read("a: 1\n---\nb: 2\n---\nc: 3\n", limits).is_ok());
+        assert_eq!(
+            exceeded("- one\n- two\n- three\n- four\n- five\n- six\n", limits),
+            ErrorKind::Limit(Limit::DocumentSize)
+        );
+    }
+}
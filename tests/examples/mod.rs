@@ -1,6 +1,22 @@
+pub mod async_tokio;
 pub mod book;
+pub mod chunks;
+pub mod cli;
+pub mod de;
+pub mod derive;
+pub mod editor;
+pub mod encoding;
+pub mod error;
+pub mod events;
 pub mod face;
+pub mod limits;
 pub mod orchestra;
+pub mod query;
 pub mod reader;
 pub mod sage;
 pub mod savant;
+pub mod schema;
+pub mod ser;
+pub mod timestamp;
+pub mod validator;
+pub mod value;
@@ -27,6 +27,13 @@ macro_rules! ex {
             let result = unsafe { String::from_utf8_unchecked (orc.listen ().ok ().unwrap ()) };
 
             assert_eq! (expect, result);
+
+            let solo = Orchestra::solo (Core::new ());
+
+            yamlette_compose! ( orchestra ; solo ; $rules );
+            let result = unsafe { String::from_utf8_unchecked (solo.listen ().ok ().unwrap ()) };
+
+            assert_eq! (expect, result);
         }
     };
 
@@ -42,6 +49,13 @@ macro_rules! ex {
             let result = unsafe { String::from_utf8_unchecked (orc.listen ().ok ().unwrap ()) };
 
             assert_eq! (expect, result);
+
+            let solo = Orchestra::solo (Core::new ());
+
+            yamlette_compose! ( orchestra ; solo ; $rules );
+            let result = unsafe { String::from_utf8_unchecked (solo.listen ().ok ().unwrap ()) };
+
+            assert_eq! (expect, result);
         }
     };
 }
@@ -56,10 +70,13 @@ extern crate yamlette;
 use self::yamlette::model::schema::core::Core;
 // use self::yamlette::txt::get_charset_utf8;
 
-use self::yamlette::orchestra::Orchestra;
+use self::yamlette::orchestra::{ Orchestra, OrchError };
 use self::yamlette::orchestra::chord::{ Omap, Set };
 
+use std::cell::RefCell;
 use std::collections::{ BTreeMap, HashMap };
+use std::io;
+use std::rc::Rc;
 
 
 
@@ -871,6 +888,159 @@ r#"%YAML 1.2
 
 
 
+#[test]
+fn solo_same_as_conductor () {
+    let mark = "Mark McGwire";
+    let sammy = "Sammy Sosa";
+    let ken = "Ken Griffey";
+
+    let orc = get_orc ();
+    let solo = Orchestra::solo (Core::new ());
+
+    use yamlette::model::style::Flow;
+
+    yamlette_compose! ( orchestra ; orc ; [ % YAML , (TAG ; "!test!", "http://yamlette.org,2015:test/") =>
+        [ % (TAG ; "!aloha!", "http://yamlette.org,2015:aloha/") => [ mark, sammy, { "hr": [ 65, 63 ], "avg": 0.278 } ] ],
+        [ { "names": [ sammy, ken ], "flow": ( # Flow (true) => { "a": [ 1, 2 ], "b": { "c": "d" } } ) } ],
+        [ % NO_YAML, NO_BORDER_BOT, (TAG ; "!hola!", "http://yamlette.org,2015:hola/") => [ mark ] ]
+    ] );
+
+    let expect = orc.listen ().ok ().unwrap ();
+
+    for _ in 0..2 {
+        yamlette_compose! ( orchestra ; solo ; [ % YAML , (TAG ; "!test!", "http://yamlette.org,2015:test/") =>
+            [ % (TAG ; "!aloha!", "http://yamlette.org,2015:aloha/") => [ mark, sammy, { "hr": [ 65, 63 ], "avg": 0.278 } ] ],
+            [ { "names": [ sammy, ken ], "flow": ( # Flow (true) => { "a": [ 1, 2 ], "b": { "c": "d" } } ) } ],
+            [ % NO_YAML, NO_BORDER_BOT, (TAG ; "!hola!", "http://yamlette.org,2015:hola/") => [ mark ] ]
+        ] );
+
+        assert_eq! (expect, solo.listen ().ok ().unwrap ());
+    }
+}
+
+
+
+struct Chunks (Rc<RefCell<Vec<Vec<u8>>>>);
+
+impl io::Write for Chunks {
+    fn write (&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut ().push (buf.to_vec ());
+        Ok (buf.len ())
+    }
+
+    fn flush (&mut self) -> io::Result<()> { Ok ( () ) }
+}
+
+
+struct Broken;
+
+impl io::Write for Broken {
+    fn write (&mut self, _: &[u8]) -> io::Result<usize> {
+        Err (io::Error::new (io::ErrorKind::BrokenPipe, "broken"))
+    }
+
+    fn flush (&mut self) -> io::Result<()> { Ok ( () ) }
+}
+
+
+#[test]
+fn sink_same_as_conductor () {
+    let mark = "Mark McGwire";
+    let sammy = "Sammy Sosa";
+    let ken = "Ken Griffey";
+
+    let orc = get_orc ();
+    let chunks = Rc::new (RefCell::new (Vec::new ()));
+    let sink = Orchestra::sink (Core::new (), Chunks (chunks.clone ()));
+
+    use yamlette::model::style::Flow;
+
+    yamlette_compose! ( orchestra ; orc ; [ % YAML , (TAG ; "!test!", "http://yamlette.org,2015:test/") =>
+        [ % (TAG ; "!aloha!", "http://yamlette.org,2015:aloha/") => [ mark, sammy, { "hr": [ 65, 63 ], "avg": 0.278 } ] ],
+        [ { "names": [ sammy, ken ], "flow": ( # Flow (true) => { "a": [ 1, 2 ], "b": { "c": "d" } } ) } ],
+        [ % NO_YAML, NO_BORDER_BOT, (TAG ; "!hola!", "http://yamlette.org,2015:hola/") => [ mark ] ]
+    ] );
+
+    let expect = orc.listen ().ok ().unwrap ();
+
+    for _ in 0..2 {
+        yamlette_compose! ( orchestra ; sink ; [ % YAML , (TAG ; "!test!", "http://yamlette.org,2015:test/") =>
+            [ % (TAG ; "!aloha!", "http://yamlette.org,2015:aloha/") => [ mark, sammy, { "hr": [ 65, 63 ], "avg": 0.278 } ] ],
+            [ { "names": [ sammy, ken ], "flow": ( # Flow (true) => { "a": [ 1, 2 ], "b": { "c": "d" } } ) } ],
+            [ % NO_YAML, NO_BORDER_BOT, (TAG ; "!hola!", "http://yamlette.org,2015:hola/") => [ mark ] ]
+        ] );
+
+        assert! (sink.listen ().ok ().unwrap ().is_empty ());
+
+        // a chunk per volume
+        assert_eq! (3, chunks.borrow ().len ());
+        assert_eq! (expect, chunks.borrow ().concat ());
+
+        chunks.borrow_mut ().clear ();
+    }
+
+    assert! (sink.into_sink::<Chunks> ().is_some ());
+}
+
+
+#[test]
+fn sink_io_error () {
+    let sink = Orchestra::sink (Core::new (), Broken);
+
+    yamlette_compose! ( orchestra ; sink ; [ [ "Mark McGwire" ], [ "Sammy Sosa" ] ] );
+
+    match sink.listen () {
+        Err (OrchError::IoError (error)) => assert_eq! (io::ErrorKind::BrokenPipe, error.kind ()),
+        _ => panic! ("the sink error is lost")
+    }
+
+    let result = yamlette! ( write into ; Vec::new () ; [[ [ "Mark McGwire" ], [ "Sammy Sosa" ] ]] );
+
+    let expect = yamlette! ( write ; [[ [ "Mark McGwire" ], [ "Sammy Sosa" ] ]] ).ok ().unwrap ();
+
+    assert_eq! (expect.into_bytes (), result.ok ().unwrap ());
+}
+
+
+
+#[test]
+fn unplayable_value () {
+    use std::borrow::Cow;
+    use yamlette::model::TaggedValue;
+    use yamlette::model::yaml::seq::SeqValue;
+
+    fn play (orchestra: &Orchestra) -> Result<Vec<u8>, OrchError> {
+        orchestra.volumes (1) ?;
+        orchestra.vol_next () ?;
+        orchestra.vol_reserve (2) ?;
+        orchestra.play (0, TaggedValue::from (SeqValue::new (orchestra.get_styles (), None))) ?;
+        orchestra.play (1, TaggedValue::new (Cow::from ("tag:example.com,2000:bare"), Box::new (()))) ?;
+        orchestra.vol_end () ?;
+        orchestra.the_end () ?;
+
+        orchestra.listen ()
+    }
+
+    let orc = get_orc ();
+    let solo = Orchestra::solo (Core::new ());
+    let sink = Orchestra::sink (Core::new (), Vec::new ());
+
+    for orchestra in [&orc, &solo, &sink].iter () {
+        match play (orchestra) {
+            Err (OrchError::Error (message)) => assert_eq! ("Could not find appropriate model (tag tag:example.com,2000:bare)", message),
+            _ => panic! ("the value is played")
+        }
+
+        // the orchestra keeps playing
+        yamlette_compose! ( orchestra ; orchestra ; [[ [ "Mark McGwire" ] ]] );
+        assert! (orchestra.listen ().is_ok ());
+    }
+
+    assert_eq! (Some (b"- Mark McGwire\n".to_vec ()), sink.into_sink::<Vec<u8>> ());
+}
+
+
+
 #[test]
 fn example_02_01_block_tagged () {
     let should_be = 
@@ -1123,6 +1293,54 @@ rbi:
 
 
 
+ex! (
+    single_entry_block_collections ;
+
+r#"map:
+  seq:
+    - 1
+list:
+  - key: value
+  - - nested
+"# ;
+
+    [[ { "map": { "seq": [ 1 ] }, "list": [ { "key": "value" }, [ "nested" ] ] } ]]
+);
+
+
+
+ex! (
+    single_entry_block_key ;
+
+r#"?
+  k: v
+: x
+"# ;
+
+    [[ { { "k": "v" }: "x" } ]]
+);
+
+
+
+ex! (
+    single_entry_block_values_of_explicit_keys ;
+
+r#"?
+  k: v
+: x
+? c
+:
+  d: true
+? e
+:
+  - 1
+"# ;
+
+    [[ { { "k": "v" }: "x", "c": { "d": true }, "e": [ 1 ] } ]]
+);
+
+
+
 ex! (
     example_02_11_simplified ;
 
@@ -1243,6 +1461,31 @@ r#"---
 
 
 
+#[test]
+fn single_entry_block_collections_of_omap_and_set () {
+    let should_be =
+r#"- a:
+  - 1
+- b:
+  - 2
+"#;
+
+    let omap = Omap (vec! [("a", vec! [1]), ("b", vec! [2])]);
+
+    check! (should_be ; [[ omap ]]);
+
+    let should_be =
+r#"? - 1
+? - 2
+"#;
+
+    let set = Set (vec! [vec! [1], vec! [2]]);
+
+    check! (should_be ; [[ set ]]);
+}
+
+
+
 ex! (
     example_02_27_simplified ;
 
@@ -0,0 +1,101 @@
+#[cfg(all(test, not(feature = "dev")))]
+mod stable {
+    extern crate yamlette;
+
+    use self::yamlette::book::extractor::pointer::Pointer;
+    use self::yamlette::book::value::Value;
+
+    const SRC: &str = r#"ports: &ports [22, 2222]
+defaults: &defaults
+  ports: [80, 443]
+servers:
+  - name: alpha
+    ports: [8080]
+  - name: beta
+    <<: *defaults
+  - name: gamma
+    ports: *ports
+    settings: { a/b: slash, t~1: tilde, "x.y": dot, 404: int, true: bool }
+"#;
+
+    #[test]
+    fn json_pointer() {
+        yamlette! ( read ; SRC ; [[ ]] ; { book: book } );
+
+        let root = Pointer::new(&book.volumes[0]).unwrap();
+
+        assert!(root.pointer("/servers/0/name").unwrap() == "alpha");
+        assert!(root.pointer("/servers/0/ports/0").unwrap() == 8080);
+        assert!(root.pointer("/servers/2/ports/1").unwrap() == 2222);
+        assert!(root.pointer("/servers/2/settings/a~1b").unwrap() == "slash");
+        assert!(root.pointer("/servers/2/settings/t~01").unwrap() == "tilde");
+        assert!(root.pointer("/servers/2/settings/404").unwrap() == "int");
+        assert!(root.pointer("/servers/2/settings/true").unwrap() == "bool");
+        assert_eq!(root.pointer("").unwrap().span(), root.span());
+
+        assert!(root.pointer("/servers/3").is_none());
+        assert!(root.pointer("/servers/first").is_none());
+        assert!(root.pointer("/servers/0/name/0").is_none());
+        assert!(root.pointer("/servers/2/settings/t~2").is_none());
+        assert!(root.pointer("servers").is_none());
+
+        // array indexes have neither signs nor leading zeros
+        assert!(root.pointer("/servers/+1").is_none());
+        assert!(root.pointer("/servers/01").is_none());
+        assert!(root.pointer("/servers/00").is_none());
+        assert!(root.pointer("/servers/-").is_none());
+        assert!(root.pointer("/servers/").is_none());
+        assert!(root.pointer("/servers/0/ports/00").is_none());
+    }
+
+    #[test]
+    fn dotted_path() {
+        yamlette! ( read ; SRC ; [[ ]] ; { book: book } );
+
+        let root = Pointer::new(&book.volumes[0]).unwrap();
+
+        assert!(root.path("servers[1].name").unwrap() == "beta");
+        assert!(root.path("servers.1.name").unwrap() == "beta");
+        assert!(root.path("servers[2].ports[0]").unwrap() == 22);
+        assert!(root.path(r#"servers[2].settings["x.y"]"#).unwrap() == "dot");
+        assert!(root.path("servers[2].settings['a/b']").unwrap() == "slash");
+        assert!(root.path("servers[2].settings[404]").unwrap() == "int");
+        assert!(root.path("[servers][0][name]").unwrap() == "alpha");
+        assert_eq!(root.path("").unwrap().span(), root.span());
+
+        assert!(root.path("servers[2].settings.x.y").is_none());
+        assert!(root.path("servers..name").is_none());
+        assert!(root.path("servers.").is_none());
+        assert!(root.path("servers[0").is_none());
+        assert!(root.path("servers[\"0]").is_none());
+    }
+
+    #[test]
+    fn typed() {
+        yamlette! ( read ; SRC ; [[ ]] ; { book: book } );
+
+        let root = Pointer::new(&book.volumes[0]).unwrap();
+
+        assert_eq!(root.fetch::<&str>("servers[0].name"), Some("alpha"));
+        assert_eq!(root.fetch::<u16>("/servers/0/ports/0"), Some(8080));
+        assert_eq!(
+            root.fetch::<Value>("/servers/2/ports"),
+            Some(Value::from(vec![22, 2222]))
+        );
+        assert_eq!(root.fetch::<u16>("servers[0].name"), None);
+
+        assert_eq!(
+            book.fetch::<String>("/servers/1/name"),
+            Some(String::from("beta"))
+        );
+        assert_eq!(book.fetch::<u16>("defaults.ports[1]"), Some(443));
+        assert!(book.query("/servers/9").is_none());
+    }
+
+    #[test]
+    fn merge_keys() {
+        yamlette! ( read ; SRC ; [[ ]] ; { book: book, merge_keys: true } );
+
+        assert_eq!(book.fetch::<u16>("servers[1].ports[1]"), Some(443));
+    }
+}
@@ -18,7 +18,7 @@ macro_rules! read {
                 }
             })
             .unwrap_or_else(|err| {
-                assert!(false, "Unexpected result: {}, :{}", err, err.position);
+                assert!(false, "Unexpected result: {}, :{}", err, err.location);
             });
 
         receiver
@@ -48,7 +48,7 @@ macro_rules! read_with_error {
             })
             .or_else(|err| {
                 assert_eq!($err_desc, err.description);
-                assert_eq!($err_pos, err.position);
+                assert_eq!($err_pos, err.location.offset);
                 Err(err)
             })
             .ok();
@@ -71,7 +71,7 @@ macro_rules! read_bytes {
                 }
             })
             .unwrap_or_else(|err| {
-                assert!(false, "Unexpected result: {}, :{}", err, err.position);
+                assert!(false, "Unexpected result: {}, :{}", err, err.location);
             });
 
         receiver
@@ -140,7 +140,7 @@ macro_rules! expect {
 
             if let BlockType::Error (err, pos) = block.cargo {
                 assert_eq! (err, $desc);
-                assert_eq! (pos, $pos);
+                assert_eq! (pos.offset, $pos);
             } else { assert! (false, "Unexpected result") }
         } else { assert! (false, "Unexpected result") }
     }};
@@ -152,7 +152,7 @@ macro_rules! expect {
 
             if let BlockType::Warning (err, pos) = block.cargo {
                 assert_eq! (err, $desc);
-                assert_eq! (pos, $pos);
+                assert_eq! (pos.offset, $pos);
             } else { assert! (false, "Unexpected result") }
         } else { assert! (false, "Unexpected result") }
     }};
@@ -8,7 +8,7 @@ macro_rules! sage {
         reader.read (
             SliceReader::new ($src.as_bytes ()),
             &mut |block| { if let Err (_) = sender.send (block) { Err (Cow::from ("Cannot yield a block")) } else { Ok ( () ) } }
-        ).unwrap_or_else (|err| { assert! (false, "Unexpected result: {}, :{}", err, err.position); });
+        ).unwrap_or_else (|err| { assert! (false, "Unexpected result: {}, :{}", err, err.location); });
 
         sage
     }}
@@ -31,7 +31,7 @@ macro_rules! sage_with_error {
             .and_then (|_| { assert! (false, "Must be an error in here; {}: {}", $err_pos, $err_desc); Ok ( () ) })
             .or_else (|err| {
                 assert_eq! ($err_desc, err.description);
-                assert_eq! ($err_pos, err.position);
+                assert_eq! ($err_pos, err.location.offset);
                 Err (err)
             }).ok ();
 
@@ -51,7 +51,7 @@ macro_rules! sage_bytes {
         reader.read (
             SliceReader::new ($src),
             &mut |block| { if let Err (_) = sender.send (block) { Err (Cow::from ("Cannot yield a block")) } else { Ok ( () ) } }
-        ).unwrap_or_else (|err| { assert! (false, "Unexpected result: {}, :{}", err, err.position); });
+        ).unwrap_or_else (|err| { assert! (false, "Unexpected result: {}, :{}", err, err.location); });
 
         sage
     }}
@@ -189,7 +189,7 @@ macro_rules! expect {
                 match *item {
                     Idea::ReadError (_, ref pos, ref msg) => {
                         assert_eq! ($err_desc, <dyn AsRef<str>>::as_ref (msg));
-                        assert_eq! ($err_pos, *pos);
+                        assert_eq! ($err_pos, pos.offset);
                         break 'top;
                     }
 
@@ -210,7 +210,7 @@ macro_rules! expect {
             Some ( idea ) => match *idea {
                 Idea::ReadWarning (_, ref pos, ref msg) => {
                     assert_eq! ($err_desc, <dyn AsRef<str>>::as_ref (msg));
-                    assert_eq! ($err_pos, *pos);
+                    assert_eq! ($err_pos, pos.offset);
                 },
                 _ => assert! (false, "Not a warning {:?}", idea)
             }
@@ -817,6 +817,9 @@ mod stable {
 
     use self::yamlette::model::schema::core::Core;
     use self::yamlette::sage::{ Idea, Sage };
+    use self::skimmer::reader::slice_reader::SliceDatum;
+    use self::yamlette::reader::{ Block, BlockType, Id, Node, NodeKind, Span };
+    use self::yamlette::sage::SageError;
 
     use self::yamlette::reader::Reader;
 
@@ -7369,4 +7372,21 @@ r"- [a, b, c]
         expect! (data, (2, 16, 18), str, ".");
         expect! (data, (2, 16, 19), str, "+");
     }
+
+
+    #[test]
+    fn unexpected_literal () {
+        let id = |parent, index| Id { level: 1, parent: parent, index: index, span: Span::default () };
+
+        let (sender, receiver) = channel::<Block<SliceDatum>> ();
+        let sage = Sage::new (receiver, Core::new ()).unwrap ();
+
+        sender.send (Block::new (id (0, 1), BlockType::Node (Node { anchor: None, tag: None, content: NodeKind::LiteralBlockOpen }))).unwrap ();
+        sender.send (Block::new (id (2, 3), BlockType::Byte (b'a', 1))).ok ();
+
+        match sage.join ().unwrap () {
+            Err (SageError::Error (message)) => assert_eq! (message, "Unexpected literal"),
+            _ => assert! (false, "the literal of another block must be an error")
+        }
+    }
 }
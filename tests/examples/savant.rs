@@ -13,7 +13,7 @@ macro_rules! savant {
                 if let Ok (Some (idea)) = savant.think (block) { data.push (idea) };
                 Ok ( () )
             }
-        ).unwrap_or_else (|err| { assert! (false, "Unexpected result: {}, :{}", err, err.position); });
+        ).unwrap_or_else (|err| { assert! (false, "Unexpected result: {}, :{}", err, err.location); });
 
         data
     }}
@@ -41,7 +41,7 @@ macro_rules! savant_with_error {
             .and_then (|_| { assert! (false, "Must be an error in here; {}: {}", $err_pos, $err_desc); Ok ( () ) })
             .or_else (|err| {
                 assert_eq! ($err_desc, err.description);
-                assert_eq! ($err_pos, err.position);
+                assert_eq! ($err_pos, err.location.offset);
                 Err (err)
             }).ok ();
 
@@ -66,7 +66,7 @@ macro_rules! savant_bytes {
                 if let Ok (Some (idea)) = savant.think (block) { data.push (idea) };
                 Ok ( () )
             }
-        ).unwrap_or_else (|err| { assert! (false, "Unexpected result: {}, :{}", err, err.position); });
+        ).unwrap_or_else (|err| { assert! (false, "Unexpected result: {}, :{}", err, err.location); });
 
         data
     }}
@@ -176,7 +176,7 @@ macro_rules! expect {
                 match *item {
                     Idea::ReadError (_, ref pos, ref msg) => {
                         assert_eq! ($err_desc, <dyn AsRef<str>>::as_ref (msg));
-                        assert_eq! ($err_pos, *pos);
+                        assert_eq! ($err_pos, pos.offset);
                         break 'top;
                     }
 
@@ -197,7 +197,7 @@ macro_rules! expect {
             Some ( idea ) => match *idea {
                 Idea::ReadWarning (_, ref pos, ref msg) => {
                     assert_eq! ($err_desc, <dyn AsRef<str>>::as_ref (msg));
-                    assert_eq! ($err_pos, *pos);
+                    assert_eq! ($err_pos, pos.offset);
                 },
                 _ => assert! (false, "Not a warning {:?}", idea)
             }
@@ -843,6 +843,9 @@ mod stable {
     use self::yamlette::model::schema::core::Core;
     use self::yamlette::sage::{ Idea };
     use self::yamlette::savant::Savant;
+    use self::skimmer::reader::slice_reader::SliceDatum;
+    use self::yamlette::reader::{ Block, BlockType, Id, Node, NodeKind, Span };
+    use self::yamlette::sage::SageError;
 
     use self::yamlette::reader::Reader;
 
@@ -7392,4 +7395,31 @@ r"- [a, b, c]
         expect! (data, (2, 16, 18), str, ".");
         expect! (data, (2, 16, 19), str, "+");
     }
+
+
+    #[test]
+    fn unexpected_literal () {
+        let id = |parent, index| Id { level: 1, parent: parent, index: index, span: Span::default () };
+        let open = || BlockType::Node (Node { anchor: None, tag: None, content: NodeKind::LiteralBlockOpen });
+        let close = || BlockType::Node (Node { anchor: None, tag: None, content: NodeKind::LiteralBlockClose });
+
+        let mut savant: Savant<Core, SliceDatum> = Savant::new (Core::new ());
+
+        assert! (savant.think (Block::new (id (0, 1), open ())).unwrap ().is_none ());
+
+        match savant.think (Block::new (id (2, 3), BlockType::Byte (b'a', 1))) {
+            Err (SageError::Error (message)) => assert_eq! (message, "Unexpected literal"),
+            _ => assert! (false, "the literal of another block must be an error")
+        }
+
+        match savant.think (Block::new (id (0, 4), close ())) {
+            Err (SageError::Error (message)) => assert_eq! (message, "Unexpected literal block"),
+            _ => assert! (false, "the end of another block must be an error")
+        }
+
+        match savant.think (Block::new (id (0, 1), close ())) {
+            Err (SageError::Error (message)) => assert_eq! (message, "Unexpected literal block"),
+            _ => assert! (false, "the end of no block must be an error")
+        }
+    }
 }
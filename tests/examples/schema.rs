@@ -0,0 +1,353 @@
+#[cfg(all(test, not(feature = "dev")))]
+mod stable {
+    extern crate yamlette;
+
+    use self::yamlette::book::extractor::pointer::Pointer;
+    use self::yamlette::book::extractor::traits::FromPointer;
+    use self::yamlette::book::word::Word;
+    use self::yamlette::model::schema::builder::SchemaBuilder;
+    use self::yamlette::model::schema::failsafe::Failsafe;
+    use self::yamlette::model::schema::json::Json;
+    use self::yamlette::model::schema::yaml11::Yaml11;
+    use self::yamlette::model::schema::Schema;
+    use self::yamlette::model::yaml::timestamp::TimestampValue;
+    use self::yamlette::model::{
+        DecodeError, EncodedString, Model, Node, Renderer, Rope, Tagged, TaggedValue,
+    };
+    use self::yamlette::orchestra::Orchestra;
+
+    use std::any::Any;
+    use std::borrow::Cow;
+
+    const TAG: &'static str = "tag:example.com,2000:color";
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum Color {
+        Red,
+        Green,
+        Blue,
+    }
+
+    impl<'a> FromPointer<'a> for Color {
+        fn from_pointer(pointer: Pointer<'a>) -> Option<Self> {
+            match *pointer.to_word() {
+                Word::Scalar(ref value) => value.as_any().downcast_ref::<Color>().cloned(),
+                _ => None,
+            }
+        }
+    }
+
+    struct ColorModel;
+
+    impl Model for ColorModel {
+        fn get_tag(&self) -> Cow<'static, str> {
+            Cow::from(TAG)
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_mut_any(&mut self) -> &mut dyn Any {
+            self
+        }
+
+        fn is_decodable(&self) -> bool {
+            true
+        }
+
+        fn is_encodable(&self) -> bool {
+            true
+        }
+
+        fn decode(&self, _explicit: bool, value: &[u8]) -> Result<TaggedValue, DecodeError> {
+            let color = match value {
+                b"red" => Color::Red,
+                b"green" => Color::Green,
+                b"blue" => Color::Blue,
+                _ => return Err(DecodeError::new(self, value, "not a color")),
+            };
+
+            Ok(TaggedValue::new(Cow::from(TAG), Box::new(color)))
+        }
+
+        fn encode(
+            &self,
+            _renderer: &Renderer,
+            value: TaggedValue,
+            _tags: &mut dyn Iterator<Item = &(Cow<'static, str>, Cow<'static, str>)>,
+        ) -> Result<Rope, TaggedValue> {
+            let name: &'static str = match value.as_any().downcast_ref::<Color>() {
+                Some(Color::Red) => "red",
+                Some(Color::Green) => "green",
+                Some(Color::Blue) => "blue",
+                None => return Err(value),
+            };
+
+            Ok(Rope::from(Node::String(EncodedString::from(name.as_bytes()))))
+        }
+    }
+
+    #[test]
+    fn explicit_tag() {
+        let schema = SchemaBuilder::new()
+            .tag_handle("!e!", "tag:example.com,2000:")
+            .model(ColorModel)
+            .build();
+
+        yamlette! ( read ; "- !e!color red\n- blue\n- 1\n" ; [[ [ (red:Color), (blue:Color), (one:u8) ] ]] ; { schema: schema } );
+
+        assert_eq!(Some(Color::Red), red);
+        assert_eq!(None, blue);
+        assert_eq!(Some(1), one);
+    }
+
+    #[test]
+    fn implicit() {
+        let schema = SchemaBuilder::new().implicit(ColorModel).build();
+
+        yamlette! ( read ; "- green\n- blue\n- 1\n- true\n- grey\n" ; [[ [ (green:Color), (blue:Color), (one:u8), (yes:bool), (grey:&str) ] ]] ; { schema: schema } );
+
+        assert_eq!(Some(Color::Green), green);
+        assert_eq!(Some(Color::Blue), blue);
+        assert_eq!(Some(1), one);
+        assert_eq!(Some(true), yes);
+        assert_eq!(Some("grey"), grey);
+    }
+
+    #[test]
+    fn resolution() {
+        let schema = SchemaBuilder::new()
+            .model(ColorModel)
+            .resolution(vec![
+                "tag:yaml.org,2002:null",
+                "tag:yaml.org,2002:int",
+                TAG,
+                "tag:yaml.org,2002:str",
+            ])
+            .build();
+
+        yamlette! ( sage ; "- red\n- 1\n- true\n- 2.5\n" ; [[ [ (red:Color), (one:u8), (yes:&str), (real:&str) ] ]] ; { schema: schema } );
+
+        assert_eq!(Some(Color::Red), red);
+        assert_eq!(Some(1), one);
+        assert_eq!(Some("true"), yes);
+        assert_eq!(Some("2.5"), real);
+    }
+
+    #[test]
+    fn override_base_model() {
+        struct Strict;
+
+        impl Model for Strict {
+            fn get_tag(&self) -> Cow<'static, str> {
+                Cow::from("tag:yaml.org,2002:bool")
+            }
+
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn as_mut_any(&mut self) -> &mut dyn Any {
+                self
+            }
+        }
+
+        let schema = SchemaBuilder::new().model(Strict).build();
+
+        assert!(!schema
+            .look_up_model("tag:yaml.org,2002:bool")
+            .unwrap()
+            .is_decodable());
+        assert!(schema.look_up_model("tag:yaml.org,2002:int").is_some());
+        assert_eq!(
+            "tag:yaml.org,2002:",
+            schema.get_tag_handles()[0].1.as_ref()
+        );
+    }
+
+    #[test]
+    fn write() {
+        let schema = SchemaBuilder::new().model(ColorModel).build();
+        let orchestra = Orchestra::solo(schema);
+
+        orchestra.volumes(1).unwrap();
+        orchestra.vol_next().unwrap();
+        orchestra.vol_reserve(1).unwrap();
+        orchestra
+            .play(0, TaggedValue::new(Cow::from(TAG), Box::new(Color::Blue)))
+            .unwrap();
+        orchestra.vol_end().unwrap();
+        orchestra.the_end().unwrap();
+
+        assert_eq!(b"blue".to_vec(), orchestra.listen().unwrap());
+    }
+
+    #[test]
+    fn failsafe() {
+        let src = "enabled: no\ncount: 12\nempty:\nlist: [ 1, true, ~ ]\n";
+
+        for _ in 0..2 {
+            let failsafe = Failsafe::new();
+
+            yamlette! ( read ; src ; [[ { "enabled" => (enabled:&str), "count" => (count:&str), "empty" => (empty:&str), "list" => [ (one:&str), (yes:&str), (tilde:&str) ] } ]] ; { schema: failsafe } );
+
+            assert_eq!(Some("no"), enabled);
+            assert_eq!(Some("12"), count);
+            assert_eq!(Some(""), empty);
+            assert_eq!(Some("1"), one);
+            assert_eq!(Some("true"), yes);
+            assert_eq!(Some("~"), tilde);
+
+            let failsafe = Failsafe::new();
+
+            yamlette! ( sage ; src ; [[ { "enabled" => (enabled:bool), "count" => (count:u8), "empty" => (empty:&str) } ]] ; { schema: failsafe } );
+
+            assert_eq!(None, enabled);
+            assert_eq!(None, count);
+            assert_eq!(Some(""), empty);
+        }
+    }
+
+    #[test]
+    fn json() {
+        let src = r#"{ "int": -12, "float": 2.5e3, "bool": false, "null": null, "str": "yes", "empty": }"#;
+
+        let json = Json::new();
+
+        yamlette! ( read ; src ; [[ { "int" => (int:i8), "float" => (float:f64), "bool" => (boolean:bool), "null" => (null:()), "str" => (string:&str), "empty" => (empty:()) } ]] ; { schema: json } );
+
+        assert_eq!(Some(-12), int);
+        assert_eq!(Some(2500.0), float);
+        assert_eq!(Some(false), boolean);
+        assert_eq!(Some(()), null);
+        assert_eq!(Some("yes"), string);
+        assert_eq!(Some(()), empty);
+
+        for src in ["key: yes", "key: 0x1F", "key: .5", "key: True", "key: !!timestamp 2001-12-14"].iter() {
+            let json = Json::new();
+
+            yamlette! ( read ; *src ; [[ ]] ; { schema: json, result: result } );
+            assert!(result.is_err(), "{}", src);
+
+            yamlette! ( read ; *src ; [[ ]] ; { result: result } );
+            assert!(result.is_ok(), "{}", src);
+        }
+
+        let json = Json::new();
+
+        yamlette! ( read ; "{\"a\": 1,\n \"b\": True}\n" ; [[ ]] ; { schema: json, result: result } );
+
+        match result {
+            Err(Err(error)) => {
+                assert_eq!(
+                    error.description,
+                    "Cannot resolve the scalar \"True\": not a JSON-compatible plain scalar"
+                );
+                assert_eq!((error.location.line, error.location.column), (2, 7));
+                assert_eq!(error.location.text, " \"b\": True}");
+            }
+            _ => assert!(false, "Unexpected result"),
+        }
+    }
+
+    #[test]
+    fn builder_on_json() {
+        let schema = SchemaBuilder::on(Json::new()).implicit(ColorModel).build();
+
+        let custom = schema.clone();
+
+        yamlette! ( read ; "[ red, 1 ]" ; [[ [ (red:Color), (one:u8) ] ]] ; { schema: custom } );
+
+        assert_eq!(Some(Color::Red), red);
+        assert_eq!(Some(1), one);
+
+        yamlette! ( read ; "[ red, grey ]" ; [[ ]] ; { schema: schema, result: result } );
+
+        assert!(result.is_err());
+    }
+
+    /// The tag a scalar is resolved to
+    #[derive(Debug, PartialEq)]
+    struct ScalarTag(String);
+
+    impl<'a> FromPointer<'a> for ScalarTag {
+        fn from_pointer(pointer: Pointer<'a>) -> Option<Self> {
+            match *pointer.to_word() {
+                Word::Scalar(ref value) => Some(ScalarTag(value.get_tag().into_owned())),
+                _ => None,
+            }
+        }
+    }
+
+    fn tag(name: &str) -> Option<ScalarTag> {
+        Some(ScalarTag(format!("tag:yaml.org,2002:{}", name)))
+    }
+
+    #[test]
+    fn yaml11() {
+        let src = "[ yes, Off, n, 017, 0b101, 1_000, '1:30', 1:30, -1:30.5, 2001-12-14, <<, =, ~ ]";
+
+        for sage in [false, true].iter() {
+            let yaml11 = Yaml11::new();
+
+            if *sage {
+                yamlette! ( sage ; src ; [[ [ (yes:bool), (off:bool), (n:bool), (oct:u8), (bin:u8), (thousand:u16), (quoted:&str), (minutes:u8), (real:f64), (date:&TimestampValue), (merge:ScalarTag), (value:ScalarTag), (null:()) ] ]] ; { schema: yaml11 } );
+
+                assert_eq!(
+                    (Some(true), Some(false), Some(false), Some(15), Some(5), Some(1000), Some("1:30"), Some(90), Some(-90.5)),
+                    (yes, off, n, oct, bin, thousand, quoted, minutes, real)
+                );
+                assert_eq!((Some(String::from("2001-12-14")), tag("merge"), tag("value"), Some(())), (date.map(|d| d.to_string()), merge, value, null));
+            } else {
+                yamlette! ( read ; src ; [[ [ (yes:bool), (off:bool), (n:bool), (oct:u8), (bin:u8), (thousand:u16), (quoted:&str), (minutes:u8), (real:f64), (date:&TimestampValue), (merge:ScalarTag), (value:ScalarTag), (null:()) ] ]] ; { schema: yaml11 } );
+
+                assert_eq!(
+                    (Some(true), Some(false), Some(false), Some(15), Some(5), Some(1000), Some("1:30"), Some(90), Some(-90.5)),
+                    (yes, off, n, oct, bin, thousand, quoted, minutes, real)
+                );
+                assert_eq!((Some(String::from("2001-12-14")), tag("merge"), tag("value"), Some(())), (date.map(|d| d.to_string()), merge, value, null));
+            }
+        }
+    }
+
+    #[test]
+    fn yaml11_directive() {
+        let src = "%YAML 1.1\n---\n[ yes, 017, 2001-12-14 ]\n...\n---\n[ yes, 017, 2001-12-14 ]\n";
+
+        yamlette! ( read ; src ; [ [ [ (yes11:bool), (oct11:u8), (date11:&TimestampValue) ] ], [ [ (yes12:&str), (oct12:u8), (date12:&str) ] ] ] );
+
+        assert_eq!((Some(true), Some(15), Some(String::from("2001-12-14"))), (yes11, oct11, date11.map(|d| d.to_string())));
+        assert_eq!((Some("yes"), Some(17), Some("2001-12-14")), (yes12, oct12, date12));
+
+        let src = "%YAML 1.2\n---\n[ yes, 017 ]\n...\n---\n[ yes, 017 ]\n";
+        let yaml11 = Yaml11::new();
+
+        yamlette! ( sage ; src ; [ [ [ (yes12:&str), (oct12:u8) ] ], [ [ (yes11:bool), (oct11:u8) ] ] ] ; { schema: yaml11 } );
+
+        assert_eq!((Some("yes"), Some(17)), (yes12, oct12));
+        assert_eq!((Some(true), Some(15)), (yes11, oct11));
+    }
+
+    #[test]
+    fn yaml11_merge_keys() {
+        let src = "defaults: &defaults\n  image: rust\n  retries: 2\ntest:\n  <<: *defaults\n  retries: 3\n";
+        let yaml11 = Yaml11::new();
+
+        yamlette! ( read ; src ; [[ { "test" => { "image" => (image:&str), "retries" => (retries:u8) } } ]] ; { schema: yaml11, merge_keys: true } );
+
+        assert_eq!(Some("rust"), image);
+        assert_eq!(Some(3), retries);
+    }
+
+    #[test]
+    fn yaml11_write() {
+        let orchestra = Orchestra::solo(Yaml11::new());
+
+        yamlette_compose! ( orchestra ; orchestra ; [ % YAML => [ [ "yes" ] ] ] );
+
+        let result = String::from_utf8(orchestra.listen().unwrap()).unwrap();
+
+        assert!(result.starts_with("%YAML 1.1\n"), "{}", result);
+    }
+}
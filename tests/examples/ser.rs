@@ -0,0 +1,110 @@
+#[cfg(all(test, feature = "serde", not(feature = "dev")))]
+mod stable {
+    extern crate serde;
+    extern crate yamlette;
+
+    use self::serde::{Deserialize, Serialize};
+
+    use self::yamlette::book::de::from_str;
+    use self::yamlette::model::schema::core::Core;
+    use self::yamlette::model::style::FLOW;
+    use self::yamlette::orchestra::ser::{to_string, Serde};
+    use self::yamlette::orchestra::Orchestra;
+
+    use std::collections::BTreeMap;
+
+    #[derive(Serialize)]
+    struct Player {
+        name: &'static str,
+        hr: u16,
+        avg: f64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        team: Option<&'static str>,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    enum Action {
+        Stop,
+        Restart(u8),
+        Scale { min: u8, max: u8 },
+        Route(String, u16),
+    }
+
+    #[test]
+    fn example_02_04() {
+        let players = vec![
+            Player {
+                name: "Mark McGwire",
+                hr: 65,
+                avg: 0.278,
+                team: None,
+            },
+            Player {
+                name: "Sammy Sosa",
+                hr: 63,
+                avg: 0.288,
+                team: Some("Chicago Cubs"),
+            },
+        ];
+
+        let result = to_string(&players).ok().unwrap();
+
+        assert_eq!(
+            result,
+            r#"- name: Mark McGwire
+  hr: 65
+  avg: 0.278
+- name: Sammy Sosa
+  hr: 63
+  avg: 0.288
+  team: Chicago Cubs
+"#
+        );
+    }
+
+    #[test]
+    fn enums() {
+        let actions = vec![
+            Action::Stop,
+            Action::Restart(5),
+            Action::Scale { min: 1, max: 3 },
+            Action::Route("gateway".to_string(), 443),
+        ];
+
+        let result = to_string(&actions.last()).ok().unwrap();
+
+        assert_eq!(result, "Route:\n  - gateway\n  - 443\n");
+
+        let result = to_string(&actions).ok().unwrap();
+        let restored: Vec<Action> = from_str(&result).ok().unwrap();
+
+        assert_eq!(actions, restored);
+    }
+
+    #[test]
+    fn same_as_chord() {
+        let mut map = BTreeMap::new();
+        map.insert("hr", vec![65, 63]);
+        map.insert("rbi", vec![147, 141]);
+
+        let chord = yamlette! ( write ; [[ ( # FLOW => map.clone () ) ]] )
+            .ok()
+            .unwrap();
+        let serde = yamlette! ( write ; [[ ( # FLOW => Serde (map.clone ()) ) ]] )
+            .ok()
+            .unwrap();
+
+        assert_eq!(chord, "{ hr: [ 65, 63 ], rbi: [ 147, 141 ] }");
+        assert_eq!(chord, serde);
+
+        let orc = Orchestra::new(Core::new()).ok().unwrap();
+        yamlette_compose! ( orchestra ; orc ; [[ { "stats": (map.clone ()), "ok": true } ]] );
+        let chord = unsafe { String::from_utf8_unchecked(orc.listen().ok().unwrap()) };
+
+        let orc = Orchestra::new(Core::new()).ok().unwrap();
+        yamlette_compose! ( orchestra ; orc ; [[ { "stats": (Serde (&map)), "ok": true } ]] );
+        let serde = unsafe { String::from_utf8_unchecked(orc.listen().ok().unwrap()) };
+
+        assert_eq!(chord, serde);
+    }
+}
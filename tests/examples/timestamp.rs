@@ -0,0 +1,168 @@
+#[cfg(all(test, not(feature = "dev")))]
+mod stable {
+    extern crate yamlette;
+
+    use self::yamlette::model::schema::yaml11::Yaml11;
+    use self::yamlette::model::yaml::timestamp::TimestampValue;
+
+    #[test]
+    fn read() {
+        let src = "- !!timestamp 2001-12-14t21:59:43.10-05:00\n- !!timestamp 2002-12-14\n- 2002-12-14\n";
+
+        yamlette! ( read ; src ; [[ [ (spaced:&TimestampValue), (date:TimestampValue), (string:&str) ] ]] );
+
+        let spaced = spaced.unwrap();
+
+        assert_eq!(-5 * 3600, spaced.offset_seconds());
+        assert_eq!(100_000_000, spaced.nanosecond());
+        assert_eq!(
+            "2001-12-15T02:59:43.1+00",
+            spaced.to_utc().unwrap().to_string()
+        );
+
+        assert!(date.unwrap().is_date());
+        assert_eq!(Some("2002-12-14"), string);
+    }
+
+    #[test]
+    fn calendar() {
+        for src in ["- 2000-02-29", "- 2001-12-31T23:59:60Z"].iter() {
+            let yaml11 = Yaml11::new();
+
+            yamlette! ( read ; *src ; [[ [ (value:TimestampValue) ] ]] ; { schema: yaml11 } );
+
+            assert!(value.is_some(), "{}", src);
+        }
+
+        for src in ["- 2001-02-29", "- 2001-04-31", "- 2001-12-14T24:00:00"].iter() {
+            let yaml11 = Yaml11::new();
+
+            yamlette! ( read ; *src ; [[ [ (string:&str) ] ]] ; { schema: yaml11 } );
+
+            assert_eq!(Some(&src[2..]), string);
+
+            yamlette! ( read ; format! ("- !!timestamp {}", &src[2..]) ; [[ [ (value:TimestampValue) ] ]] );
+
+            assert!(value.is_none(), "{}", src);
+        }
+
+        assert!(TimestampValue::date(2024, 2, 29).is_some());
+        assert!(TimestampValue::date(2023, 2, 29).is_none());
+        assert!(TimestampValue::date_time(2023, 1, 1, 12, 60, 0).is_none());
+    }
+
+    #[test]
+    fn write() {
+        let date_time = TimestampValue::date_time(2001, 12, 14, 21, 59, 43)
+            .unwrap()
+            .nanoseconds(100_000_000)
+            .offset(-5 * 3600);
+
+        let result = yamlette! ( write ; [[ [ (TimestampValue::date (2002, 12, 14).unwrap ()), (date_time) ] ]] ).ok().unwrap();
+
+        assert_eq!("- 2002-12-14\n- 2001-12-14T21:59:43.1-05\n", result);
+    }
+}
+
+#[cfg(all(test, feature = "chrono", not(feature = "dev")))]
+mod chrono {
+    extern crate chrono;
+    extern crate yamlette;
+
+    use self::chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+
+    use self::yamlette::model::schema::yaml11::Yaml11;
+
+    #[test]
+    fn read() {
+        let src = "date: 2002-12-14\ncanonical: 2001-12-15T02:59:43.1Z\nspaced: 2001-12-14 21:59:43.10 -5\ntime: !!timestamp 21:59:43\n";
+        let yaml11 = Yaml11::new();
+
+        yamlette! ( read ; src ; [[ {
+            "date" => (date:NaiveDate),
+            "canonical" => (canonical:DateTime<Utc>),
+            "spaced" => (spaced:DateTime<FixedOffset>),
+            "time" => (time:NaiveTime)
+        } ]] ; { schema: yaml11 } );
+
+        let spaced = spaced.unwrap();
+
+        assert_eq!(NaiveDate::from_ymd_opt(2002, 12, 14), date);
+        assert_eq!(canonical.unwrap(), spaced);
+        assert_eq!(-5 * 3600, spaced.offset().local_minus_utc());
+        assert_eq!(NaiveTime::from_hms_opt(21, 59, 43), time);
+
+        let yaml11 = Yaml11::new();
+
+        yamlette! ( sage ; src ; [[ { "date" => (date:DateTime<Utc>), "spaced" => (spaced:NaiveDateTime) } ]] ; { schema: yaml11 } );
+
+        assert_eq!(Some(Utc.with_ymd_and_hms(2002, 12, 14, 0, 0, 0).unwrap()), date);
+        assert_eq!(
+            NaiveDate::from_ymd_opt(2001, 12, 15).and_then(|d| d.and_hms_milli_opt(2, 59, 43, 100)),
+            spaced
+        );
+    }
+
+    #[test]
+    fn write() {
+        let date = NaiveDate::from_ymd_opt(2002, 12, 14).unwrap();
+        let date_time = FixedOffset::east_opt(5 * 3600 + 1800)
+            .unwrap()
+            .with_ymd_and_hms(2001, 12, 14, 21, 59, 43)
+            .unwrap();
+
+        let result = yamlette! ( write ; [[ [ date, date_time ] ]] ).ok().unwrap();
+
+        assert_eq!("- 2002-12-14\n- 2001-12-14T21:59:43+5:30\n", result);
+
+        let yaml11 = Yaml11::new();
+
+        yamlette! ( read ; result ; [[ [ (read_date:NaiveDate), (read_date_time:DateTime<FixedOffset>) ] ]] ; { schema: yaml11 } );
+
+        assert_eq!(Some(date), read_date);
+        assert_eq!(Some(date_time), read_date_time);
+    }
+}
+
+#[cfg(all(test, feature = "time", not(feature = "dev")))]
+mod time {
+    extern crate time;
+    extern crate yamlette;
+
+    use self::time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+    use self::yamlette::model::schema::yaml11::Yaml11;
+
+    #[test]
+    fn read() {
+        let src = "date: 2002-12-14\ncanonical: 2001-12-15T02:59:43.1Z\nspaced: 2001-12-14 21:59:43.10 -5\n";
+        let yaml11 = Yaml11::new();
+
+        yamlette! ( read ; src ; [[ {
+            "date" => (date:Date),
+            "canonical" => (canonical:PrimitiveDateTime),
+            "spaced" => (spaced:OffsetDateTime)
+        } ]] ; { schema: yaml11 } );
+
+        let canonical = canonical.unwrap();
+        let spaced = spaced.unwrap();
+
+        assert_eq!(Date::from_calendar_date(2002, Month::December, 14).ok(), date);
+        assert_eq!(canonical.assume_utc(), spaced);
+        assert_eq!(Some(UtcOffset::from_hms(-5, 0, 0).unwrap()), Some(spaced.offset()));
+        assert_eq!(Time::from_hms_milli(2, 59, 43, 100).unwrap(), canonical.time());
+    }
+
+    #[test]
+    fn write() {
+        let date_time = PrimitiveDateTime::new(
+            Date::from_calendar_date(2001, Month::December, 14).unwrap(),
+            Time::from_hms(21, 59, 43).unwrap(),
+        )
+        .assume_offset(UtcOffset::from_hms(-5, 0, 0).unwrap());
+
+        let result = yamlette! ( write ; [[ [ date_time ] ]] ).ok().unwrap();
+
+        assert_eq!("- 2001-12-14T21:59:43-05\n", result);
+    }
+}
@@ -0,0 +1,199 @@
+#[cfg(all(test, feature = "validator", not(feature = "dev")))]
+mod stable {
+    extern crate yamlette;
+
+    use self::yamlette::book::validator::Validator;
+    use self::yamlette::book::value::Value;
+
+    const SCHEMA: &str = r#"
+type: object
+required: [name, image, replicas]
+additionalProperties: false
+properties:
+  name:
+    type: string
+    pattern: "^[a-z][a-z0-9-]*$"
+    maxLength: 16
+  image:
+    type: object
+    required: [repository]
+    properties:
+      repository: { type: string, minLength: 1 }
+      pull: { enum: [always, never, if-not-present] }
+  replicas: { type: integer, minimum: 1, maximum: 10 }
+  ratio: { type: number, exclusiveMaximum: 1 }
+  ports:
+    type: array
+    minItems: 1
+    uniqueItems: true
+    items: { type: integer, minimum: 1, maximum: 65535 }
+  debug: { type: [boolean, "null"] }
+  mode: { oneOf: [{ const: fast }, { const: safe }] }
+"#;
+
+    fn validator() -> Validator {
+        yamlette! ( read ; SCHEMA ; [[ (schema:Value) ]] );
+
+        Validator::new(&schema.unwrap()).ok().unwrap()
+    }
+
+    #[test]
+    fn valid() {
+        let src = r#"
+name: web-app
+image:
+  repository: registry/app
+  pull: always
+replicas: 3
+ratio: 0.5
+ports: [80, 443]
+debug: ~
+mode: safe
+"#;
+
+        yamlette! ( read ; src ; [[ ]] ; { book: book } );
+        let validator = validator();
+
+        let violations = validator.validate(&book.volumes[0]);
+        assert!(violations.is_empty(), "{:?}", violations);
+        assert!(validator.is_valid(&book.volumes[0]));
+    }
+
+    #[test]
+    fn violations() {
+        let src = r#"
+name: Web App
+image:
+  pull: sometimes
+replicas: 0
+ratio: 1
+ports: [80, 80, 70000]
+debug: "yes"
+mode: slow
+extra: true
+"#;
+
+        yamlette! ( read ; src ; [[ ]] ; { book: book } );
+
+        let violations: Vec<(String, String, usize)> = validator()
+            .validate(&book.volumes[0])
+            .into_iter()
+            .map(|v| (v.path, v.message.into_owned(), v.span.start.line))
+            .collect();
+
+        let expected: Vec<(&str, &str, usize)> = vec![
+            ("/name", "The string does not match /^[a-z][a-z0-9-]*$/", 2),
+            ("/image", "Missing the required key \"repository\"", 4),
+            ("/image/pull", "The value is not one of the allowed", 4),
+            ("/replicas", "The value is less than 1", 5),
+            ("/ratio", "The value must be less than 1", 6),
+            ("/ports/1", "The item is not unique", 7),
+            ("/ports/2", "The value is greater than 65535", 7),
+            ("/debug", "Expected boolean or null, found string", 8),
+            (
+                "/mode",
+                "The value matches 0 of the schemas in oneOf instead of one",
+                9,
+            ),
+            ("/extra", "Unexpected key \"extra\"", 10),
+        ];
+
+        assert_eq!(
+            violations,
+            expected
+                .into_iter()
+                .map(|(p, m, l)| (p.to_string(), m.to_string(), l))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn root_and_escaping() {
+        let validator = validator();
+
+        yamlette! ( read ; "- not a map" ; [[ ]] ; { book: book } );
+
+        let violations = validator.validate(&book.volumes[0]);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].path, "");
+        assert_eq!(
+            violations[0].to_string(),
+            "Expected object, found array at # (line 1, column 1)"
+        );
+
+        yamlette! ( read ; r#"{ type: object, additionalProperties: { type: integer } }"# ; [[ (schema:Value) ]] );
+        let validator = Validator::new(&schema.unwrap()).ok().unwrap();
+
+        yamlette! ( read ; r#"{ "a/b~c": x }"# ; [[ ]] ; { book: book } );
+
+        let violations = validator.validate(&book.volumes[0]);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].path, "/a~1b~0c");
+    }
+
+    #[test]
+    fn json_schema() {
+        let schema = r#"{
+  "type": "array",
+  "items": {
+    "type": "object",
+    "required": ["id"],
+    "properties": { "id": { "type": "integer" }, "tags": { "type": "array", "maxItems": 2 } }
+  }
+}"#;
+
+        yamlette! ( read ; schema ; [[ ]] ; { book: schema } );
+        let validator = Validator::from_volume(&schema.volumes[0]).ok().unwrap();
+
+        yamlette! ( read ; r#"[{"id": 1}, {"tags": [a, b, c]}]"# ; [[ ]] ; { book: book } );
+
+        let violations: Vec<String> = validator
+            .validate(&book.volumes[0])
+            .into_iter()
+            .map(|v| format!("{} {}", v.path, v.message))
+            .collect();
+
+        assert_eq!(
+            violations,
+            vec![
+                "/1 Missing the required key \"id\"",
+                "/1/tags Expected at most 2 items",
+            ]
+        );
+    }
+
+    #[test]
+    fn bad_schema() {
+        yamlette! ( read ; "properties: { name: { type: text } }" ; [[ (schema:Value) ]] );
+
+        match Validator::new(&schema.unwrap()) {
+            Err(err) => assert_eq!(err.to_string(), "Unknown type at #/properties/name/type"),
+            Ok(_) => assert!(false, "Unexpected success"),
+        };
+
+        yamlette! ( read ; "pattern: '('" ; [[ (schema:Value) ]] );
+        assert!(Validator::new(&schema.unwrap()).is_err());
+    }
+
+    #[test]
+    fn recursive_aliases() {
+        yamlette! ( read ; "{ uniqueItems: true, items: { enum: [1] } }" ; [[ (schema:Value) ]] );
+        let validator = Validator::new(&schema.unwrap()).ok().unwrap();
+
+        yamlette! ( read ; "&a [ *a, *a ]" ; [[ ]] ; { book: book } );
+
+        let violations: Vec<String> = validator
+            .validate(&book.volumes[0])
+            .into_iter()
+            .map(|v| format!("{} {}", v.path, v.message))
+            .collect();
+
+        assert_eq!(
+            violations,
+            vec![
+                "/0 The value is not one of the allowed",
+                "/1 The value is not one of the allowed",
+            ]
+        );
+    }
+}
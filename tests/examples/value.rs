@@ -0,0 +1,210 @@
+#[cfg(all(test, not(feature = "dev")))]
+mod stable {
+    extern crate yamlette;
+
+    use self::yamlette::book::value::{Value, ValueError};
+
+    #[test]
+    fn example_02_04() {
+        let src = r#"-
+  name: Mark McGwire
+  hr:   65
+  avg:  0.278
+-
+  name: Sammy Sosa
+  hr:   63
+  avg:  0.288
+"#;
+
+        yamlette! ( read ; src ; [[ (players:Value) ]] );
+
+        let players = players.unwrap();
+
+        assert_eq!(players.as_seq().map(Vec::len), Some(2));
+        assert_eq!(players[0]["name"].as_str(), Some("Mark McGwire"));
+        assert_eq!(players[0]["hr"].as_i64(), Some(65));
+        assert_eq!(players[1]["avg"].as_f64(), Some(0.288));
+
+        assert!(players[2]["name"].is_null());
+        assert!(players[0]["team"].is_null());
+        assert_eq!(
+            players.get(1).and_then(|p| p.get("hr")),
+            Some(&Value::from(63))
+        );
+    }
+
+    #[test]
+    fn example_02_10() {
+        let src = r#"---
+hr:
+  - Mark McGwire
+  # Following node labeled SS
+  - &SS Sammy Sosa
+rbi:
+  - *SS # Subsequent occurrence
+  - Ken Griffey"#;
+
+        yamlette! ( read ; src ; [[ (doc:Value) ]] );
+
+        let doc = doc.unwrap();
+
+        assert_eq!(doc["rbi"][0], Value::from("Sammy Sosa"));
+        assert_eq!(doc["hr"][1], doc["rbi"][0]);
+    }
+
+    #[test]
+    fn edit_and_write() {
+        let src = r#"name: Mark McGwire
+hr: 65
+avg: 0.278
+teams:
+  - Oakland Athletics
+  - St. Louis Cardinals
+"#;
+
+        yamlette! ( read ; src ; [[ (player:Value) ]] );
+
+        let mut player = player.unwrap();
+
+        assert_eq!(player.insert("hr", 70), Some(Value::from(65)));
+        assert_eq!(player.remove("avg"), Some(Value::from(0.278)));
+        assert_eq!(player.remove("avg"), None);
+
+        player["teams"].push("Retired");
+        assert_eq!(
+            player["teams"].remove(0),
+            Some(Value::from("Oakland Athletics"))
+        );
+
+        if let Some(name) = player.get_mut("name") {
+            *name = Value::from("Mark David McGwire");
+        }
+
+        player["rookie"] = Value::from(false);
+
+        let result = yamlette! ( write ; [[ (player) ]] ).ok().unwrap();
+
+        assert_eq!(
+            result,
+            r#"name: Mark David McGwire
+hr: 70
+teams:
+  - St. Louis Cardinals
+  - Retired
+rookie: false
+"#
+        );
+    }
+
+    #[test]
+    fn build_and_write() {
+        let mut doc = Value::Null;
+
+        doc["string"] = Value::from("text");
+        doc["list"].push(1);
+        doc["list"].push(Value::Null);
+        doc.insert(10, vec![true, false]);
+        doc.insert("binary", Value::Binary(vec![104, 101, 108, 108, 111]));
+
+        let result = yamlette! ( write ; [[ (doc) ]] ).ok().unwrap();
+
+        assert_eq!(
+            result,
+            r#"string: text
+list:
+  - 1
+  - ~
+10:
+  - true
+  - false
+binary: aGVsbG8=
+"#
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let src = r#"anchored: &anchor
+  key: !local value
+copy: *anchor
+tagged: !custom [1, 2]
+"#;
+
+        yamlette! ( read ; src ; [[ (doc:Value) ]] );
+
+        let doc = doc.unwrap();
+
+        assert_eq!(doc["copy"]["key"].tag(), Some("!local"));
+        assert_eq!(doc["copy"]["key"].as_str(), Some("value"));
+        assert_eq!(doc["tagged"].tag(), Some("!custom"));
+        assert_eq!(doc["tagged"][1].as_i64(), Some(2));
+
+        let result = yamlette! ( write ; [[ (doc.clone()) ]] ).ok().unwrap();
+
+        yamlette! ( read ; result ; [[ (again:Value) ]] );
+
+        let again = again.unwrap();
+
+        assert_eq!(again["anchored"], doc["anchored"]);
+        assert_eq!(again["copy"], doc["copy"]);
+        assert_eq!(again["tagged"], *doc["tagged"].untagged());
+    }
+
+    #[test]
+    fn recursive_aliases() {
+        yamlette! ( read ; "&a [ *a ]" ; [[ (doc:Value) ]] );
+        assert!(doc.is_none());
+
+        yamlette! ( read ; "&a { b: [ 1, { c: *a } ] }" ; [[ (doc:Value) ]] );
+        assert!(doc.is_none());
+
+        // the same anchor may be copied any number of times, nested as well
+        yamlette! ( read ; "[ &a [1], *a, [ *a, *a ] ]" ; [[ (doc:Value) ]] );
+
+        let doc = doc.unwrap();
+
+        assert_eq!(doc[1], doc[0]);
+        assert_eq!(doc[2][1], doc[0]);
+    }
+
+    #[test]
+    fn typeless_scalars() {
+        let src = "%YAML 1.1\n---\n[ =, !!yaml \"!\", !!merge <<, text ]\n";
+
+        yamlette! ( read ; src ; [[ (doc:Value) ]] );
+
+        let doc = doc.unwrap();
+
+        assert_eq!(doc[0].tag(), Some("tag:yaml.org,2002:value"));
+        assert_eq!(doc[0].as_str(), Some("="));
+        assert_eq!(doc[1].tag(), Some("tag:yaml.org,2002:yaml"));
+        assert_eq!(doc[1].as_str(), Some("!"));
+        assert_eq!(doc[2].tag(), Some("tag:yaml.org,2002:merge"));
+        assert_eq!(doc[2].as_str(), Some("<<"));
+        assert_eq!(doc[3], Value::from("text"));
+    }
+
+    #[test]
+    fn collection_tags() {
+        yamlette! ( read ; "set: !!set {a, b}\nomap: !!omap [a: 1]\n" ; [[ (doc:Value) ]] );
+
+        let doc = doc.unwrap();
+
+        assert_eq!(doc["set"].tag(), Some("tag:yaml.org,2002:set"));
+        assert!(doc["set"].untagged()["a"].is_null());
+        assert_eq!(doc["omap"].tag(), Some("tag:yaml.org,2002:omap"));
+    }
+
+    #[test]
+    fn errors() {
+        yamlette! ( read ; "[ 1, &a { b: *a } ]" ; [[ ]] ; { book: book } );
+
+        match Value::try_from_volume(&book.volumes[0]) {
+            Err(ValueError::Recursion) => (),
+            _ => assert!(false, "Unexpected result"),
+        };
+
+        let item = book.query("/0").unwrap();
+        assert_eq!(Value::try_from_pointer(item).ok(), Some(Value::from(1)));
+    }
+}
@@ -0,0 +1,144 @@
+use crate::case::RenameRule;
+
+use syn::meta::ParseNestedMeta;
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Expr, LitStr, Path, Result, Token};
+
+/// The attributes of the derived struct or enum
+#[derive(Default)]
+pub struct Container {
+    pub rename_all: Option<RenameRule>,
+    pub tag: Option<String>,
+    pub style: Vec<Expr>,
+}
+
+/// How a missing field gets its value
+pub enum FieldDefault {
+    None,
+    Trait,
+    Path(Path),
+}
+
+/// The attributes of a field
+pub struct Field {
+    pub rename: Option<String>,
+    pub default: FieldDefault,
+    pub flatten: bool,
+    pub skip: bool,
+    pub style: Vec<Expr>,
+}
+
+/// The attributes of an enum variant
+#[derive(Default)]
+pub struct Variant {
+    pub rename: Option<String>,
+    pub yaml_tag: Option<String>,
+}
+
+impl Container {
+    pub fn parse(attrs: &[Attribute]) -> Result<Container> {
+        let mut container = Container::default();
+
+        each(attrs, |meta| {
+            if meta.path.is_ident("rename_all") {
+                let rule: LitStr = meta.value()?.parse()?;
+
+                container.rename_all = Some(
+                    RenameRule::parse(&rule.value())
+                        .ok_or_else(|| meta.error("unknown rename_all rule"))?,
+                );
+            } else if meta.path.is_ident("tag") {
+                container.tag = Some(string(&meta)?);
+            } else if meta.path.is_ident("style") {
+                container.style.extend(styles(&meta)?);
+            } else {
+                return Err(meta.error("unknown yamlette container attribute"));
+            }
+
+            Ok(())
+        })?;
+
+        Ok(container)
+    }
+}
+
+impl Field {
+    pub fn parse(attrs: &[Attribute]) -> Result<Field> {
+        let mut field = Field {
+            rename: None,
+            default: FieldDefault::None,
+            flatten: false,
+            skip: false,
+            style: Vec::new(),
+        };
+
+        each(attrs, |meta| {
+            if meta.path.is_ident("rename") {
+                field.rename = Some(string(&meta)?);
+            } else if meta.path.is_ident("default") {
+                field.default = if meta.input.peek(syn::Token![=]) {
+                    FieldDefault::Path(syn::parse_str(&string(&meta)?)?)
+                } else {
+                    FieldDefault::Trait
+                };
+            } else if meta.path.is_ident("flatten") {
+                field.flatten = true;
+            } else if meta.path.is_ident("skip") {
+                field.skip = true;
+            } else if meta.path.is_ident("style") {
+                field.style.extend(styles(&meta)?);
+            } else {
+                return Err(meta.error("unknown yamlette field attribute"));
+            }
+
+            Ok(())
+        })?;
+
+        Ok(field)
+    }
+}
+
+impl Variant {
+    pub fn parse(attrs: &[Attribute]) -> Result<Variant> {
+        let mut variant = Variant::default();
+
+        each(attrs, |meta| {
+            if meta.path.is_ident("rename") {
+                variant.rename = Some(string(&meta)?);
+            } else if meta.path.is_ident("yaml_tag") {
+                variant.yaml_tag = Some(string(&meta)?);
+            } else {
+                return Err(meta.error("unknown yamlette variant attribute"));
+            }
+
+            Ok(())
+        })?;
+
+        Ok(variant)
+    }
+}
+
+fn each<F>(attrs: &[Attribute], mut parse: F) -> Result<()>
+where
+    F: FnMut(ParseNestedMeta) -> Result<()>,
+{
+    for attr in attrs {
+        if attr.path().is_ident("yamlette") {
+            attr.parse_nested_meta(&mut parse)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn string(meta: &ParseNestedMeta) -> Result<String> {
+    let value: LitStr = meta.value()?.parse()?;
+    Ok(value.value())
+}
+
+/// The expressions of `style(FLOW, ForceQuotes(true))`
+fn styles(meta: &ParseNestedMeta) -> Result<Punctuated<Expr, Token![,]>> {
+    let content;
+    syn::parenthesized!(content in meta.input);
+    Punctuated::parse_terminated(&content)
+}
@@ -0,0 +1,108 @@
+/// The `rename_all` conventions, applied to the field and variant names
+#[derive(Clone, Copy)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+    Camel,
+    Pascal,
+}
+
+impl RenameRule {
+    pub fn parse(rule: &str) -> Option<RenameRule> {
+        match rule {
+            "lowercase" => Some(RenameRule::Lower),
+            "UPPERCASE" => Some(RenameRule::Upper),
+            "snake_case" => Some(RenameRule::Snake),
+            "SCREAMING_SNAKE_CASE" => Some(RenameRule::ScreamingSnake),
+            "kebab-case" => Some(RenameRule::Kebab),
+            "SCREAMING-KEBAB-CASE" => Some(RenameRule::ScreamingKebab),
+            "camelCase" => Some(RenameRule::Camel),
+            "PascalCase" => Some(RenameRule::Pascal),
+            _ => None,
+        }
+    }
+
+    pub fn apply(self, name: &str) -> String {
+        let words = split(name);
+
+        match self {
+            RenameRule::Lower => words.concat().to_lowercase(),
+            RenameRule::Upper => words.concat().to_uppercase(),
+            RenameRule::Snake => words.join("_").to_lowercase(),
+            RenameRule::ScreamingSnake => words.join("_").to_uppercase(),
+            RenameRule::Kebab => words.join("-").to_lowercase(),
+            RenameRule::ScreamingKebab => words.join("-").to_uppercase(),
+            RenameRule::Camel => {
+                let mut result = String::with_capacity(name.len());
+
+                for (idx, word) in words.iter().enumerate() {
+                    if idx == 0 {
+                        result.push_str(&word.to_lowercase());
+                    } else {
+                        result.push_str(&capitalize(word));
+                    }
+                }
+
+                result
+            }
+            RenameRule::Pascal => words.iter().map(|word| capitalize(word)).collect(),
+        }
+    }
+}
+
+/// The key of a field or a variant, unless it's renamed explicitly
+pub fn rename(rule: Option<RenameRule>, name: &str) -> String {
+    let name = name.strip_prefix("r#").unwrap_or(name);
+
+    match rule {
+        Some(rule) => rule.apply(name),
+        None => name.to_string(),
+    }
+}
+
+/// Splits both `snake_case` and `PascalCase` names into words
+fn split(name: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut start = 0;
+    let mut prev_lower = false;
+
+    for (idx, c) in name.char_indices() {
+        if c == '_' || c == '-' {
+            if start < idx {
+                words.push(&name[start..idx]);
+            }
+            start = idx + c.len_utf8();
+            prev_lower = false;
+            continue;
+        }
+
+        if c.is_uppercase() && prev_lower {
+            words.push(&name[start..idx]);
+            start = idx;
+        }
+
+        prev_lower = c.is_lowercase() || c.is_ascii_digit();
+    }
+
+    if start < name.len() {
+        words.push(&name[start..]);
+    }
+
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
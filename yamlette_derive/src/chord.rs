@@ -0,0 +1,460 @@
+use crate::attr::{Container, Field, Variant};
+use crate::case::{self, RenameRule};
+use crate::ty::option_inner;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse_quote, Data, DataEnum, DeriveInput, Error, Expr, Fields, Generics, Ident, Member, Result,
+};
+
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let container = Container::parse(&input.attrs)?;
+    let generics = bound_generics(&input.generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let name = &input.ident;
+
+    let cx = Context {
+        chord: quote!(::yamlette::orchestra::chord::Chord),
+        style: quote!(::yamlette::model::style::Style),
+        rule: container.rename_all,
+    };
+
+    let (size, body) = match input.data {
+        Data::Struct(ref data) => {
+            if container.tag.is_some() {
+                return Err(Error::new_spanned(name, "the `tag` attribute is for enums"));
+            }
+
+            let shape = Shape::parse(&data.fields, cx.rule)?;
+            let pattern = shape.pattern(quote!(#name), false);
+            let pattern_ref = shape.pattern(quote!(#name), true);
+            let size = cx.size(&shape, None);
+            let play = cx.play(&shape, None, &quote!(__level), &quote!(__alias));
+
+            (
+                quote! {
+                    let #pattern_ref = *self;
+                    #size
+                },
+                quote! {
+                    let #pattern = self;
+                    #play
+                },
+            )
+        }
+        Data::Enum(ref data) => cx.variants(name, data, container.tag.as_deref())?,
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                name,
+                "Chord can't be derived for unions",
+            ))
+        }
+    };
+
+    let chord = &cx.chord;
+    let style = &cx.style;
+    let body = cx.styled(
+        &container.style,
+        quote! {{
+            #body
+            ::std::result::Result::Ok(())
+        }},
+    );
+
+    Ok(quote! {
+        impl #impl_generics #chord for #name #ty_generics #where_clause {
+            fn chord_size(&self) -> usize {
+                #size
+            }
+
+            fn play(
+                self,
+                __orchestra: &::yamlette::orchestra::Orchestra,
+                __level: usize,
+                __alias: ::std::option::Option<::std::borrow::Cow<'static, str>>,
+                __cs: ::yamlette::model::style::CommonStyles,
+                __vs: &mut [&mut dyn #style],
+            ) -> ::std::result::Result<(), ::yamlette::orchestra::OrchError> {
+                #body
+            }
+        }
+    })
+}
+
+/// Bounds all the type parameters by `Chord`
+fn bound_generics(generics: &Generics) -> Generics {
+    let mut generics = generics.clone();
+
+    let params: Vec<Ident> = generics.type_params().map(|p| p.ident.clone()).collect();
+    let where_clause = generics.make_where_clause();
+
+    for param in params {
+        where_clause.predicates.push(parse_quote! {
+            #param: ::yamlette::orchestra::chord::Chord
+        });
+    }
+
+    generics
+}
+
+/// A field that gets written, bound to `binding` when the value is destructured
+struct Slot {
+    member: Member,
+    binding: Ident,
+    key: Option<String>,
+    optional: bool,
+    style: Vec<Expr>,
+}
+
+enum Shape {
+    Named(Vec<Slot>),
+    Tuple(Vec<Slot>),
+    Unit,
+}
+
+impl Shape {
+    fn parse(fields: &Fields, rule: Option<RenameRule>) -> Result<Shape> {
+        let mut slots = Vec::new();
+
+        for (idx, field) in fields.iter().enumerate() {
+            let attrs = Field::parse(&field.attrs)?;
+
+            if attrs.flatten {
+                return Err(Error::new_spanned(
+                    field,
+                    "the `flatten` attribute isn't supported by Chord",
+                ));
+            }
+
+            if attrs.skip {
+                continue;
+            }
+
+            let member = match field.ident {
+                Some(ref ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(idx.into()),
+            };
+
+            slots.push(Slot {
+                member,
+                binding: format_ident!("__field{}", idx),
+                key: field.ident.as_ref().map(|ident| match attrs.rename {
+                    Some(ref key) => key.clone(),
+                    None => case::rename(rule, &ident.to_string()),
+                }),
+                optional: option_inner(&field.ty).is_some(),
+                style: attrs.style,
+            });
+        }
+
+        Ok(match *fields {
+            Fields::Named(_) => Shape::Named(slots),
+            Fields::Unnamed(_) => Shape::Tuple(slots),
+            Fields::Unit => Shape::Unit,
+        })
+    }
+
+    fn slots(&self) -> &[Slot] {
+        match *self {
+            Shape::Named(ref slots) | Shape::Tuple(ref slots) => slots,
+            Shape::Unit => &[],
+        }
+    }
+
+    /// `Path { field: __field0, 1: __field1, .. }`, which works for all kinds of structs
+    fn pattern(&self, path: TokenStream, by_ref: bool) -> TokenStream {
+        let members = self.slots().iter().map(|slot| &slot.member);
+        let bindings = self.slots().iter().map(|slot| &slot.binding);
+        let by_ref = if by_ref { quote!(ref) } else { quote!() };
+
+        quote!(#path { #(#members: #by_ref #bindings,)* .. })
+    }
+}
+
+struct Context {
+    chord: TokenStream,
+    style: TokenStream,
+    rule: Option<RenameRule>,
+}
+
+impl Context {
+    /// The number of nodes, with the bindings being references.
+    /// `entry` is the key-value pair the internally tagged variants start with
+    fn size(&self, shape: &Shape, entry: Option<(&str, &str)>) -> TokenStream {
+        let chord = &self.chord;
+        let entry = entry.map(|_| quote!(+ 2));
+
+        match *shape {
+            Shape::Named(ref slots) => {
+                let items = slots.iter().map(|slot| {
+                    let binding = &slot.binding;
+
+                    if slot.optional {
+                        quote! {
+                            match *#binding {
+                                ::std::option::Option::Some(ref __value) => 1 + #chord::chord_size(__value),
+                                ::std::option::Option::None => 0,
+                            }
+                        }
+                    } else {
+                        quote!(1 + #chord::chord_size(#binding))
+                    }
+                });
+
+                quote!(1 #entry #(+ #items)*)
+            }
+            Shape::Tuple(ref slots) if slots.len() == 1 => self.item_size(&slots[0]),
+            Shape::Tuple(ref slots) => {
+                let items = slots.iter().map(|slot| self.item_size(slot));
+                quote!(1 #(+ #items)*)
+            }
+            Shape::Unit => quote!(1),
+        }
+    }
+
+    /// The items of sequences, which are written as null when `None`
+    fn item_size(&self, slot: &Slot) -> TokenStream {
+        let chord = &self.chord;
+        let binding = &slot.binding;
+
+        if slot.optional {
+            quote! {
+                match *#binding {
+                    ::std::option::Option::Some(ref __value) => #chord::chord_size(__value),
+                    ::std::option::Option::None => 1,
+                }
+            }
+        } else {
+            quote!(#chord::chord_size(#binding))
+        }
+    }
+
+    /// The statements playing the value with the bindings being moved out of it
+    fn play(
+        &self,
+        shape: &Shape,
+        entry: Option<(&str, &str)>,
+        level: &TokenStream,
+        alias: &TokenStream,
+    ) -> TokenStream {
+        let chord = &self.chord;
+        let none = quote!(::std::option::Option::None);
+
+        match *shape {
+            Shape::Named(ref slots) => {
+                let entry = entry.map(|(tag, key)| {
+                    quote! {
+                        #chord::play(#tag, __orchestra, #level + 1, #none, __cs, __vs)?;
+                        #chord::play(#key, __orchestra, #level + 1, #none, __cs, __vs)?;
+                    }
+                });
+
+                let items = slots.iter().map(|slot| {
+                    let key = slot.key.as_deref().unwrap_or_default();
+                    let binding = &slot.binding;
+                    let value = quote!(__value);
+
+                    let key = quote! {
+                        #chord::play(#key, __orchestra, #level + 1, #none, __cs, __vs)?;
+                    };
+
+                    if slot.optional {
+                        let value = self.value(slot, &value, &quote!(#level + 1), &none);
+
+                        quote! {
+                            if let ::std::option::Option::Some(__value) = #binding {
+                                #key
+                                #value
+                            }
+                        }
+                    } else {
+                        let value = self.value(slot, &quote!(#binding), &quote!(#level + 1), &none);
+
+                        quote! {
+                            #key
+                            #value
+                        }
+                    }
+                });
+
+                quote! {
+                    #chord::play(::yamlette::orchestra::chord::EmptyDict, __orchestra, #level, #alias, __cs, __vs)?;
+                    #entry
+                    #(#items)*
+                }
+            }
+            Shape::Tuple(ref slots) if slots.len() == 1 => self.item(&slots[0], level, alias),
+            Shape::Tuple(ref slots) => {
+                let level_in = quote!(#level + 1);
+                let items = slots.iter().map(|slot| self.item(slot, &level_in, &none));
+
+                quote! {
+                    #chord::play(::yamlette::orchestra::chord::EmptyList, __orchestra, #level, #alias, __cs, __vs)?;
+                    #(#items)*
+                }
+            }
+            Shape::Unit => quote! {
+                #chord::play((), __orchestra, #level, #alias, __cs, __vs)?;
+            },
+        }
+    }
+
+    fn item(&self, slot: &Slot, level: &TokenStream, alias: &TokenStream) -> TokenStream {
+        let chord = &self.chord;
+        let binding = &slot.binding;
+
+        if slot.optional {
+            let value = self.value(slot, &quote!(__value), level, alias);
+
+            quote! {
+                match #binding {
+                    ::std::option::Option::Some(__value) => { #value }
+                    ::std::option::Option::None => {
+                        #chord::play((), __orchestra, #level, #alias, __cs, __vs)?;
+                    }
+                }
+            }
+        } else {
+            self.value(slot, &quote!(#binding), level, alias)
+        }
+    }
+
+    fn value(
+        &self,
+        slot: &Slot,
+        value: &TokenStream,
+        level: &TokenStream,
+        alias: &TokenStream,
+    ) -> TokenStream {
+        let chord = &self.chord;
+        let play = self.styled(
+            &slot.style,
+            quote!(#chord::play(#value, __orchestra, #level, #alias, __cs, __vs)),
+        );
+
+        quote!(#play?;)
+    }
+
+    /// Wraps the expression so it sees `__cs` and `__vs` with the styles applied
+    fn styled(&self, styles: &[Expr], expr: TokenStream) -> TokenStream {
+        if styles.is_empty() {
+            return expr;
+        }
+
+        let style = &self.style;
+
+        quote! {
+            ::yamlette::orchestra::chord::with_styles(
+                __cs,
+                __vs,
+                &mut [#(&mut #styles as &mut dyn #style),*],
+                |__cs, __vs| #expr,
+            )
+        }
+    }
+
+    /// The unit variants are written as their names, the rest either as maps of a single key
+    /// holding the content or, with the `tag` attribute, as maps with the name under the tag key
+    fn variants(
+        &self,
+        name: &Ident,
+        data: &DataEnum,
+        tag: Option<&str>,
+    ) -> Result<(TokenStream, TokenStream)> {
+        let chord = &self.chord;
+        let none = quote!(::std::option::Option::None);
+        let level_in = quote!(__level + 1);
+
+        let mut sizes = Vec::new();
+        let mut plays = Vec::new();
+
+        for variant in data.variants.iter() {
+            let attrs = Variant::parse(&variant.attrs)?;
+            let ident = &variant.ident;
+
+            if attrs.yaml_tag.is_some() {
+                return Err(Error::new_spanned(
+                    variant,
+                    "the variants with `yaml_tag` can't be written, since the orchestra can't issue the collection tags",
+                ));
+            }
+
+            let key = match attrs.rename {
+                Some(key) => key,
+                None => case::rename(self.rule, &ident.to_string()),
+            };
+
+            let shape = Shape::parse(&variant.fields, self.rule)?;
+            let pattern = shape.pattern(quote!(#name::#ident), false);
+            let pattern_ref = shape.pattern(quote!(#name::#ident), true);
+
+            let (size, play) = match (tag, &shape) {
+                (Some(tag), &Shape::Unit) => {
+                    let shape = Shape::Named(Vec::new());
+
+                    (
+                        self.size(&shape, Some((tag, &key))),
+                        self.play(
+                            &shape,
+                            Some((tag, &key)),
+                            &quote!(__level),
+                            &quote!(__alias),
+                        ),
+                    )
+                }
+                (Some(tag), &Shape::Named(_)) => (
+                    self.size(&shape, Some((tag, &key))),
+                    self.play(
+                        &shape,
+                        Some((tag, &key)),
+                        &quote!(__level),
+                        &quote!(__alias),
+                    ),
+                ),
+                (Some(_), &Shape::Tuple(_)) => {
+                    return Err(Error::new_spanned(
+                        variant,
+                        "the tuple variants can't be internally tagged",
+                    ))
+                }
+                (None, &Shape::Unit) => (
+                    quote!(1),
+                    quote! {
+                        #chord::play(#key, __orchestra, __level, __alias, __cs, __vs)?;
+                    },
+                ),
+                (None, _) => {
+                    let size = self.size(&shape, None);
+                    let play = self.play(&shape, None, &level_in, &none);
+
+                    (
+                        quote!(2 + #size),
+                        quote! {
+                            #chord::play(::yamlette::orchestra::chord::EmptyDict, __orchestra, __level, __alias, __cs, __vs)?;
+                            #chord::play(#key, __orchestra, #level_in, #none, __cs, __vs)?;
+                            #play
+                        },
+                    )
+                }
+            };
+
+            sizes.push(quote!(#pattern_ref => #size,));
+            plays.push(quote!(#pattern => { #play }));
+        }
+
+        Ok((
+            quote! {
+                match *self {
+                    #(#sizes)*
+                }
+            },
+            quote! {
+                match self {
+                    #(#plays)*
+                }
+            },
+        ))
+    }
+}
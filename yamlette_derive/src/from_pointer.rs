@@ -0,0 +1,360 @@
+use crate::attr::{Container, Field, FieldDefault, Variant};
+use crate::case::{self, RenameRule};
+use crate::ty::option_inner;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    parse_quote, Data, DataEnum, DeriveInput, Error, Fields, FieldsNamed, FieldsUnnamed,
+    GenericParam, Generics, Ident, Lifetime, LifetimeParam, Result,
+};
+
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let container = Container::parse(&input.attrs)?;
+    let (lifetime, generics) = bound_generics(&input.generics);
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let name = &input.ident;
+    let node = Ident::new("__pointer", Span::call_site());
+
+    let cx = Context {
+        trait_: quote!(::yamlette::book::extractor::traits::FromPointer<#lifetime>),
+        word: quote!(::yamlette::book::word::Word),
+        rule: container.rename_all,
+    };
+
+    let body = match input.data {
+        Data::Struct(ref data) => {
+            if container.tag.is_some() {
+                return Err(Error::new_spanned(name, "the `tag` attribute is for enums"));
+            }
+
+            let value = cx.fields(quote!(#name), &data.fields, &node)?;
+            quote!(::std::option::Option::Some(#value))
+        }
+        Data::Enum(ref data) => cx.variants(name, data, container.tag.as_deref(), &node)?,
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                name,
+                "FromPointer can't be derived for unions",
+            ))
+        }
+    };
+
+    let pointer = quote!(::yamlette::book::extractor::pointer::Pointer<#lifetime>);
+    let trait_ = &cx.trait_;
+
+    Ok(quote! {
+        impl #impl_generics #trait_ for #name #ty_generics #where_clause {
+            fn from_pointer(#node: #pointer) -> ::std::option::Option<Self> {
+                #body
+            }
+        }
+    })
+}
+
+/// Takes the first lifetime of the type as the lifetime of the pointer (or adds one),
+/// and bounds all the type parameters by `FromPointer`
+fn bound_generics(generics: &Generics) -> (Lifetime, Generics) {
+    let mut generics = generics.clone();
+
+    let lifetime = match generics.lifetimes().next() {
+        Some(param) => param.lifetime.clone(),
+        None => {
+            let lifetime = Lifetime::new("'__y", Span::call_site());
+            let param = GenericParam::Lifetime(LifetimeParam::new(lifetime.clone()));
+            generics.params.insert(0, param);
+            lifetime
+        }
+    };
+
+    let params: Vec<Ident> = generics.type_params().map(|p| p.ident.clone()).collect();
+    let where_clause = generics.make_where_clause();
+
+    for param in params {
+        where_clause.predicates.push(parse_quote! {
+            #param: ::yamlette::book::extractor::traits::FromPointer<#lifetime>
+        });
+    }
+
+    (lifetime, generics)
+}
+
+struct Context {
+    trait_: TokenStream,
+    word: TokenStream,
+    rule: Option<RenameRule>,
+}
+
+impl Context {
+    /// The expression building the value out of the node, returning `None` from
+    /// `from_pointer` as soon as a part of it can't be read
+    fn fields(&self, ctor: TokenStream, fields: &Fields, node: &Ident) -> Result<TokenStream> {
+        let trait_ = &self.trait_;
+        let word = &self.word;
+
+        match *fields {
+            Fields::Named(ref fields) => self.named(ctor, fields, node),
+            Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => {
+                let ty = &fields.unnamed[0].ty;
+                Ok(quote!(#ctor(<#ty as #trait_>::from_pointer(#node)?)))
+            }
+            Fields::Unnamed(ref fields) => Ok(self.tuple(ctor, fields, node)),
+            Fields::Unit => Ok(quote! {
+                match *#node.unalias().to_word() {
+                    #word::Null => #ctor,
+                    _ => return ::std::option::Option::None,
+                }
+            }),
+        }
+    }
+
+    fn named(&self, ctor: TokenStream, fields: &FieldsNamed, node: &Ident) -> Result<TokenStream> {
+        let trait_ = &self.trait_;
+        let word = &self.word;
+
+        let mut slots = Vec::new();
+        let mut arms = Vec::new();
+        let mut inits = Vec::new();
+
+        for (idx, field) in fields.named.iter().enumerate() {
+            let attrs = Field::parse(&field.attrs)?;
+            let ident = field.ident.as_ref().unwrap();
+            let ty = &field.ty;
+
+            if attrs.skip {
+                inits.push(quote!(#ident: ::std::default::Default::default()));
+                continue;
+            }
+
+            if attrs.flatten {
+                inits.push(quote!(#ident: <#ty as #trait_>::from_pointer(#node)?));
+                continue;
+            }
+
+            let slot = format_ident!("__field{}", idx);
+            let key = match attrs.rename {
+                Some(key) => key,
+                None => case::rename(self.rule, &ident.to_string()),
+            };
+
+            slots.push(quote! {
+                let mut #slot = ::std::option::Option::None;
+            });
+            arms.push(quote! {
+                ::std::option::Option::Some(#key) => #slot = ::std::option::Option::Some(__value),
+            });
+
+            let inner = option_inner(ty);
+
+            let present = match inner {
+                Some(inner) => quote! {
+                    match *__value.unalias().to_word() {
+                        #word::Null => ::std::option::Option::None,
+                        _ => ::std::option::Option::Some(<#inner as #trait_>::from_pointer(__value)?),
+                    }
+                },
+                None => quote!(<#ty as #trait_>::from_pointer(__value)?),
+            };
+
+            let missing = match attrs.default {
+                FieldDefault::Trait => quote!(::std::default::Default::default()),
+                FieldDefault::Path(ref path) => quote!(#path()),
+                FieldDefault::None if inner.is_some() => quote!(::std::option::Option::None),
+                FieldDefault::None => quote!(return ::std::option::Option::None),
+            };
+
+            inits.push(quote! {
+                #ident: match #slot {
+                    ::std::option::Option::Some(__value) => #present,
+                    ::std::option::Option::None => #missing,
+                }
+            });
+        }
+
+        let lookup = if slots.is_empty() {
+            quote!()
+        } else {
+            quote! {
+                let mut __key = #node.into_map();
+
+                while let ::std::option::Option::Some(__k) = __key {
+                    let __value = __k.next_sibling()?;
+
+                    match <&str as #trait_>::from_pointer(__k) {
+                        #(#arms)*
+                        _ => (),
+                    }
+
+                    __key = __value.next_sibling();
+                }
+            }
+        };
+
+        Ok(quote! {{
+            match *#node.unalias().to_word() {
+                #word::Map(_) => (),
+                _ => return ::std::option::Option::None,
+            }
+
+            #(#slots)*
+            #lookup
+
+            #ctor { #(#inits),* }
+        }})
+    }
+
+    /// Reads a sequence of exactly as many items as the fields
+    fn tuple(&self, ctor: TokenStream, fields: &FieldsUnnamed, node: &Ident) -> TokenStream {
+        let trait_ = &self.trait_;
+        let word = &self.word;
+
+        let idents: Vec<Ident> = (0..fields.unnamed.len())
+            .map(|idx| format_ident!("__field{}", idx))
+            .collect();
+        let types = fields.unnamed.iter().map(|field| &field.ty);
+
+        quote! {{
+            match *#node.unalias().to_word() {
+                #word::Seq(_) => (),
+                _ => return ::std::option::Option::None,
+            }
+
+            let mut __item = #node.into_seq();
+
+            #(
+                let #idents = {
+                    let __p = __item?;
+                    __item = __p.next_sibling();
+                    <#types as #trait_>::from_pointer(__p)?
+                };
+            )*
+
+            if __item.is_some() {
+                return ::std::option::Option::None;
+            }
+
+            #ctor(#(#idents),*)
+        }}
+    }
+
+    fn variants(
+        &self,
+        name: &Ident,
+        data: &DataEnum,
+        tag: Option<&str>,
+        node: &Ident,
+    ) -> Result<TokenStream> {
+        let trait_ = &self.trait_;
+        let word = &self.word;
+        let content = Ident::new("__content", Span::call_site());
+
+        let mut tagged = Vec::new();
+        let mut named = Vec::new();
+        let mut units = Vec::new();
+
+        for variant in data.variants.iter() {
+            let attrs = Variant::parse(&variant.attrs)?;
+            let ident = &variant.ident;
+            let ctor = quote!(#name::#ident);
+
+            if let Some(ref yaml_tag) = attrs.yaml_tag {
+                let value = match variant.fields {
+                    Fields::Unit => ctor,
+                    _ => self.fields(ctor, &variant.fields, node)?,
+                };
+
+                tagged.push(quote! {
+                    #yaml_tag => return ::std::option::Option::Some(#value),
+                });
+                continue;
+            }
+
+            let key = match attrs.rename {
+                Some(key) => key,
+                None => case::rename(self.rule, &ident.to_string()),
+            };
+
+            if tag.is_some() {
+                let value = match variant.fields {
+                    Fields::Unit => ctor,
+                    Fields::Unnamed(ref fields) if fields.unnamed.len() != 1 => {
+                        return Err(Error::new_spanned(
+                            variant,
+                            "the tuple variants can't be internally tagged",
+                        ))
+                    }
+                    _ => self.fields(ctor, &variant.fields, node)?,
+                };
+
+                named.push(quote!(#key => ::std::option::Option::Some(#value),));
+            } else {
+                if let Fields::Unit = variant.fields {
+                    units.push(quote!(#key => ::std::option::Option::Some(#ctor),));
+                }
+
+                let value = self.fields(ctor, &variant.fields, &content)?;
+                named.push(quote!(#key => ::std::option::Option::Some(#value),));
+            }
+        }
+
+        let by_yaml_tag = if tagged.is_empty() {
+            quote!()
+        } else {
+            quote! {
+                if let ::std::option::Option::Some(__tag) = #node.tag() {
+                    match &*__tag {
+                        #(#tagged)*
+                        _ => (),
+                    }
+                }
+            }
+        };
+
+        let by_name = match tag {
+            Some(tag) => quote! {
+                match <&str as #trait_>::from_pointer(#node.get(#tag)?)? {
+                    #(#named)*
+                    _ => ::std::option::Option::None,
+                }
+            },
+            None => {
+                let by_scalar = if units.is_empty() {
+                    quote!(::std::option::Option::None)
+                } else {
+                    quote! {
+                        match <&str as #trait_>::from_pointer(#node)? {
+                            #(#units)*
+                            _ => ::std::option::Option::None,
+                        }
+                    }
+                };
+
+                quote! {
+                    match *#node.unalias().to_word() {
+                        #word::Map(_) => {
+                            let __key = #node.into_map()?;
+                            let #content = __key.next_sibling()?;
+
+                            if #content.next_sibling().is_some() {
+                                return ::std::option::Option::None;
+                            }
+
+                            match <&str as #trait_>::from_pointer(__key)? {
+                                #(#named)*
+                                _ => ::std::option::Option::None,
+                            }
+                        }
+                        _ => #by_scalar,
+                    }
+                }
+            }
+        };
+
+        Ok(quote! {
+            #by_yaml_tag
+            #by_name
+        })
+    }
+}
@@ -0,0 +1,57 @@
+//! Derive macros for the traits of `yamlette`, re-exported by it with the `derive` feature
+
+extern crate proc_macro;
+
+mod attr;
+mod case;
+mod chord;
+mod from_pointer;
+mod ty;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Implements `FromPointer<'a>` for a struct or an enum.
+///
+/// The structs with named fields are read from mappings, the tuple structs from sequences,
+/// the newtypes from whatever their inner type is read from and the unit structs from null.
+/// The enums are externally tagged (`Unit` or `{ Variant: content }`) unless the `tag` attribute
+/// names the key holding the variant, or the variants have the YAML tags of their own.
+///
+/// Container attributes: `#[yamlette(rename_all = "...", tag = "...")]`
+///
+/// Field attributes: `#[yamlette(rename = "...", default, default = "path", flatten, skip)]`
+/// (`style` is ignored)
+///
+/// Variant attributes: `#[yamlette(rename = "...", yaml_tag = "!circle")]`
+#[proc_macro_derive(FromPointer, attributes(yamlette))]
+pub fn derive_from_pointer(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    from_pointer::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements `orchestra::chord::Chord` for a struct or an enum.
+///
+/// The structs with named fields are written as mappings (leaving out the `Option` fields
+/// that are `None`), the tuple structs as sequences, the newtypes as their inner values
+/// and the unit structs as null.
+/// The unit variants are written as their names, the others as `{ Variant: content }`,
+/// or as mappings with the name under the key given by the `tag` attribute.
+///
+/// Container attributes: `#[yamlette(rename_all = "...", tag = "...", style(FLOW, ...))]`
+///
+/// Field attributes: `#[yamlette(rename = "...", skip, style(FORCE_QUOTES, Multiline(true), ...))]`
+///
+/// The styles are expressions of `model::style::Style` values, applied the same way
+/// as `( # FLOW => value )` of `yamlette_compose!`.
+#[proc_macro_derive(Chord, attributes(yamlette))]
+pub fn derive_chord(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    chord::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}